@@ -1,6 +1,9 @@
 use std::time::Duration;
 
-use crate::config::Config;
+use crate::config::{Config, TlsBackend};
+use crate::middleware::{build_http_service, with_retry, HttpResponse, HttpService};
+use crate::observer::{api_name, RequestEvent};
+use crate::rate_limit::{RateLimiter, WriteLimit};
 use crate::utils::{user_agent, ValueGetter};
 use crate::{
     common::*, CompressionError, ConfigError, RequestError, RequestErrorKind, ResponseErrorKind,
@@ -10,14 +13,20 @@ use aliyun_log_sdk_sign::sign_v1;
 use http::header::USER_AGENT;
 use http::HeaderMap;
 
-use log::debug;
-
-use tokio::time::sleep;
+use tower::ServiceExt;
+use tracing::{debug_span, info_span, Instrument};
 
 use crate::{
     compress::{compress, decompress, CompressType},
     error::{Error, Result},
 };
+mod pagination;
+use pagination::{paginate, Page};
+
+mod ensure;
+pub use ensure::EnsureOutcome;
+use ensure::FieldChanges;
+
 mod consumer_group;
 pub use consumer_group::*;
 
@@ -27,9 +36,27 @@ pub use project::*;
 mod logstore;
 pub use logstore::*;
 
+mod metricstore;
+pub use metricstore::*;
+
 mod index;
 pub use index::*;
 
+mod schedule;
+pub use schedule::*;
+
+mod alert;
+pub use alert::*;
+
+mod scheduled_sql;
+pub use scheduled_sql::*;
+
+mod etl;
+pub use etl::*;
+
+mod shipper;
+pub use shipper::*;
+
 pub(crate) use crate::macros::*;
 
 mod pull_logs;
@@ -38,16 +65,32 @@ mod pull_logs_raw;
 pub use pull_logs_raw::*;
 mod put_logs;
 pub use put_logs::*;
+mod put_metrics;
+pub use put_metrics::*;
+mod query_metrics;
+pub use query_metrics::*;
 mod get_cursor;
 pub use get_cursor::*;
+mod get_cursor_time;
+pub use get_cursor_time::*;
 mod list_shards;
 pub use list_shards::*;
 mod get_logs;
 use crate::request::Request;
 use crate::response::{DecompressedResponse, FromHttpResponse, Response};
 pub use get_logs::*;
+mod get_histograms;
+pub use get_histograms::*;
+mod get_project_logs;
+pub use get_project_logs::*;
+mod get_context_logs;
+pub use get_context_logs::*;
 mod put_logs_raw;
 pub use put_logs_raw::*;
+#[cfg(feature = "opentelemetry")]
+mod otel;
+#[cfg(feature = "opentelemetry")]
+pub use otel::*;
 
 /// Aliyun Log Service client
 ///
@@ -81,16 +124,196 @@ pub use put_logs_raw::*;
 /// ```
 ///
 /// For more configuration options, see [`ConfigBuilder`](crate::config::ConfigBuilder).
+///
+/// Each request is traced with [`tracing`] in a `sls.request` span, with the `operation`, `project`,
+/// `path`, `request_id`, `http_status`, `error_code` and `retries` fields, containing the
+/// `sls.encode`, `sls.sign`, `sls.attempt` and `sls.parse` spans, and retry backoffs as events.
+///
+/// The request timeout and the maximum number of retries can be overridden per request on the
+/// request builders, which also accept a deadline for the whole request, retries included:
+/// ```no_run
+/// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+/// use std::time::{Duration, Instant};
+///
+/// // a long running analytical query
+/// let now = chrono::Utc::now().timestamp();
+/// let resp = client
+///     .get_logs("my-project", "my-logstore")
+///     .from(now - 86400 * 30)
+///     .to(now)
+///     .query("* | select status, count(*) as c group by status")
+///     .request_timeout(Duration::from_secs(300))
+///     .send()
+///     .await?;
+///
+/// // fail fast when writing logs
+/// # let log_group = aliyun_log_sdk_protobuf::LogGroup::new();
+/// client
+///     .put_logs("my-project", "my-logstore")
+///     .log_group(log_group)
+///     .max_retry(0)
+///     .deadline(Instant::now() + Duration::from_millis(500))
+///     .send()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
 pub struct Client {
     handle: HandleRef,
 }
 
 pub(crate) struct Handle {
     config: Config,
-    http_client: reqwest::Client,
+    http_service: HttpService,
+    rate_limiter: Option<std::sync::Arc<RateLimiter>>,
 }
 
-pub(crate) type HandleRef = std::sync::Arc<Handle>;
+/// A shared handle to the client, with the options of the request being built.
+#[derive(Clone)]
+pub(crate) struct HandleRef {
+    handle: std::sync::Arc<Handle>,
+    pub(crate) options: RequestOptions,
+}
+
+/// Per-request overrides of the client settings.
+#[derive(Clone, Default)]
+pub(crate) struct RequestOptions {
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) max_retry: Option<u32>,
+    pub(crate) deadline: Option<std::time::Instant>,
+}
+
+impl HandleRef {
+    fn new(handle: Handle) -> Self {
+        Self {
+            handle: std::sync::Arc::new(handle),
+            options: RequestOptions::default(),
+        }
+    }
+
+    pub(crate) async fn send<R>(&self, request: R) -> Result<Response<R::ResponseBody>>
+    where
+        R: Request,
+    {
+        let observed = !self.handle.config.observers.is_empty();
+        let start = std::time::Instant::now();
+        let api = api_name::<R>();
+        let project = request.project().map(str::to_string);
+        let span = info_span!(
+            "sls.request",
+            operation = %api,
+            project = project.as_deref(),
+            path = request.path(),
+            request_id = tracing::field::Empty,
+            http_status = tracing::field::Empty,
+            error_code = tracing::field::Empty,
+            retries = tracing::field::Empty,
+        );
+        let mut ctx = SendContext {
+            options: &self.options,
+            write_limit: None,
+            stats: RequestStats::default(),
+        };
+        let send = async {
+            ctx.write_limit = self.write_limit(&request).await;
+            self.handle.send(request, &mut ctx).await
+        }
+        .instrument(span.clone());
+        let result = match self.options.deadline {
+            Some(deadline) => tokio::time::timeout_at(deadline.into(), send)
+                .await
+                .unwrap_or(Err(Error::DeadlineExceeded)),
+            None => send.await,
+        };
+        if observed {
+            let event = ctx
+                .stats
+                .into_event(api_name::<R>(), project, &result, start);
+            for observer in &self.handle.config.observers {
+                observer.on_request_completed(&event);
+            }
+        }
+        result
+    }
+
+    /// The write rate limit of a request, if it is subject to one.
+    ///
+    /// With the [`Shard`](crate::RateLimitScope::Shard) scope, the shards of the logstore
+    /// are listed to find the one a hash key is routed to.
+    async fn write_limit<R: Request>(&self, request: &R) -> Option<WriteLimit> {
+        let rate_limiter = self.handle.rate_limiter.as_ref()?;
+        let project = request.project()?;
+        let target = request.write_target()?;
+        let shard = match target.hash_key {
+            Some(hash_key) if rate_limiter.by_shard() => {
+                let client = Client {
+                    handle: HandleRef {
+                        handle: self.handle.clone(),
+                        options: RequestOptions::default(),
+                    },
+                };
+                let list_shards = client.list_shards(project, target.logstore).send();
+                rate_limiter
+                    .shard_of(project, &target, hash_key, list_shards)
+                    .await
+            }
+            _ => None,
+        };
+        let key = rate_limiter.key(project, &target, shard);
+        Some(WriteLimit::new(rate_limiter.clone(), key))
+    }
+}
+
+/// State of a request being sent.
+struct SendContext<'a> {
+    options: &'a RequestOptions,
+    /// The rate limit of the request, if it is a write subject to one
+    write_limit: Option<WriteLimit>,
+    stats: RequestStats,
+}
+
+/// What is known of a request being sent, reported to the observers once it is completed.
+#[derive(Default)]
+struct RequestStats {
+    retries: std::sync::Arc<std::sync::atomic::AtomicU32>,
+    request_size: usize,
+    request_raw_size: usize,
+    response_size: usize,
+    response_raw_size: usize,
+}
+
+impl RequestStats {
+    fn into_event<B: FromHttpResponse + Send + Sync>(
+        self,
+        api: String,
+        project: Option<String>,
+        result: &Result<Response<B>>,
+        start: std::time::Instant,
+    ) -> RequestEvent {
+        let (http_status, error_code) = match result {
+            Ok(resp) => (Some(resp.status.as_u16()), None),
+            Err(Error::Server {
+                http_status,
+                error_code,
+                ..
+            }) => (Some(*http_status as u16), Some(error_code.clone())),
+            Err(_) => (None, None),
+        };
+        RequestEvent {
+            api,
+            project,
+            http_status,
+            error_code,
+            success: result.is_ok(),
+            retry_count: self.retries.load(std::sync::atomic::Ordering::Relaxed),
+            request_size: self.request_size,
+            request_raw_size: self.request_raw_size,
+            response_size: self.response_size,
+            response_raw_size: self.response_raw_size,
+            latency: start.elapsed(),
+        }
+    }
+}
 
 pub(crate) type BuildResult<T> = std::result::Result<(HandleRef, T), RequestError>;
 
@@ -100,21 +323,57 @@ pub trait FromConfig: Sized {
 
 impl FromConfig for Client {
     fn from_config(config: Config) -> Result<Self, ConfigError> {
-        let http_client = reqwest::Client::builder()
-            .connect_timeout(config.connection_timeout)
-            .timeout(config.request_timeout)
-            .pool_idle_timeout(DEFAULT_POOL_IDLE_TIMEOUT)
-            .build()?;
+        let http_client = match &config.http_client {
+            Some(http_client) => http_client.clone(),
+            None => build_http_client(&config)?,
+        };
+        let http_service = build_http_service(&config, http_client);
+        let rate_limiter = config
+            .write_rate_limit
+            .clone()
+            .map(|limit| std::sync::Arc::new(RateLimiter::new(limit)));
         let handle = HandleRef::new(Handle {
             config,
-            http_client,
+            http_service,
+            rate_limiter,
         });
         Ok(Self { handle })
     }
 }
 
+fn build_http_client(config: &Config) -> Result<reqwest::Client, ConfigError> {
+    let mut builder = reqwest::Client::builder()
+        .connect_timeout(config.connection_timeout)
+        .timeout(config.request_timeout)
+        .pool_idle_timeout(config.pool_idle_timeout)
+        .tcp_keepalive(config.tcp_keepalive);
+    for proxy in &config.proxies {
+        builder = builder.proxy(proxy.clone());
+    }
+    for certificate in &config.root_certificates {
+        builder = builder.add_root_certificate(certificate.clone());
+    }
+    builder = match config.tls_backend {
+        Some(TlsBackend::NativeTls) => builder.use_native_tls(),
+        #[cfg(feature = "rustls-tls")]
+        Some(TlsBackend::Rustls) => builder.use_rustls_tls(),
+        None => builder,
+    };
+    if let Some(max_idle) = config.pool_max_idle_per_host {
+        builder = builder.pool_max_idle_per_host(max_idle);
+    }
+    if config.http2_prior_knowledge {
+        builder = builder.http2_prior_knowledge();
+    }
+    Ok(builder.build()?)
+}
+
 impl Handle {
-    pub(crate) async fn send<R>(&self, request: R) -> Result<Response<R::ResponseBody>>
+    async fn send<R>(
+        &self,
+        request: R,
+        ctx: &mut SendContext<'_>,
+    ) -> Result<Response<R::ResponseBody>>
     where
         R: Request,
     {
@@ -127,12 +386,12 @@ impl Handle {
             headers.insert(http::header::CONTENT_TYPE, content_type);
         }
 
-        let body = self.get_request_body(&request, &mut headers)?;
+        let body =
+            debug_span!("sls.encode", compress_type = ?R::COMPRESS_TYPE.map(|t| t.to_string()))
+                .in_scope(|| self.get_request_body(&request, &mut headers))?;
+        ctx.stats.request_size = body.as_ref().map_or(0, |b| b.len());
         if !headers.contains_key(LOG_BODY_RAW_SIZE) {
-            let body_len = match body {
-                None => 0,
-                Some(ref b) => b.len(),
-            };
+            let body_len = ctx.stats.request_size;
 
             headers.insert(
                 LOG_BODY_RAW_SIZE,
@@ -144,11 +403,15 @@ impl Handle {
         }
 
         let resp = self
-            .send_http(method, host, path, query_params, body, headers)
+            .send_http(method, host, path, query_params, body, headers, ctx)
             .await?;
+        ctx.stats.response_size = resp.body_size;
+        ctx.stats.response_raw_size = resp.decompressed.len();
 
         let resp_bytes: bytes::Bytes = resp.decompressed.into();
-        let resp_body = <R::ResponseBody as FromHttpResponse>::try_from(resp_bytes, &resp.headers)?;
+        let resp_body = debug_span!("sls.parse").in_scope(|| {
+            <R::ResponseBody as FromHttpResponse>::try_from(resp_bytes, &resp.headers)
+        })?;
         Ok(Response {
             body: resp_body,
             headers: resp.headers,
@@ -179,6 +442,7 @@ impl Handle {
         Ok(Some(compressed.into()))
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn send_http(
         &self,
         method: http::Method,
@@ -187,6 +451,7 @@ impl Handle {
         query_params: Option<Vec<(String, String)>>,
         body: Option<bytes::Bytes>,
         mut headers: http::HeaderMap,
+        ctx: &mut SendContext<'_>,
     ) -> Result<DecompressedResponse> {
         if !headers.contains_key(USER_AGENT) {
             headers.insert(
@@ -203,107 +468,82 @@ impl Handle {
         // do request signing
         let query_params = query_params.unwrap_or_default();
 
-        sign_v1(
-            &self.config.access_key_id,
-            &self.config.access_key_secret,
-            self.config.security_token.as_deref(),
-            method.clone(),
-            path.as_ref(),
-            &mut headers,
-            query_params.into(),
-            body.as_deref(),
-        )
-        .map_err(RequestErrorKind::from)
-        .map_err(RequestError::from)?;
-
-        let max_retry = self.config.max_retry + 1;
-        for i in 0..max_retry {
-            // here body.clone() is O(1), no underlying data is copied
-            match self
-                .send_signed_http(&method, &url, &headers, body.clone())
-                .await
-            {
-                Ok(resp) => {
-                    return Ok(resp);
-                }
-                Err(err) => {
-                    debug!("fail to send on {} err: {:?}", i, &err.to_string());
-                    if !self.should_retry(&err) || i + 1 >= max_retry {
-                        return Err(err);
-                    }
-                }
-            }
+        debug_span!("sls.sign")
+            .in_scope(|| {
+                sign_v1(
+                    &self.config.access_key_id,
+                    &self.config.access_key_secret,
+                    self.config.security_token.as_deref(),
+                    method.clone(),
+                    path.as_ref(),
+                    &mut headers,
+                    query_params.into(),
+                    body.as_deref(),
+                )
+            })
+            .map_err(RequestErrorKind::from)
+            .map_err(RequestError::from)?;
 
-            let backoff = exponential_backoff(
-                self.config.base_retry_backoff,
-                i,
-                self.config.max_retry_backoff,
-            );
-            sleep(backoff).await;
-        }
-        Err(Error::Other(anyhow::anyhow!(
-            "unreachable, this is a bug, please open an issue to report it."
-        )))
+        ctx.stats.request_raw_size = headers.get_i32_or_default(&LOG_BODY_RAW_SIZE, 0) as usize;
+        self.send_signed_http(method, &url, headers, body, ctx)
+            .await
     }
 
     async fn send_signed_http(
         &self,
-        method: &http::Method,
+        method: http::Method,
         url: &url::Url,
-        headers: &HeaderMap,
+        headers: HeaderMap,
         body: Option<bytes::Bytes>,
+        ctx: &SendContext<'_>,
     ) -> Result<DecompressedResponse> {
-        let req = match *method {
-            http::Method::POST => self.http_client.post(url.clone()),
-            http::Method::GET => self.http_client.get(url.clone()),
-            http::Method::PUT => self.http_client.put(url.clone()),
-            http::Method::DELETE => self.http_client.delete(url.clone()),
-            _ => {
-                return Err(Error::Other(anyhow::anyhow!("Unsupported HTTP method: {method:?}, this is a bug, please open an issue to report it.")));
-            }
-        };
-
-        let req = match body {
-            Some(b) => req.body(b).headers(headers.clone()),
-            None => req.headers(headers.clone()),
-        };
-        self.send_reqwest(req.build()?).await
+        let mut req = reqwest::Request::new(method, url.clone());
+        *req.headers_mut() = headers;
+        if let Some(b) = body {
+            *req.body_mut() = Some(b.into());
+        }
+        if let Some(timeout) = ctx.options.timeout {
+            *req.timeout_mut() = Some(timeout);
+        }
+        self.send_reqwest(req, ctx).await
     }
 
-    async fn send_reqwest(&self, request: reqwest::Request) -> Result<DecompressedResponse> {
-        let response = self.http_client.execute(request).await?;
-        let status = response.status();
-        match status {
+    async fn send_reqwest(
+        &self,
+        request: reqwest::Request,
+        ctx: &SendContext<'_>,
+    ) -> Result<DecompressedResponse> {
+        let max_retry = ctx.options.max_retry.unwrap_or(self.config.max_retry);
+        // boxed with an explicit type, otherwise the compiler fails to prove the builder futures `Send`
+        let send: BoxFuture<std::result::Result<HttpResponse, tower::BoxError>> = Box::pin(
+            with_retry(
+                self.http_service.clone(),
+                &self.config,
+                max_retry,
+                ctx.stats.retries.clone(),
+                ctx.write_limit.clone(),
+            )
+            .oneshot(request),
+        );
+        let response = send.await.map_err(Error::from_box_error)?;
+        match response.status {
             http::status::StatusCode::OK => {
-                let resp_headers = response.headers().to_owned();
-                let resp_body = response.bytes().await?;
-                let decompressed = self.do_decompress(resp_body, &resp_headers)?;
+                let body_size = response.body.len();
+                let decompressed = self.do_decompress(response.body, &response.headers)?;
                 Ok(DecompressedResponse {
-                    headers: resp_headers,
-                    status,
+                    headers: response.headers,
+                    status: response.status,
                     decompressed,
+                    body_size,
                 })
             }
-            _ => {
-                let request_id = response.headers().get_str(LOG_REQUEST_ID);
-                let resp_body = response.text().await?;
-                Err(Error::server_error(
-                    status,
-                    request_id,
-                    resp_body.as_bytes(),
-                ))
+            status => {
+                let request_id = response.headers.get_str(LOG_REQUEST_ID);
+                Err(Error::server_error(status, request_id, &response.body))
             }
         }
     }
 
-    fn should_retry(&self, err: &Error) -> bool {
-        match err {
-            Error::Network(_) => true,
-            Error::Server { http_status, .. } => *http_status >= 500 && *http_status <= 503,
-            _ => false,
-        }
-    }
-
     fn build_host(&self, project: Option<&str>) -> String {
         match project {
             Some(project) => format!(
@@ -387,14 +627,10 @@ impl Handle {
     }
 }
 
-fn exponential_backoff(base_delay: Duration, retry_count: u32, max_delay: Duration) -> Duration {
-    let exp_delay = base_delay * 2u32.pow(retry_count);
-    std::cmp::min(exp_delay, max_delay)
-}
-
-const DEFAULT_POOL_IDLE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(55);
-
 pub type BoxFuture<T> =
     ::std::pin::Pin<::std::boxed::Box<dyn ::std::future::Future<Output = T> + ::std::marker::Send>>;
 
 pub type ResponseResultBoxFuture<B> = BoxFuture<Result<Response<B>, Error>>;
+
+pub type BoxStream<T> =
+    ::std::pin::Pin<::std::boxed::Box<dyn ::futures_util::Stream<Item = T> + ::std::marker::Send>>;
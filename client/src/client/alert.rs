@@ -0,0 +1,634 @@
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+mod create_alert;
+pub use create_alert::*;
+
+mod update_alert;
+pub use update_alert::*;
+
+mod delete_alert;
+pub use delete_alert::*;
+
+mod get_alert;
+pub use get_alert::*;
+
+mod list_alerts;
+pub use list_alerts::*;
+
+mod enable_alert;
+pub use enable_alert::*;
+
+mod disable_alert;
+pub use disable_alert::*;
+
+use super::*;
+
+/// Alert rule information returned by the server.
+#[derive(Debug, Clone, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct Alert {
+    /// Alert name, unique within the project
+    name: String,
+
+    /// Display name of the alert
+    #[serde(rename = "displayName", default)]
+    display_name: String,
+
+    /// Alert description
+    #[serde(default)]
+    description: Option<String>,
+
+    /// Alert state, `Enabled` or `Disabled`
+    #[serde(default)]
+    state: String,
+
+    /// Alert configuration
+    #[serde(default)]
+    configuration: AlertConfiguration,
+
+    /// Schedule of the alert evaluation
+    schedule: Schedule,
+
+    /// Creation time (Unix timestamp)
+    #[serde(rename = "createTime", default)]
+    create_time: Option<i64>,
+
+    /// Last modification time (Unix timestamp)
+    #[serde(rename = "lastModifiedTime", default)]
+    last_modified_time: Option<i64>,
+}
+
+impl Alert {
+    /// Returns true if the alert is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.state.eq_ignore_ascii_case("enabled")
+    }
+}
+
+/// Builder for constructing an alert configuration.
+///
+/// Fields not set on the builder fall back to the defaults used by the console,
+/// e.g. version `2.0`, no grouping, `threshold` 1 and the built-in dynamic alert policy.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_log_rust_sdk::{
+///     AlertConfiguration, AlertEvalCondition, AlertPolicyConfiguration, AlertQuery,
+///     AlertSeverity, AlertSeverityConfiguration,
+/// };
+///
+/// let configuration = AlertConfiguration::builder()
+///     .query(AlertQuery::new("my-project", "my-logstore", "level: ERROR | select count(*) as cnt"))
+///     .severity(AlertSeverityConfiguration::new(
+///         AlertSeverity::High,
+///         AlertEvalCondition::new("cnt > 100"),
+///     ))
+///     .policy(AlertPolicyConfiguration::new("sls.builtin.dynamic").action_policy_id("my-action-policy"))
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct AlertConfigurationBuilder {
+    query_list: Vec<AlertQuery>,
+    group_configuration: Option<AlertGroupConfiguration>,
+    join_configurations: Vec<AlertJoinConfiguration>,
+    severity_configurations: Vec<AlertSeverityConfiguration>,
+    labels: Vec<AlertTag>,
+    annotations: Vec<AlertTag>,
+    auto_annotation: Option<bool>,
+    send_resolved: Option<bool>,
+    threshold: Option<i32>,
+    no_data_fire: Option<bool>,
+    no_data_severity: Option<AlertSeverity>,
+    policy_configuration: Option<AlertPolicyConfiguration>,
+    mute_until: Option<i64>,
+}
+
+impl AlertConfigurationBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a query to the query list, at least one query is required.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The query to evaluate
+    pub fn query(mut self, query: AlertQuery) -> Self {
+        self.query_list.push(query);
+        self
+    }
+
+    /// Set how the query results are grouped, default no grouping.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_configuration` - The group configuration
+    pub fn group_configuration(mut self, group_configuration: AlertGroupConfiguration) -> Self {
+        self.group_configuration = Some(group_configuration);
+        self
+    }
+
+    /// Add a join configuration, needed when there are multiple queries.
+    ///
+    /// # Arguments
+    ///
+    /// * `join_configuration` - How to join the results of two adjacent queries
+    pub fn join_configuration(mut self, join_configuration: AlertJoinConfiguration) -> Self {
+        self.join_configurations.push(join_configuration);
+        self
+    }
+
+    /// Add a trigger condition and its severity, at least one is required.
+    ///
+    /// Conditions are evaluated in order, the first matched one decides the severity.
+    ///
+    /// # Arguments
+    ///
+    /// * `severity_configuration` - The trigger condition and severity
+    pub fn severity(mut self, severity_configuration: AlertSeverityConfiguration) -> Self {
+        self.severity_configurations.push(severity_configuration);
+        self
+    }
+
+    /// Add a label to the alert.
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.push(AlertTag::new(key, value));
+        self
+    }
+
+    /// Add an annotation to the alert, e.g. `title` or `desc`.
+    pub fn annotation(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.annotations.push(AlertTag::new(key, value));
+        self
+    }
+
+    /// Set whether to add annotations automatically, default true.
+    pub fn auto_annotation(mut self, auto_annotation: bool) -> Self {
+        self.auto_annotation = Some(auto_annotation);
+        self
+    }
+
+    /// Set whether to send a notification when the alert is resolved, default false.
+    pub fn send_resolved(mut self, send_resolved: bool) -> Self {
+        self.send_resolved = Some(send_resolved);
+        self
+    }
+
+    /// Set the number of consecutive triggers before an alert fires, default 1.
+    pub fn threshold(mut self, threshold: i32) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Set whether to fire an alert when the query returns no data, default false.
+    ///
+    /// # Arguments
+    ///
+    /// * `no_data_fire` - Whether to fire on no data
+    /// * `severity` - The severity of the no data alert
+    pub fn no_data_fire(mut self, no_data_fire: bool, severity: AlertSeverity) -> Self {
+        self.no_data_fire = Some(no_data_fire);
+        self.no_data_severity = Some(severity);
+        self
+    }
+
+    /// Set the notification policy of the alert, default `sls.builtin.dynamic`.
+    pub fn policy(mut self, policy_configuration: AlertPolicyConfiguration) -> Self {
+        self.policy_configuration = Some(policy_configuration);
+        self
+    }
+
+    /// Mute the alert until the given unix timestamp in seconds.
+    pub fn mute_until(mut self, mute_until: i64) -> Self {
+        self.mute_until = Some(mute_until);
+        self
+    }
+
+    /// Build the alert configuration.
+    pub fn build(self) -> AlertConfiguration {
+        AlertConfiguration {
+            version: DEFAULT_ALERT_VERSION.to_string(),
+            config_type: DEFAULT_ALERT_TYPE.to_string(),
+            dashboard: DEFAULT_ALERT_DASHBOARD.to_string(),
+            query_list: self.query_list,
+            group_configuration: self.group_configuration.unwrap_or_default(),
+            join_configurations: self.join_configurations,
+            severity_configurations: self.severity_configurations,
+            labels: self.labels,
+            annotations: self.annotations,
+            auto_annotation: self.auto_annotation.unwrap_or(true),
+            send_resolved: self.send_resolved.unwrap_or(false),
+            threshold: self.threshold.unwrap_or(1),
+            no_data_fire: self.no_data_fire.unwrap_or(false),
+            no_data_severity: self.no_data_severity.unwrap_or_default(),
+            policy_configuration: self.policy_configuration.unwrap_or_default(),
+            mute_until: self.mute_until.unwrap_or(0),
+        }
+    }
+}
+
+/// Alert configuration, defines what to query, when to trigger and who to notify.
+///
+/// Use [`AlertConfiguration::builder`] to construct one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertConfiguration {
+    /// Alert configuration version
+    pub version: String,
+    /// Alert configuration type
+    #[serde(rename = "type")]
+    pub config_type: String,
+    /// The dashboard that the alert belongs to
+    pub dashboard: String,
+    /// The queries to evaluate
+    #[serde(rename = "queryList")]
+    pub query_list: Vec<AlertQuery>,
+    /// How the query results are grouped
+    #[serde(rename = "groupConfiguration")]
+    pub group_configuration: AlertGroupConfiguration,
+    /// How the results of multiple queries are joined
+    #[serde(rename = "joinConfigurations")]
+    pub join_configurations: Vec<AlertJoinConfiguration>,
+    /// Trigger conditions and their severities
+    #[serde(rename = "severityConfigurations")]
+    pub severity_configurations: Vec<AlertSeverityConfiguration>,
+    /// Labels attached to the alert
+    pub labels: Vec<AlertTag>,
+    /// Annotations attached to the alert
+    pub annotations: Vec<AlertTag>,
+    /// Whether to add annotations automatically
+    #[serde(rename = "autoAnnotation")]
+    pub auto_annotation: bool,
+    /// Whether to send a notification when the alert is resolved
+    #[serde(rename = "sendResolved")]
+    pub send_resolved: bool,
+    /// Number of consecutive triggers before an alert fires
+    pub threshold: i32,
+    /// Whether to fire an alert when the query returns no data
+    #[serde(rename = "noDataFire")]
+    pub no_data_fire: bool,
+    /// Severity of the no data alert
+    #[serde(rename = "noDataSeverity")]
+    pub no_data_severity: AlertSeverity,
+    /// Notification policy
+    #[serde(rename = "policyConfiguration")]
+    pub policy_configuration: AlertPolicyConfiguration,
+    /// Unix timestamp in seconds until which the alert is muted, 0 means not muted
+    #[serde(rename = "muteUntil")]
+    pub mute_until: i64,
+}
+
+impl Default for AlertConfiguration {
+    fn default() -> Self {
+        AlertConfigurationBuilder::new().build()
+    }
+}
+
+impl AlertConfiguration {
+    /// Create a new AlertConfigurationBuilder for fluent configuration.
+    pub fn builder() -> AlertConfigurationBuilder {
+        AlertConfigurationBuilder::new()
+    }
+}
+
+/// A query evaluated by an alert.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertQuery {
+    /// Store type, `log`, `metric` or `meta`
+    #[serde(rename = "storeType")]
+    pub store_type: String,
+    /// Region of the project, empty means the same region as the alert
+    pub region: String,
+    /// Project of the store
+    pub project: String,
+    /// Logstore or metricstore name
+    pub store: String,
+    /// The query statement
+    pub query: String,
+    /// Time span type, `Relative`, `Truncated` or `Custom`
+    #[serde(rename = "timeSpanType")]
+    pub time_span_type: String,
+    /// Start of the query time range, e.g. `-15m`
+    pub start: String,
+    /// End of the query time range, e.g. `now`
+    pub end: String,
+    /// Dedicated SQL mode, `auto`, `enable` or `disable`
+    #[serde(rename = "powerSqlMode")]
+    pub power_sql_mode: String,
+    /// RAM role used to access a store in another account
+    #[serde(rename = "roleArn", skip_serializing_if = "String::is_empty")]
+    pub role_arn: String,
+}
+
+impl Default for AlertQuery {
+    fn default() -> Self {
+        Self {
+            store_type: "log".to_string(),
+            region: String::new(),
+            project: String::new(),
+            store: String::new(),
+            query: String::new(),
+            time_span_type: "Relative".to_string(),
+            start: "-15m".to_string(),
+            end: "now".to_string(),
+            power_sql_mode: "auto".to_string(),
+            role_arn: String::new(),
+        }
+    }
+}
+
+impl AlertQuery {
+    /// Create a query on a logstore over the last 15 minutes.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - Project of the logstore
+    /// * `store` - Logstore name
+    /// * `query` - The query statement
+    pub fn new(
+        project: impl Into<String>,
+        store: impl Into<String>,
+        query: impl Into<String>,
+    ) -> Self {
+        Self {
+            project: project.into(),
+            store: store.into(),
+            query: query.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the store type, `log`, `metric` or `meta`.
+    pub fn store_type(mut self, store_type: impl Into<String>) -> Self {
+        self.store_type = store_type.into();
+        self
+    }
+
+    /// Set the relative time range of the query, e.g. `-15m` to `now`.
+    pub fn time_range(mut self, start: impl Into<String>, end: impl Into<String>) -> Self {
+        self.start = start.into();
+        self.end = end.into();
+        self
+    }
+}
+
+/// Alert severity.
+///
+/// Severities which are not known by this version of the SDK are kept as [`AlertSeverity::Unknown`].
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+#[non_exhaustive]
+pub enum AlertSeverity {
+    Report,
+    Low,
+    #[default]
+    Medium,
+    High,
+    Critical,
+    /// A severity not known by this version of the SDK, with its value
+    Unknown(i32),
+}
+
+impl From<AlertSeverity> for i32 {
+    fn from(value: AlertSeverity) -> Self {
+        match value {
+            AlertSeverity::Report => 2,
+            AlertSeverity::Low => 4,
+            AlertSeverity::Medium => 6,
+            AlertSeverity::High => 8,
+            AlertSeverity::Critical => 10,
+            AlertSeverity::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for AlertSeverity {
+    fn from(value: i32) -> Self {
+        match value {
+            2 => AlertSeverity::Report,
+            4 => AlertSeverity::Low,
+            6 => AlertSeverity::Medium,
+            8 => AlertSeverity::High,
+            10 => AlertSeverity::Critical,
+            _ => AlertSeverity::Unknown(value),
+        }
+    }
+}
+
+/// Severities are compared and ordered by their value, unknown ones included,
+/// so that `Unknown(8)` is equal to `High`.
+impl PartialEq for AlertSeverity {
+    fn eq(&self, other: &Self) -> bool {
+        i32::from(*self) == i32::from(*other)
+    }
+}
+
+impl Eq for AlertSeverity {}
+
+impl PartialOrd for AlertSeverity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AlertSeverity {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        i32::from(*self).cmp(&i32::from(*other))
+    }
+}
+
+/// A trigger condition and the severity of the alert when it matches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertSeverityConfiguration {
+    /// Severity of the alert
+    pub severity: AlertSeverity,
+    /// The trigger condition
+    #[serde(rename = "evalCondition")]
+    pub eval_condition: AlertEvalCondition,
+}
+
+impl AlertSeverityConfiguration {
+    pub fn new(severity: AlertSeverity, eval_condition: AlertEvalCondition) -> Self {
+        Self {
+            severity,
+            eval_condition,
+        }
+    }
+}
+
+/// Trigger condition of an alert.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertEvalCondition {
+    /// Condition evaluated on each result row, e.g. `cnt > 100`, empty means any data
+    pub condition: String,
+    /// Condition evaluated on the number of result rows, e.g. `__count__ > 3`
+    #[serde(rename = "countCondition")]
+    pub count_condition: String,
+}
+
+impl AlertEvalCondition {
+    /// Create a condition evaluated on each result row.
+    pub fn new(condition: impl Into<String>) -> Self {
+        Self {
+            condition: condition.into(),
+            count_condition: String::new(),
+        }
+    }
+
+    /// Create a condition evaluated on the number of result rows.
+    pub fn count(count_condition: impl Into<String>) -> Self {
+        Self {
+            condition: String::new(),
+            count_condition: count_condition.into(),
+        }
+    }
+}
+
+/// How the query results of an alert are grouped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertGroupConfiguration {
+    /// Group type, `no_group`, `labels_auto` or `custom`
+    #[serde(rename = "type")]
+    pub group_type: String,
+    /// Fields to group by, only used by `custom`
+    pub fields: Vec<String>,
+}
+
+impl Default for AlertGroupConfiguration {
+    fn default() -> Self {
+        Self {
+            group_type: "no_group".to_string(),
+            fields: vec![],
+        }
+    }
+}
+
+impl AlertGroupConfiguration {
+    /// Group by the given fields.
+    pub fn custom(fields: Vec<String>) -> Self {
+        Self {
+            group_type: "custom".to_string(),
+            fields,
+        }
+    }
+}
+
+/// How the results of two adjacent queries are joined.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertJoinConfiguration {
+    /// Join type, `cross_join`, `inner_join`, `left_join`, `right_join`, `full_join`,
+    /// `left_exclude`, `right_exclude`, `concat` or `no_join`
+    #[serde(rename = "type")]
+    pub join_type: String,
+    /// Join condition, e.g. `$0.host == $1.host`
+    #[serde(default)]
+    pub condition: String,
+}
+
+impl AlertJoinConfiguration {
+    pub fn new(join_type: impl Into<String>, condition: impl Into<String>) -> Self {
+        Self {
+            join_type: join_type.into(),
+            condition: condition.into(),
+        }
+    }
+}
+
+/// A label or annotation of an alert.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertTag {
+    pub key: String,
+    pub value: String,
+}
+
+impl AlertTag {
+    pub fn new(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// Notification policy of an alert.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertPolicyConfiguration {
+    /// Alert policy ID, decides how alerts are merged, silenced and routed
+    #[serde(rename = "alertPolicyId")]
+    pub alert_policy_id: String,
+    /// Action policy ID, decides how notifications are sent,
+    /// only used by the dynamic alert policy
+    #[serde(rename = "actionPolicyId", skip_serializing_if = "String::is_empty")]
+    pub action_policy_id: String,
+    /// Minimum interval between repeated notifications, e.g. `1m`
+    #[serde(rename = "repeatInterval")]
+    pub repeat_interval: String,
+    /// Whether to use the default policy
+    #[serde(rename = "useDefault")]
+    pub use_default: bool,
+}
+
+impl Default for AlertPolicyConfiguration {
+    fn default() -> Self {
+        Self::new(DEFAULT_ALERT_POLICY_ID)
+    }
+}
+
+impl AlertPolicyConfiguration {
+    /// Create a notification policy with the given alert policy ID.
+    pub fn new(alert_policy_id: impl Into<String>) -> Self {
+        Self {
+            alert_policy_id: alert_policy_id.into(),
+            action_policy_id: String::new(),
+            repeat_interval: "1m".to_string(),
+            use_default: false,
+        }
+    }
+
+    /// Set the action policy ID.
+    pub fn action_policy_id(mut self, action_policy_id: impl Into<String>) -> Self {
+        self.action_policy_id = action_policy_id.into();
+        self
+    }
+
+    /// Set the minimum interval between repeated notifications, e.g. `5m`.
+    pub fn repeat_interval(mut self, repeat_interval: impl Into<String>) -> Self {
+        self.repeat_interval = repeat_interval.into();
+        self
+    }
+}
+
+const DEFAULT_ALERT_VERSION: &str = "2.0";
+const DEFAULT_ALERT_TYPE: &str = "default";
+const DEFAULT_ALERT_DASHBOARD: &str = "internal-alert-analysis";
+const DEFAULT_ALERT_POLICY_ID: &str = "sls.builtin.dynamic";
+pub(crate) const JOB_TYPE_ALERT: &str = "Alert";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alert_severity() {
+        let severity: AlertSeverity = serde_json::from_str("8").unwrap();
+        assert_eq!(severity, AlertSeverity::High);
+        let severity: AlertSeverity = serde_json::from_str("9").unwrap();
+        assert_eq!(severity, AlertSeverity::Unknown(9));
+        assert_eq!(serde_json::to_string(&severity).unwrap(), "9");
+        assert!(AlertSeverity::High < severity && severity < AlertSeverity::Critical);
+
+        // equality agrees with the order
+        assert_eq!(AlertSeverity::Unknown(8), AlertSeverity::High);
+        assert_eq!(
+            AlertSeverity::Unknown(8).cmp(&AlertSeverity::High),
+            std::cmp::Ordering::Equal
+        );
+        assert_ne!(AlertSeverity::Unknown(9), AlertSeverity::High);
+    }
+}
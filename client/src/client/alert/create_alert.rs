@@ -0,0 +1,182 @@
+use super::*;
+use crate::RequestErrorKind;
+
+impl crate::client::Client {
+    /// Create a new alert rule in a project.
+    ///
+    /// The alert is enabled once created, use [`disable_alert`](crate::Client::disable_alert)
+    /// to stop it from being evaluated.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `alert_name` - The name of the alert, unique within the project
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::{
+    ///     AlertConfiguration, AlertEvalCondition, AlertQuery, AlertSeverity,
+    ///     AlertSeverityConfiguration, Schedule,
+    /// };
+    ///
+    /// let configuration = AlertConfiguration::builder()
+    ///     .query(AlertQuery::new("my-project", "my-logstore", "level: ERROR | select count(*) as cnt"))
+    ///     .severity(AlertSeverityConfiguration::new(
+    ///         AlertSeverity::High,
+    ///         AlertEvalCondition::new("cnt > 100"),
+    ///     ))
+    ///     .annotation("title", "Too many errors")
+    ///     .build();
+    ///
+    /// client.create_alert("my-project", "error-count-alert")
+    ///     .display_name("Error count alert")
+    ///     .configuration(configuration)
+    ///     .schedule(Schedule::fixed_rate("1m"))
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_alert(
+        &self,
+        project: impl AsRef<str>,
+        alert_name: impl AsRef<str>,
+    ) -> CreateAlertRequestBuilder {
+        CreateAlertRequestBuilder {
+            project: project.as_ref().to_string(),
+            handle: self.handle.clone(),
+            alert_name: alert_name.as_ref().to_string(),
+            display_name: None,
+            description: None,
+            configuration: None,
+            schedule: None,
+        }
+    }
+}
+
+pub struct CreateAlertRequestBuilder {
+    project: String,
+    handle: HandleRef,
+    alert_name: String,
+    display_name: Option<String>,
+    description: Option<String>,
+    configuration: Option<AlertConfiguration>,
+    schedule: Option<Schedule>,
+}
+
+request_options!(CreateAlertRequestBuilder);
+
+impl CreateAlertRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Set the display name of the alert (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `display_name` - Display name of the alert
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// Set the description of the alert (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `description` - Description of the alert
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the alert configuration (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - Queries, trigger conditions and notification policy of the alert
+    pub fn configuration(mut self, configuration: AlertConfiguration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+
+    /// Set how often the alert is evaluated (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule` - Schedule of the alert, e.g. `Schedule::fixed_rate("1m")`
+    pub fn schedule(mut self, schedule: Schedule) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
+    fn build(self) -> BuildResult<CreateAlertRequest> {
+        check_required!(
+            ("display_name", self.display_name),
+            ("configuration", self.configuration),
+            ("schedule", self.schedule)
+        );
+        Ok((
+            self.handle,
+            CreateAlertRequest {
+                project: self.project,
+                name: self.alert_name,
+                display_name: self.display_name.unwrap(),
+                description: self.description,
+                job_type: JOB_TYPE_ALERT,
+                state: "Enabled",
+                configuration: self.configuration.unwrap(),
+                schedule: self.schedule.unwrap(),
+            },
+        ))
+    }
+}
+
+#[derive(Serialize)]
+struct CreateAlertRequest {
+    #[serde(skip_serializing)]
+    project: String,
+
+    name: String,
+
+    #[serde(rename = "displayName")]
+    display_name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    #[serde(rename = "type")]
+    job_type: &'static str,
+
+    state: &'static str,
+
+    configuration: AlertConfiguration,
+
+    schedule: Schedule,
+}
+
+impl Request for CreateAlertRequest {
+    const HTTP_METHOD: http::Method = http::Method::POST;
+    const CONTENT_TYPE: Option<http::HeaderValue> = Some(LOG_JSON);
+    type ResponseBody = ();
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        "/jobs"
+    }
+
+    fn body(&self) -> crate::Result<Option<bytes::Bytes>, RequestError> {
+        let json = serde_json::to_string(&self).map_err(RequestErrorKind::JsonEncode)?;
+        Ok(Some(bytes::Bytes::from(json)))
+    }
+}
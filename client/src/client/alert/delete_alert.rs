@@ -0,0 +1,78 @@
+use super::*;
+
+impl crate::client::Client {
+    /// Delete an alert rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `alert_name` - The name of the alert to delete
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// client.delete_alert("my-project", "error-count-alert")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_alert(
+        &self,
+        project: impl AsRef<str>,
+        alert_name: impl AsRef<str>,
+    ) -> DeleteAlertRequestBuilder {
+        DeleteAlertRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/jobs/{}", alert_name.as_ref()),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+pub struct DeleteAlertRequestBuilder {
+    handle: HandleRef,
+    project: String,
+    path: String,
+}
+
+request_options!(DeleteAlertRequestBuilder);
+
+impl DeleteAlertRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<DeleteAlertRequest> {
+        Ok((
+            self.handle,
+            DeleteAlertRequest {
+                project: self.project,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+struct DeleteAlertRequest {
+    project: String,
+    path: String,
+}
+
+impl Request for DeleteAlertRequest {
+    type ResponseBody = ();
+    const HTTP_METHOD: http::Method = http::Method::DELETE;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+}
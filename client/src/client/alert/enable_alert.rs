@@ -0,0 +1,82 @@
+use super::*;
+
+impl crate::client::Client {
+    /// Enable an alert rule, so that it is evaluated on its schedule again.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `alert_name` - The name of the alert to enable
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// client.enable_alert("my-project", "error-count-alert")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn enable_alert(
+        &self,
+        project: impl AsRef<str>,
+        alert_name: impl AsRef<str>,
+    ) -> EnableAlertRequestBuilder {
+        EnableAlertRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/jobs/{}", alert_name.as_ref()),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+pub struct EnableAlertRequestBuilder {
+    handle: HandleRef,
+    project: String,
+    path: String,
+}
+
+request_options!(EnableAlertRequestBuilder);
+
+impl EnableAlertRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<EnableAlertRequest> {
+        Ok((
+            self.handle,
+            EnableAlertRequest {
+                project: self.project,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+struct EnableAlertRequest {
+    project: String,
+    path: String,
+}
+
+impl Request for EnableAlertRequest {
+    type ResponseBody = ();
+    const HTTP_METHOD: http::Method = http::Method::PUT;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        Some(vec![("action".to_string(), "enable".to_string())])
+    }
+}
@@ -0,0 +1,90 @@
+use super::*;
+use crate::ResponseResult;
+
+impl crate::client::Client {
+    /// Get an alert rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `alert_name` - The name of the alert to get
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let resp = client.get_alert("my-project", "error-count-alert")
+    ///     .send()
+    ///     .await?;
+    /// let alert = resp.get_body();
+    /// println!("Alert {} enabled: {}", alert.display_name(), alert.is_enabled());
+    /// for query in &alert.configuration().query_list {
+    ///     println!("  {}/{}: {}", query.project, query.store, query.query);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_alert(
+        &self,
+        project: impl AsRef<str>,
+        alert_name: impl AsRef<str>,
+    ) -> GetAlertRequestBuilder {
+        GetAlertRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/jobs/{}", alert_name.as_ref()),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+pub struct GetAlertRequestBuilder {
+    handle: HandleRef,
+    project: String,
+    path: String,
+}
+
+request_options!(GetAlertRequestBuilder);
+
+impl GetAlertRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<Alert> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<GetAlertRequest> {
+        Ok((
+            self.handle,
+            GetAlertRequest {
+                project: self.project,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+struct GetAlertRequest {
+    project: String,
+    path: String,
+}
+
+impl Request for GetAlertRequest {
+    type ResponseBody = Alert;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl FromHttpResponse for Alert {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
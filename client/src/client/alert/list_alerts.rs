@@ -0,0 +1,119 @@
+use super::*;
+use crate::ResponseResult;
+
+impl crate::client::Client {
+    /// List alert rules in a project with pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `offset` - The offset for pagination (starting from 0)
+    /// * `size` - The number of alerts to return (page size)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let resp = client.list_alerts("my-project", 0, 100)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// println!("Total alerts: {}", resp.get_body().total());
+    /// for alert in resp.get_body().alerts() {
+    ///     println!("Alert: {}, state: {}", alert.name(), alert.state());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_alerts(
+        &self,
+        project: impl AsRef<str>,
+        offset: i32,
+        size: i32,
+    ) -> ListAlertsRequestBuilder {
+        ListAlertsRequestBuilder {
+            project: project.as_ref().to_string(),
+            handle: self.handle.clone(),
+            offset,
+            size,
+        }
+    }
+}
+
+pub struct ListAlertsRequestBuilder {
+    project: String,
+    handle: HandleRef,
+    offset: i32,
+    size: i32,
+}
+
+request_options!(ListAlertsRequestBuilder);
+
+impl ListAlertsRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<ListAlertsResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<ListAlertsRequest> {
+        Ok((
+            self.handle,
+            ListAlertsRequest {
+                project: self.project,
+                offset: self.offset,
+                size: self.size,
+            },
+        ))
+    }
+}
+
+struct ListAlertsRequest {
+    project: String,
+    offset: i32,
+    size: i32,
+}
+
+impl Request for ListAlertsRequest {
+    type ResponseBody = ListAlertsResponse;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        "/jobs"
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        Some(vec![
+            ("jobType".to_string(), JOB_TYPE_ALERT.to_string()),
+            ("offset".to_string(), self.offset.to_string()),
+            ("size".to_string(), self.size.to_string()),
+        ])
+    }
+}
+
+/// Response containing a list of alerts
+#[derive(Debug, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct ListAlertsResponse {
+    /// Number of alerts returned in this response
+    count: i32,
+
+    /// Total number of alerts in the project
+    total: i32,
+
+    /// List of alerts
+    #[serde(rename = "results", default)]
+    alerts: Vec<Alert>,
+}
+
+impl FromHttpResponse for ListAlertsResponse {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
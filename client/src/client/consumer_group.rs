@@ -21,3 +21,9 @@ pub use get_consumer_group_checkpoint::*;
 
 mod update_consumer_group_checkpoint;
 pub use update_consumer_group_checkpoint::*;
+
+mod ensure_consumer_group;
+pub use ensure_consumer_group::*;
+
+mod get_consumer_group_status;
+pub use get_consumer_group_status::*;
@@ -63,6 +63,8 @@ pub struct ConsumerGroupHeartbeatRequestBuilder {
     shards: Vec<i32>,
 }
 
+request_options!(ConsumerGroupHeartbeatRequestBuilder);
+
 impl ConsumerGroupHeartbeatRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<ConsumerGroupHeartbeatResponse> {
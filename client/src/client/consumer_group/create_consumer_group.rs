@@ -52,6 +52,8 @@ pub struct CreateConsumerGroupRequestBuilder {
     order: Option<bool>,
 }
 
+request_options!(CreateConsumerGroupRequestBuilder);
+
 impl CreateConsumerGroupRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<()> {
@@ -46,6 +46,8 @@ pub struct DeleteConsumerGroupRequestBuilder {
     path: String,
 }
 
+request_options!(DeleteConsumerGroupRequestBuilder);
+
 impl DeleteConsumerGroupRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<()> {
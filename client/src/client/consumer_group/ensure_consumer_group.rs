@@ -0,0 +1,137 @@
+use super::*;
+use crate::ErrorCode;
+
+impl crate::client::Client {
+    /// Create a consumer group if it does not exist, or update it to the desired settings.
+    ///
+    /// `timeout` and `order` are required when the consumer group does not exist.
+    /// Otherwise the settings set are compared and updated when they differ,
+    /// and the outcome lists the fields updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `logstore` - The name of the logstore
+    /// * `consumer_group` - The name of the consumer group
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let outcome = client.ensure_consumer_group("my-project", "my-logstore", "my-consumer-group")
+    ///     .timeout(60)
+    ///     .order(true)
+    ///     .send()
+    ///     .await?;
+    /// println!("changed fields: {:?}", outcome.changed_fields());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ensure_consumer_group(
+        &self,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+        consumer_group: impl AsRef<str>,
+    ) -> EnsureConsumerGroupRequestBuilder {
+        EnsureConsumerGroupRequestBuilder {
+            project: project.as_ref().to_string(),
+            logstore: logstore.as_ref().to_string(),
+            handle: self.handle.clone(),
+            consumer_group: consumer_group.as_ref().to_string(),
+            timeout: None,
+            order: None,
+        }
+    }
+}
+
+pub struct EnsureConsumerGroupRequestBuilder {
+    project: String,
+    logstore: String,
+    handle: HandleRef,
+    consumer_group: String,
+    timeout: Option<i32>,
+    order: Option<bool>,
+}
+
+request_options!(EnsureConsumerGroupRequestBuilder);
+
+impl EnsureConsumerGroupRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> BoxFuture<Result<EnsureOutcome>> {
+        Box::pin(async move {
+            let client = crate::Client {
+                handle: self.handle,
+            };
+            // consumer groups can only be listed, not fetched one by one
+            let get = || async {
+                let groups = client
+                    .list_consumer_groups(&self.project, &self.logstore)
+                    .collect_all()
+                    .await?;
+                Ok::<_, Error>(
+                    groups
+                        .into_iter()
+                        .find(|group| group.consumer_group_name() == &self.consumer_group),
+                )
+            };
+            let current = match get().await? {
+                Some(group) => group,
+                None => {
+                    let mut create = client.create_consumer_group(
+                        &self.project,
+                        &self.logstore,
+                        &self.consumer_group,
+                    );
+                    if let Some(timeout) = self.timeout {
+                        create = create.timeout(timeout);
+                    }
+                    if let Some(order) = self.order {
+                        create = create.order(order);
+                    }
+                    match create.send().await {
+                        Ok(_) => return Ok(EnsureOutcome::Created),
+                        // created concurrently, compare with what was created instead
+                        Err(err) if err.code() == Some(ErrorCode::ConsumerGroupAlreadyExist) => {
+                            get().await?.ok_or(err)?
+                        }
+                        Err(err) => return Err(err),
+                    }
+                }
+            };
+
+            let mut changes = FieldChanges::default();
+            let timeout = changes.check("timeout", self.timeout, Some(*current.timeout()));
+            let order = changes.check("order", self.order, Some(*current.order()));
+            if !changes.is_empty() {
+                // both settings are required by the update
+                client
+                    .update_consumer_group(&self.project, &self.logstore, &self.consumer_group)
+                    .timeout(timeout.unwrap_or(*current.timeout()))
+                    .order(order.unwrap_or(*current.order()))
+                    .send()
+                    .await?;
+            }
+            Ok(changes.into_outcome())
+        })
+    }
+
+    /// Set the heartbeat timeout in seconds, required if the consumer group does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Timeout in seconds after which a consumer without heartbeat is removed
+    pub fn timeout(mut self, timeout: i32) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set whether to consume in order, required if the consumer group does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Whether the data of a shard is consumed in order of writing
+    pub fn order(mut self, order: bool) -> Self {
+        self.order = Some(order);
+        self
+    }
+}
@@ -70,6 +70,8 @@ pub struct GetConsumerGroupCheckpointRequestBuilder {
     shard_id: Option<i32>,
 }
 
+request_options!(GetConsumerGroupCheckpointRequestBuilder);
+
 impl GetConsumerGroupCheckpointRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<GetConsumerGroupCheckpointResponse> {
@@ -150,9 +152,31 @@ pub struct ConsumerGroupCheckpoint {
     shard_id: i32,
     /// The checkpoint value (cursor)
     checkpoint: String,
-    /// The timestamp when this checkpoint was last updated
+    /// The timestamp when this checkpoint was last updated, in unix timestamp, in microseconds
     #[serde(rename = "updateTime")]
     update_time: i64,
     /// The consumer that owns this checkpoint
     consumer: String,
 }
+
+impl ConsumerGroupCheckpoint {
+    /// Returns true if a checkpoint has been saved for the shard.
+    pub fn has_checkpoint(&self) -> bool {
+        !self.checkpoint.is_empty()
+    }
+
+    /// The offset of the checkpoint in the shard, `None` if no valid checkpoint has been saved.
+    pub fn offset(&self) -> Option<u64> {
+        get_cursor_models::cursor_offset(&self.checkpoint)
+    }
+
+    /// The time when this checkpoint was last updated, in unix timestamp, in seconds.
+    pub fn update_time_secs(&self) -> i64 {
+        self.update_time / 1_000_000
+    }
+
+    /// The consumer that owns the shard, `None` if no consumer holds it.
+    pub fn owner(&self) -> Option<&str> {
+        Some(self.consumer.as_str()).filter(|consumer| !consumer.is_empty())
+    }
+}
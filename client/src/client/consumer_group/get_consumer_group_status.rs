@@ -0,0 +1,403 @@
+use super::*;
+use futures_util::{StreamExt, TryStreamExt};
+use getset::Getters;
+use std::collections::HashMap;
+
+impl crate::client::Client {
+    /// Get how far behind the end of each shard a consumer group is.
+    ///
+    /// The checkpoints of the consumer group are compared with the end cursors of the shards,
+    /// giving for each shard the number of log groups not consumed yet, and how long before the
+    /// last data received the data at the checkpoint was received, along with the consumer owning it.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project containing the logstore
+    /// * `logstore` - The name of the logstore containing the consumer group
+    /// * `consumer_group` - The name of the consumer group
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let status = client
+    ///     .get_consumer_group_status("my-project", "my-logstore", "my-consumer-group")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for shard in status.shards() {
+    ///     println!(
+    ///         "Shard {} owned by {:?}: {:?} log groups, {:?} seconds behind",
+    ///         shard.shard_id(),
+    ///         shard.consumer(),
+    ///         shard.cursor_lag(),
+    ///         shard.time_lag()
+    ///     );
+    /// }
+    /// if status.max_time_lag().unwrap_or(0) > 600 {
+    ///     println!("The consumer group is more than 10 minutes behind");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_consumer_group_status(
+        &self,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+        consumer_group: impl AsRef<str>,
+    ) -> GetConsumerGroupStatusRequestBuilder {
+        GetConsumerGroupStatusRequestBuilder {
+            project: project.as_ref().to_string(),
+            logstore: logstore.as_ref().to_string(),
+            handle: self.handle.clone(),
+            consumer_group: consumer_group.as_ref().to_string(),
+            concurrency: None,
+        }
+    }
+}
+
+pub struct GetConsumerGroupStatusRequestBuilder {
+    project: String,
+    logstore: String,
+    handle: HandleRef,
+    consumer_group: String,
+    concurrency: Option<usize>,
+}
+
+request_options!(GetConsumerGroupStatusRequestBuilder);
+
+impl GetConsumerGroupStatusRequestBuilder {
+    /// Set how many shards are looked up at a time, default is 8.
+    ///
+    /// # Arguments
+    ///
+    /// * `concurrency` - Maximum number of shards looked up concurrently, each with up to 4 requests
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = Some(concurrency);
+        self
+    }
+
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> BoxFuture<Result<ConsumerGroupStatus>> {
+        Box::pin(async move {
+            let client = crate::Client {
+                handle: self.handle,
+            };
+            let (shards, checkpoints) = futures_util::future::try_join(
+                client.list_shards(&self.project, &self.logstore).send(),
+                client
+                    .get_consumer_group_checkpoint(
+                        &self.project,
+                        &self.logstore,
+                        &self.consumer_group,
+                    )
+                    .send(),
+            )
+            .await?;
+            let checkpoints: HashMap<i32, &ConsumerGroupCheckpoint> = checkpoints
+                .get_body()
+                .checkpoints()
+                .iter()
+                .map(|checkpoint| (*checkpoint.shard_id(), checkpoint))
+                .collect();
+            // collected first, as the compiler fails to prove the stream `Send` otherwise
+            let shards: Vec<_> = shards
+                .get_body()
+                .shards()
+                .iter()
+                .map(|shard| {
+                    let checkpoint = checkpoints.get(shard.shard_id()).copied();
+                    get_shard_lag(&client, &self.project, &self.logstore, shard, checkpoint)
+                })
+                .collect();
+            let concurrency = self.concurrency.unwrap_or(DEFAULT_CONCURRENCY).max(1);
+            Ok(ConsumerGroupStatus {
+                shards: futures_util::stream::iter(shards)
+                    .buffered(concurrency)
+                    .try_collect()
+                    .await?,
+            })
+        })
+    }
+}
+
+const DEFAULT_CONCURRENCY: usize = 8;
+
+async fn get_shard_lag(
+    client: &crate::Client,
+    project: &str,
+    logstore: &str,
+    shard: &list_shards_models::Shard,
+    checkpoint: Option<&ConsumerGroupCheckpoint>,
+) -> Result<ShardLag> {
+    let shard_id = *shard.shard_id();
+    let get_cursor = |cursor_pos| {
+        client
+            .get_cursor(project, logstore, shard_id)
+            .cursor_pos(cursor_pos)
+            .send()
+    };
+    let cursor_time = |cursor: String| get_cursor_time(client, project, logstore, shard_id, cursor);
+    let (begin, end) = futures_util::future::try_join(
+        get_cursor(get_cursor_models::CursorPos::Begin),
+        get_cursor(get_cursor_models::CursorPos::End),
+    )
+    .await?;
+    let begin_cursor = begin.get_body().cursor();
+    let end_cursor = end.get_body().cursor().to_string();
+    let begin_offset = get_cursor_models::cursor_offset(begin_cursor);
+    let end_offset = get_cursor_models::cursor_offset(&end_cursor);
+
+    // the end cursor is past the last log group, so the time of the shard is the one of the log
+    // group before it, unless the shard holds no data, e.g. if it expired
+    let end_time = match (begin_offset, end_offset) {
+        (Some(begin), Some(end)) if end > begin => {
+            Some(cursor_time(get_cursor_models::cursor_at_offset(end - 1)).await?)
+        }
+        _ => None,
+    };
+    let checkpoint = checkpoint.filter(|checkpoint| checkpoint.has_checkpoint());
+    let position =
+        checkpoint.map(|checkpoint| checkpoint_position(checkpoint, begin_cursor, &end_cursor));
+    let checkpoint_time = match (checkpoint, position) {
+        (_, Some(CheckpointPosition::End)) => end_time,
+        // nothing consumed yet, or the data at the checkpoint expired, so the consumer is at least
+        // as far behind as the oldest data of the shard, if it holds any
+        (_, Some(CheckpointPosition::Begin | CheckpointPosition::Expired)) => match end_time {
+            Some(_) => Some(cursor_time(begin_cursor.to_string()).await?),
+            None => None,
+        },
+        (Some(checkpoint), Some(CheckpointPosition::Within)) => {
+            Some(cursor_time(checkpoint.checkpoint().clone()).await?)
+        }
+        _ => None,
+    };
+    Ok(ShardLag::new(
+        shard,
+        checkpoint,
+        end_cursor,
+        end_time,
+        checkpoint_time,
+        position == Some(CheckpointPosition::Expired),
+    ))
+}
+
+/// Where a checkpoint is in a shard, which tells how the time of its data is found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckpointPosition {
+    /// At the beginning of the shard, nothing consumed yet
+    Begin,
+    /// Before the beginning of the shard, the data at the checkpoint expired
+    Expired,
+    /// At the end of the shard, all the data consumed
+    End,
+    /// Between the beginning and the end of the shard
+    Within,
+}
+
+fn checkpoint_position(
+    checkpoint: &ConsumerGroupCheckpoint,
+    begin_cursor: &str,
+    end_cursor: &str,
+) -> CheckpointPosition {
+    let expired = checkpoint
+        .offset()
+        .zip(get_cursor_models::cursor_offset(begin_cursor))
+        .is_some_and(|(offset, begin_offset)| offset < begin_offset);
+    if checkpoint.checkpoint() == end_cursor {
+        CheckpointPosition::End
+    } else if checkpoint.checkpoint() == begin_cursor {
+        CheckpointPosition::Begin
+    } else if expired {
+        CheckpointPosition::Expired
+    } else {
+        CheckpointPosition::Within
+    }
+}
+
+async fn get_cursor_time(
+    client: &crate::Client,
+    project: &str,
+    logstore: &str,
+    shard_id: i32,
+    cursor: String,
+) -> Result<i64> {
+    let resp = client
+        .get_cursor_time(project, logstore, shard_id, cursor)
+        .send()
+        .await?;
+    Ok(*resp.get_body().cursor_time())
+}
+
+/// How far behind the end of each shard a consumer group is.
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct ConsumerGroupStatus {
+    /// The lag of each shard of the logstore
+    shards: Vec<ShardLag>,
+}
+
+impl ConsumerGroupStatus {
+    /// The largest time lag of the shards, in seconds, `None` if no checkpoint has been saved.
+    pub fn max_time_lag(&self) -> Option<i64> {
+        self.shards.iter().filter_map(|shard| shard.time_lag).max()
+    }
+
+    /// The number of log groups not consumed yet in the shards with a checkpoint.
+    pub fn total_cursor_lag(&self) -> u64 {
+        self.shards
+            .iter()
+            .filter_map(|shard| shard.cursor_lag)
+            .sum()
+    }
+}
+
+/// How far behind the end of a shard a consumer group is.
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct ShardLag {
+    /// The shard ID
+    shard_id: i32,
+    /// The status of the shard, `readwrite` or `readonly`
+    status: String,
+    /// The consumer owning the shard, `None` if no consumer holds it
+    consumer: Option<String>,
+    /// The time when the checkpoint was last updated, in unix timestamp, in seconds
+    update_time: Option<i64>,
+    /// The checkpoint of the consumer group, `None` if none has been saved
+    checkpoint: Option<String>,
+    /// The cursor at the end of the shard
+    end_cursor: String,
+    /// The number of log groups between the checkpoint and the end of the shard
+    cursor_lag: Option<u64>,
+    /// The time the data at the checkpoint was received, in unix timestamp, in seconds,
+    /// `None` if the shard holds no data.
+    ///
+    /// When the checkpoint is at the beginning of the shard, or its data expired,
+    /// this is the time of the oldest data of the shard.
+    checkpoint_time: Option<i64>,
+    /// The time the last data of the shard was received, in unix timestamp, in seconds,
+    /// `None` if the shard holds no data
+    end_time: Option<i64>,
+    /// The number of seconds between the data at the checkpoint and the last data of the shard,
+    /// a lower bound if the data at the checkpoint [`expired`](Self::expired)
+    time_lag: Option<i64>,
+    /// Whether the data at the checkpoint expired before being consumed
+    expired: bool,
+}
+
+impl ShardLag {
+    fn new(
+        shard: &list_shards_models::Shard,
+        checkpoint: Option<&ConsumerGroupCheckpoint>,
+        end_cursor: String,
+        end_time: Option<i64>,
+        checkpoint_time: Option<i64>,
+        expired: bool,
+    ) -> Self {
+        let end_offset = get_cursor_models::cursor_offset(&end_cursor);
+        let cursor_lag = checkpoint
+            .and_then(|checkpoint| checkpoint.offset())
+            .zip(end_offset)
+            .map(|(offset, end_offset)| end_offset.saturating_sub(offset));
+        ShardLag {
+            shard_id: *shard.shard_id(),
+            status: shard.status().clone(),
+            consumer: checkpoint
+                .and_then(|checkpoint| checkpoint.owner())
+                .map(str::to_string),
+            update_time: checkpoint.map(|checkpoint| checkpoint.update_time_secs()),
+            checkpoint: checkpoint.map(|checkpoint| checkpoint.checkpoint().clone()),
+            end_cursor,
+            cursor_lag,
+            checkpoint_time,
+            end_time,
+            time_lag: checkpoint_time
+                .zip(end_time)
+                .map(|(time, end_time)| (end_time - time).max(0)),
+            expired,
+        }
+    }
+
+    /// Returns true if all the data of the shard has been consumed.
+    pub fn is_caught_up(&self) -> bool {
+        self.cursor_lag == Some(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn cursor(offset: u64) -> String {
+        get_cursor_models::cursor_at_offset(offset)
+    }
+
+    #[test]
+    fn test_shard_lag() {
+        let shard: list_shards_models::Shard = serde_json::from_value(json!({
+            "shardID": 1, "status": "readwrite", "inclusiveBeginKey": "00", "exclusiveEndKey": "ff", "createTime": 1700000000
+        }))
+        .unwrap();
+        let checkpoint: ConsumerGroupCheckpoint = serde_json::from_value(json!({
+            "shard": 1, "checkpoint": cursor(1000), "updateTime": 1700000600123456i64, "consumer": "consumer-1"
+        }))
+        .unwrap();
+
+        let lag = ShardLag::new(
+            &shard,
+            Some(&checkpoint),
+            cursor(1042),
+            Some(1700000900),
+            Some(1700000300),
+            false,
+        );
+        assert_eq!(*lag.cursor_lag(), Some(42));
+        assert_eq!(*lag.time_lag(), Some(600));
+        assert_eq!(lag.consumer().as_deref(), Some("consumer-1"));
+        assert_eq!(*lag.update_time(), Some(1700000600));
+        assert!(!lag.is_caught_up());
+
+        let lag = ShardLag::new(&shard, None, cursor(1042), None, None, false);
+        assert_eq!(*lag.cursor_lag(), None);
+        assert_eq!(*lag.time_lag(), None);
+        let status = ConsumerGroupStatus { shards: vec![lag] };
+        assert_eq!(status.max_time_lag(), None);
+        assert_eq!(status.total_cursor_lag(), 0);
+
+        // nothing consumed yet: the time of the checkpoint is the one of the beginning of the shard
+        let position = checkpoint_position(&checkpoint, &cursor(1000), &cursor(1042));
+        assert_eq!(position, CheckpointPosition::Begin);
+        let lag = ShardLag::new(
+            &shard,
+            Some(&checkpoint),
+            cursor(1042),
+            Some(1700000900),
+            Some(1700000000),
+            false,
+        );
+        assert_eq!(*lag.time_lag(), Some(900));
+
+        // the data at the checkpoint expired: the time lag is a lower bound
+        let position = checkpoint_position(&checkpoint, &cursor(1010), &cursor(1042));
+        assert_eq!(position, CheckpointPosition::Expired);
+        let lag = ShardLag::new(
+            &shard,
+            Some(&checkpoint),
+            cursor(1042),
+            Some(1700000900),
+            Some(1700000100),
+            true,
+        );
+        assert_eq!(*lag.time_lag(), Some(800));
+        assert!(*lag.expired());
+        let status = ConsumerGroupStatus { shards: vec![lag] };
+        assert_eq!(status.max_time_lag(), Some(800));
+
+        let position = checkpoint_position(&checkpoint, &cursor(900), &cursor(1042));
+        assert_eq!(position, CheckpointPosition::Within);
+        let position = checkpoint_position(&checkpoint, &cursor(900), &cursor(1000));
+        assert_eq!(position, CheckpointPosition::End);
+    }
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::ResponseResult;
+use futures_util::TryStreamExt;
 use getset::Getters;
 use serde::{Deserialize, Serialize};
 
@@ -51,6 +52,8 @@ pub struct ListConsumerGroupsRequestBuilder {
     path: String,
 }
 
+request_options!(ListConsumerGroupsRequestBuilder);
+
 impl ListConsumerGroupsRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<ListConsumerGroupsResponse> {
@@ -60,6 +63,38 @@ impl ListConsumerGroupsRequestBuilder {
         })
     }
 
+    /// Stream all the consumer groups of the logstore.
+    ///
+    /// The consumer groups of a logstore are listed in a single request,
+    /// this is provided for consistency with the other list APIs.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut groups = client.list_consumer_groups("my-project", "my-logstore").into_stream();
+    /// while let Some(group) = groups.try_next().await? {
+    ///     println!("Group: {}", group.consumer_group_name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> BoxStream<Result<ConsumerGroup>> {
+        Box::pin(
+            futures_util::stream::once(self.collect_all())
+                .map_ok(|groups| futures_util::stream::iter(groups.into_iter().map(Ok)))
+                .try_flatten(),
+        )
+    }
+
+    /// Collect all the consumer groups of the logstore.
+    #[must_use = "the result future must be awaited"]
+    pub fn collect_all(self) -> BoxFuture<Result<Vec<ConsumerGroup>>> {
+        Box::pin(async move { Ok(self.send().await?.take_body().consumer_groups) })
+    }
+
     fn build(self) -> BuildResult<ListConsumerGroupsRequest> {
         Ok((
             self.handle,
@@ -54,6 +54,8 @@ pub struct UpdateConsumerGroupRequestBuilder {
     order: Option<bool>,
 }
 
+request_options!(UpdateConsumerGroupRequestBuilder);
+
 impl UpdateConsumerGroupRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<()> {
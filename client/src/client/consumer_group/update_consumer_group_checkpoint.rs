@@ -81,6 +81,8 @@ pub struct UpdateCheckpointRequestBuilder {
     force_success: Option<bool>,
 }
 
+request_options!(UpdateCheckpointRequestBuilder);
+
 impl UpdateCheckpointRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<()> {
@@ -141,7 +143,7 @@ impl UpdateCheckpointRequestBuilder {
         self
     }
 
-    fn build(self) -> BuildResult<UpdateCheckpointRequest> {
+    fn build(self) -> BuildResult<UpdateConsumerGroupCheckpointRequest> {
         check_required!(
             ("shard_id", self.shard_id),
             ("checkpoint", self.checkpoint),
@@ -150,7 +152,7 @@ impl UpdateCheckpointRequestBuilder {
 
         Ok((
             self.handle,
-            UpdateCheckpointRequest {
+            UpdateConsumerGroupCheckpointRequest {
                 project: self.project,
                 path: self.path,
                 shard_id: self.shard_id.unwrap(),
@@ -163,7 +165,7 @@ impl UpdateCheckpointRequestBuilder {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct UpdateCheckpointRequest {
+struct UpdateConsumerGroupCheckpointRequest {
     #[serde(skip_serializing)]
     project: String,
     #[serde(skip_serializing)]
@@ -180,7 +182,7 @@ struct UpdateCheckpointRequest {
     checkpoint: String,
 }
 
-impl Request for UpdateCheckpointRequest {
+impl Request for UpdateConsumerGroupCheckpointRequest {
     const HTTP_METHOD: http::Method = http::Method::POST;
     const CONTENT_TYPE: Option<http::HeaderValue> = Some(LOG_JSON);
     type ResponseBody = ();
@@ -0,0 +1,88 @@
+/// Outcome of the `ensure_*` helpers, such as [`ensure_project`](crate::Client::ensure_project).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EnsureOutcome {
+    /// The resource did not exist and was created
+    Created,
+    /// The resource existed and the listed fields were updated
+    Updated(Vec<String>),
+    /// The resource existed and already matched the desired state
+    Unchanged,
+}
+
+impl EnsureOutcome {
+    /// The fields which were updated, empty if the resource was created or unchanged.
+    pub fn changed_fields(&self) -> &[String] {
+        match self {
+            EnsureOutcome::Updated(fields) => fields,
+            _ => &[],
+        }
+    }
+
+    /// Whether the resource was created or updated.
+    pub fn is_changed(&self) -> bool {
+        !matches!(self, EnsureOutcome::Unchanged)
+    }
+}
+
+/// Fields of a resource which differ from the desired state.
+#[derive(Default)]
+pub(crate) struct FieldChanges(Vec<String>);
+
+impl FieldChanges {
+    /// Return the desired value of a field if it is set and differs from the current value.
+    pub(crate) fn check<T: PartialEq>(
+        &mut self,
+        field: &str,
+        desired: Option<T>,
+        current: Option<T>,
+    ) -> Option<T> {
+        let desired = desired?;
+        if current.as_ref() == Some(&desired) {
+            return None;
+        }
+        self.0.push(field.to_string());
+        Some(desired)
+    }
+
+    pub(crate) fn push(&mut self, field: impl Into<String>) {
+        self.0.push(field.into());
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn into_outcome(self) -> EnsureOutcome {
+        if self.0.is_empty() {
+            EnsureOutcome::Unchanged
+        } else {
+            EnsureOutcome::Updated(self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_changes() {
+        let mut changes = FieldChanges::default();
+        assert_eq!(changes.check("ttl", Some(30), Some(30)), None);
+        assert_eq!(changes.check("hot_ttl", None, Some(7)), None);
+        assert_eq!(
+            changes.check("mode", Some("query"), Some("standard")),
+            Some("query")
+        );
+        assert_eq!(changes.check("max_split_shard", Some(64), None), Some(64));
+        assert_eq!(
+            changes.into_outcome(),
+            EnsureOutcome::Updated(vec!["mode".to_string(), "max_split_shard".to_string()])
+        );
+        assert_eq!(
+            FieldChanges::default().into_outcome(),
+            EnsureOutcome::Unchanged
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+mod create_etl;
+pub use create_etl::*;
+
+mod update_etl;
+pub use update_etl::*;
+
+mod delete_etl;
+pub use delete_etl::*;
+
+mod get_etl;
+pub use get_etl::*;
+
+mod list_etls;
+pub use list_etls::*;
+
+mod start_etl;
+pub use start_etl::*;
+
+mod stop_etl;
+pub use stop_etl::*;
+
+mod preview_etl;
+pub use preview_etl::*;
+
+use super::*;
+
+/// Data transformation (ETL) job information returned by the server.
+#[derive(Debug, Clone, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct Etl {
+    /// Job name, unique within the project
+    name: String,
+
+    /// Display name of the job
+    #[serde(rename = "displayName", default)]
+    display_name: String,
+
+    /// Job description
+    #[serde(default)]
+    description: Option<String>,
+
+    /// Job configuration
+    configuration: EtlConfiguration,
+
+    /// Job status, e.g. `STARTING`, `RUNNING`, `STOPPING`, `STOPPED`
+    #[serde(default)]
+    status: String,
+
+    /// ID of the schedule running the job
+    #[serde(rename = "scheduleId", default)]
+    schedule_id: Option<String>,
+
+    /// Creation time (Unix timestamp)
+    #[serde(rename = "createTime", default)]
+    create_time: Option<i64>,
+
+    /// Last modification time (Unix timestamp)
+    #[serde(rename = "lastModifiedTime", default)]
+    last_modified_time: Option<i64>,
+}
+
+impl Etl {
+    /// Returns true if the job is running.
+    pub fn is_running(&self) -> bool {
+        self.status == "RUNNING"
+    }
+}
+
+/// Builder for constructing a data transformation configuration.
+///
+/// # Examples
+///
+/// Route raw logs into per-team logstores with SPL:
+///
+/// ```
+/// use aliyun_log_rust_sdk::{EtlConfiguration, EtlSink};
+///
+/// let configuration = EtlConfiguration::builder()
+///     .logstore("raw-log")
+///     .script("* | where team = 'payment'")
+///     .role_arn("acs:ram::123456:role/aliyunlogetlrole")
+///     .sink(EtlSink::new("payment", "cn-hangzhou-intranet.log.aliyuncs.com", "my-project", "payment-log"))
+///     .from_time(1700000000)
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct EtlConfigurationBuilder {
+    configuration: EtlConfiguration,
+}
+
+impl EtlConfigurationBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the source logstore to read from (required).
+    pub fn logstore(mut self, logstore: impl Into<String>) -> Self {
+        self.configuration.logstore = logstore.into();
+        self
+    }
+
+    /// Set the transformation script (required).
+    pub fn script(mut self, script: impl Into<String>) -> Self {
+        self.configuration.script = script.into();
+        self
+    }
+
+    /// Set the language of the script, default [`EtlLanguage::Spl`].
+    pub fn lang(mut self, lang: EtlLanguage) -> Self {
+        self.configuration.lang = lang;
+        self
+    }
+
+    /// Set the RAM role used to read the source logstore (required).
+    pub fn role_arn(mut self, role_arn: impl Into<String>) -> Self {
+        self.configuration.role_arn = role_arn.into();
+        self
+    }
+
+    /// Add a logstore the transformed logs are written to, at least one is required.
+    ///
+    /// Logs are written to the first sink unless the script routes them elsewhere.
+    pub fn sink(mut self, sink: EtlSink) -> Self {
+        self.configuration.sinks.push(sink);
+        self
+    }
+
+    /// Set the unix timestamp in seconds from which logs are processed (required).
+    pub fn from_time(mut self, from_time: i64) -> Self {
+        self.configuration.from_time = from_time;
+        self
+    }
+
+    /// Set the unix timestamp in seconds at which the job stops, default 0 means never.
+    pub fn to_time(mut self, to_time: i64) -> Self {
+        self.configuration.to_time = to_time;
+        self
+    }
+
+    /// Set a parameter of the script, referenced as `res_local("key")` in DSL.
+    pub fn parameter(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.configuration
+            .parameters
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Build the data transformation configuration.
+    pub fn build(self) -> EtlConfiguration {
+        self.configuration
+    }
+}
+
+/// Data transformation configuration, defines the source, the script and the sinks.
+///
+/// Use [`EtlConfiguration::builder`] to construct one.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EtlConfiguration {
+    /// Source logstore
+    pub logstore: String,
+    /// Transformation script
+    pub script: String,
+    /// Language of the script
+    pub lang: EtlLanguage,
+    /// RAM role used to read the source logstore
+    #[serde(rename = "roleArn")]
+    pub role_arn: String,
+    /// Logstores the transformed logs are written to
+    pub sinks: Vec<EtlSink>,
+    /// Unix timestamp in seconds from which logs are processed
+    #[serde(rename = "fromTime")]
+    pub from_time: i64,
+    /// Unix timestamp in seconds at which the job stops, 0 means never
+    #[serde(rename = "toTime")]
+    pub to_time: i64,
+    /// Parameters of the script
+    pub parameters: HashMap<String, String>,
+}
+
+impl EtlConfiguration {
+    /// Create a new EtlConfigurationBuilder for fluent configuration.
+    pub fn builder() -> EtlConfigurationBuilder {
+        EtlConfigurationBuilder::new()
+    }
+}
+
+/// Language of a data transformation script.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EtlLanguage {
+    /// SLS Processing Language, e.g. `* | where status = '200'`
+    #[default]
+    #[serde(rename = "SPL")]
+    Spl,
+    /// Python-style data transformation DSL, e.g. `e_keep(e_search("status==200"))`
+    #[serde(rename = "DSL")]
+    Dsl,
+}
+
+/// A logstore the transformed logs are written to.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EtlSink {
+    /// Sink name, referenced by the script when routing logs
+    pub name: String,
+    /// Endpoint of the destination project
+    pub endpoint: String,
+    /// Destination project
+    pub project: String,
+    /// Destination logstore
+    pub logstore: String,
+    /// RAM role used to write the destination logstore
+    #[serde(rename = "roleArn")]
+    pub role_arn: String,
+    /// Datasets written to this sink, only used by SPL scripts
+    pub datasets: Vec<String>,
+}
+
+impl EtlSink {
+    /// Create a sink writing into `project`/`logstore`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Sink name, referenced by the script when routing logs
+    /// * `endpoint` - Endpoint of the destination project, e.g. `cn-hangzhou-intranet.log.aliyuncs.com`
+    /// * `project` - Destination project
+    /// * `logstore` - Destination logstore
+    pub fn new(
+        name: impl Into<String>,
+        endpoint: impl Into<String>,
+        project: impl Into<String>,
+        logstore: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            endpoint: endpoint.into(),
+            project: project.into(),
+            logstore: logstore.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the RAM role used to write the destination logstore.
+    pub fn role_arn(mut self, role_arn: impl Into<String>) -> Self {
+        self.role_arn = role_arn.into();
+        self
+    }
+
+    /// Set the datasets written to this sink, only used by SPL scripts.
+    pub fn datasets(mut self, datasets: &[&str]) -> Self {
+        self.datasets = datasets.iter().map(|d| d.to_string()).collect();
+        self
+    }
+}
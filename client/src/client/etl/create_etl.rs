@@ -0,0 +1,156 @@
+use super::*;
+use crate::RequestErrorKind;
+
+impl crate::client::Client {
+    /// Create a new data transformation (ETL) job in a project.
+    ///
+    /// A data transformation job continuously runs a script over a source logstore and
+    /// writes the results into one or more sink logstores.
+    /// The job starts running after it is created.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `etl_name` - The name of the job, unique within the project
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::{EtlConfiguration, EtlSink};
+    ///
+    /// let endpoint = "cn-hangzhou-intranet.log.aliyuncs.com";
+    /// let configuration = EtlConfiguration::builder()
+    ///     .logstore("raw-log")
+    ///     .script("* | where team = 'payment'")
+    ///     .role_arn("acs:ram::123456:role/aliyunlogetlrole")
+    ///     .sink(EtlSink::new("payment", endpoint, "my-project", "payment-log"))
+    ///     .from_time(chrono::Utc::now().timestamp())
+    ///     .build();
+    ///
+    /// client.create_etl("my-project", "route-payment-log")
+    ///     .display_name("Route payment logs")
+    ///     .configuration(configuration)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_etl(
+        &self,
+        project: impl AsRef<str>,
+        etl_name: impl AsRef<str>,
+    ) -> CreateEtlRequestBuilder {
+        CreateEtlRequestBuilder {
+            project: project.as_ref().to_string(),
+            handle: self.handle.clone(),
+            etl_name: etl_name.as_ref().to_string(),
+            display_name: None,
+            description: None,
+            configuration: None,
+        }
+    }
+}
+
+pub struct CreateEtlRequestBuilder {
+    project: String,
+    handle: HandleRef,
+    etl_name: String,
+    display_name: Option<String>,
+    description: Option<String>,
+    configuration: Option<EtlConfiguration>,
+}
+
+request_options!(CreateEtlRequestBuilder);
+
+impl CreateEtlRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Set the display name of the job (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `display_name` - Display name of the job
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// Set the description of the job (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `description` - Description of the job
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the job configuration (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - Source, script and sinks of the job
+    pub fn configuration(mut self, configuration: EtlConfiguration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+
+    fn build(self) -> BuildResult<CreateEtlRequest> {
+        check_required!(
+            ("display_name", self.display_name),
+            ("configuration", self.configuration)
+        );
+        Ok((
+            self.handle,
+            CreateEtlRequest {
+                project: self.project,
+                name: self.etl_name,
+                display_name: self.display_name.unwrap(),
+                description: self.description,
+                configuration: self.configuration.unwrap(),
+            },
+        ))
+    }
+}
+
+#[derive(Serialize)]
+struct CreateEtlRequest {
+    #[serde(skip_serializing)]
+    project: String,
+
+    name: String,
+
+    #[serde(rename = "displayName")]
+    display_name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    configuration: EtlConfiguration,
+}
+
+impl Request for CreateEtlRequest {
+    const HTTP_METHOD: http::Method = http::Method::POST;
+    const CONTENT_TYPE: Option<http::HeaderValue> = Some(LOG_JSON);
+    type ResponseBody = ();
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        "/etls"
+    }
+
+    fn body(&self) -> crate::Result<Option<bytes::Bytes>, RequestError> {
+        let json = serde_json::to_string(&self).map_err(RequestErrorKind::JsonEncode)?;
+        Ok(Some(bytes::Bytes::from(json)))
+    }
+}
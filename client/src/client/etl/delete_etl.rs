@@ -0,0 +1,78 @@
+use super::*;
+
+impl crate::client::Client {
+    /// Delete a data transformation (ETL) job.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `etl_name` - The name of the job to delete
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// client.delete_etl("my-project", "route-payment-log")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_etl(
+        &self,
+        project: impl AsRef<str>,
+        etl_name: impl AsRef<str>,
+    ) -> DeleteEtlRequestBuilder {
+        DeleteEtlRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/etls/{}", etl_name.as_ref()),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+pub struct DeleteEtlRequestBuilder {
+    handle: HandleRef,
+    project: String,
+    path: String,
+}
+
+request_options!(DeleteEtlRequestBuilder);
+
+impl DeleteEtlRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<DeleteEtlRequest> {
+        Ok((
+            self.handle,
+            DeleteEtlRequest {
+                project: self.project,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+struct DeleteEtlRequest {
+    project: String,
+    path: String,
+}
+
+impl Request for DeleteEtlRequest {
+    type ResponseBody = ();
+    const HTTP_METHOD: http::Method = http::Method::DELETE;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+}
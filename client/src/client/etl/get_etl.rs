@@ -0,0 +1,90 @@
+use super::*;
+use crate::ResponseResult;
+
+impl crate::client::Client {
+    /// Get a data transformation (ETL) job.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `etl_name` - The name of the job to get
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let resp = client.get_etl("my-project", "route-payment-log")
+    ///     .send()
+    ///     .await?;
+    /// let etl = resp.get_body();
+    /// println!("{} is {}", etl.display_name(), etl.status());
+    /// for sink in &etl.configuration().sinks {
+    ///     println!("  {} -> {}/{}", sink.name, sink.project, sink.logstore);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_etl(
+        &self,
+        project: impl AsRef<str>,
+        etl_name: impl AsRef<str>,
+    ) -> GetEtlRequestBuilder {
+        GetEtlRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/etls/{}", etl_name.as_ref()),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+pub struct GetEtlRequestBuilder {
+    handle: HandleRef,
+    project: String,
+    path: String,
+}
+
+request_options!(GetEtlRequestBuilder);
+
+impl GetEtlRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<Etl> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<GetEtlRequest> {
+        Ok((
+            self.handle,
+            GetEtlRequest {
+                project: self.project,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+struct GetEtlRequest {
+    project: String,
+    path: String,
+}
+
+impl Request for GetEtlRequest {
+    type ResponseBody = Etl;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl FromHttpResponse for Etl {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
@@ -0,0 +1,137 @@
+use super::*;
+use crate::ResponseResult;
+
+impl crate::client::Client {
+    /// List data transformation (ETL) jobs in a project with pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `offset` - The offset for pagination (starting from 0)
+    /// * `size` - The number of jobs to return (page size)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let resp = client.list_etls("my-project", 0, 100)
+    ///     .logstore("raw-log")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// println!("Total ETL jobs: {}", resp.get_body().total());
+    /// for etl in resp.get_body().etls() {
+    ///     println!("ETL: {}, status: {}", etl.name(), etl.status());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_etls(
+        &self,
+        project: impl AsRef<str>,
+        offset: i32,
+        size: i32,
+    ) -> ListEtlsRequestBuilder {
+        ListEtlsRequestBuilder {
+            project: project.as_ref().to_string(),
+            handle: self.handle.clone(),
+            offset,
+            size,
+            logstore: None,
+        }
+    }
+}
+
+pub struct ListEtlsRequestBuilder {
+    project: String,
+    handle: HandleRef,
+    offset: i32,
+    size: i32,
+    logstore: Option<String>,
+}
+
+request_options!(ListEtlsRequestBuilder);
+
+impl ListEtlsRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<ListEtlsResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Only list jobs reading from this source logstore (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `logstore` - The name of the source logstore
+    pub fn logstore(mut self, logstore: impl Into<String>) -> Self {
+        self.logstore = Some(logstore.into());
+        self
+    }
+
+    fn build(self) -> BuildResult<ListEtlsRequest> {
+        Ok((
+            self.handle,
+            ListEtlsRequest {
+                project: self.project,
+                offset: self.offset,
+                size: self.size,
+                logstore: self.logstore,
+            },
+        ))
+    }
+}
+
+struct ListEtlsRequest {
+    project: String,
+    offset: i32,
+    size: i32,
+    logstore: Option<String>,
+}
+
+impl Request for ListEtlsRequest {
+    type ResponseBody = ListEtlsResponse;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        "/etls"
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        let mut params = vec![
+            ("offset".to_string(), self.offset.to_string()),
+            ("size".to_string(), self.size.to_string()),
+        ];
+        if let Some(logstore) = &self.logstore {
+            params.push(("logstore".to_string(), logstore.clone()));
+        }
+        Some(params)
+    }
+}
+
+/// Response containing a list of data transformation jobs
+#[derive(Debug, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct ListEtlsResponse {
+    /// Number of jobs returned in this response
+    count: i32,
+
+    /// Total number of jobs in the project
+    total: i32,
+
+    /// List of data transformation jobs
+    #[serde(rename = "results", default)]
+    etls: Vec<Etl>,
+}
+
+impl FromHttpResponse for ListEtlsResponse {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
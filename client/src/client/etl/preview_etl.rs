@@ -0,0 +1,211 @@
+use super::*;
+use crate::{RequestErrorKind, ResponseResult};
+use aliyun_log_sdk_protobuf::LogGroup;
+
+impl crate::client::Client {
+    /// Preview a data transformation script against sample logs, without creating a job.
+    ///
+    /// The sample logs are transformed by the script and the results are returned
+    /// grouped by the sink they would be written to, so that routing rules can be
+    /// checked before the job is created or updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::{EtlConfiguration, EtlSink};
+    /// use aliyun_log_sdk_protobuf::{Log, LogGroup};
+    ///
+    /// let endpoint = "cn-hangzhou-intranet.log.aliyuncs.com";
+    /// let configuration = EtlConfiguration::builder()
+    ///     .logstore("raw-log")
+    ///     .script("* | where team = 'payment'")
+    ///     .role_arn("acs:ram::123456:role/aliyunlogetlrole")
+    ///     .sink(EtlSink::new("payment", endpoint, "my-project", "payment-log"))
+    ///     .build();
+    ///
+    /// let mut log = Log::from_unixtime(chrono::Utc::now().timestamp() as u32);
+    /// log.add_content_kv("team", "payment")
+    ///     .add_content_kv("message", "order paid");
+    /// let mut log_group = LogGroup::new();
+    /// log_group.add_log(log);
+    ///
+    /// let resp = client.preview_etl("my-project")
+    ///     .configuration(configuration)
+    ///     .log_group(log_group)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for result in resp.get_body().results() {
+    ///     println!("{} logs routed to sink {}", result.logs().len(), result.sink());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn preview_etl(&self, project: impl AsRef<str>) -> PreviewEtlRequestBuilder {
+        PreviewEtlRequestBuilder {
+            project: project.as_ref().to_string(),
+            handle: self.handle.clone(),
+            configuration: None,
+            log_groups: None,
+        }
+    }
+}
+
+pub struct PreviewEtlRequestBuilder {
+    project: String,
+    handle: HandleRef,
+    configuration: Option<EtlConfiguration>,
+    log_groups: Option<Vec<LogGroup>>,
+}
+
+request_options!(PreviewEtlRequestBuilder);
+
+impl PreviewEtlRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<PreviewEtlResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Set the configuration holding the script and sinks to preview (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - Configuration of the job to preview
+    pub fn configuration(mut self, configuration: EtlConfiguration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+
+    /// Add a log group of sample logs, at least one is required.
+    ///
+    /// # Arguments
+    ///
+    /// * `log_group` - Sample logs to transform, e.g. taken from `pull_logs`
+    pub fn log_group(mut self, log_group: LogGroup) -> Self {
+        self.log_groups.get_or_insert_with(Vec::new).push(log_group);
+        self
+    }
+
+    /// Add log groups of sample logs, at least one is required.
+    ///
+    /// # Arguments
+    ///
+    /// * `log_groups` - Sample logs to transform, e.g. taken from `pull_logs`
+    pub fn log_groups(mut self, log_groups: impl IntoIterator<Item = LogGroup>) -> Self {
+        self.log_groups
+            .get_or_insert_with(Vec::new)
+            .extend(log_groups);
+        self
+    }
+
+    fn build(self) -> BuildResult<PreviewEtlRequest> {
+        check_required!(
+            ("configuration", self.configuration),
+            ("log_groups", self.log_groups)
+        );
+        let logs = self
+            .log_groups
+            .unwrap()
+            .iter()
+            .flat_map(flatten_log_group)
+            .collect();
+        Ok((
+            self.handle,
+            PreviewEtlRequest {
+                project: self.project,
+                configuration: self.configuration.unwrap(),
+                logs,
+            },
+        ))
+    }
+}
+
+/// Flatten each log of a log group into a map of fields, the way logs are seen by scripts.
+fn flatten_log_group(log_group: &LogGroup) -> Vec<HashMap<String, String>> {
+    log_group
+        .logs()
+        .iter()
+        .map(|log| {
+            let mut fields = HashMap::new();
+            fields.insert("__time__".to_string(), log.time().to_string());
+            if let Some(topic) = log_group.topic() {
+                fields.insert("__topic__".to_string(), topic.clone());
+            }
+            if let Some(source) = log_group.source() {
+                fields.insert("__source__".to_string(), source.clone());
+            }
+            for tag in log_group.log_tags() {
+                fields.insert(format!("__tag__:{}", tag.key()), tag.value().clone());
+            }
+            for content in log.contents() {
+                fields.insert(content.key().clone(), content.value().clone());
+            }
+            fields
+        })
+        .collect()
+}
+
+#[derive(Serialize)]
+struct PreviewEtlRequest {
+    #[serde(skip_serializing)]
+    project: String,
+
+    configuration: EtlConfiguration,
+
+    logs: Vec<HashMap<String, String>>,
+}
+
+impl Request for PreviewEtlRequest {
+    const HTTP_METHOD: http::Method = http::Method::POST;
+    const CONTENT_TYPE: Option<http::HeaderValue> = Some(LOG_JSON);
+    type ResponseBody = PreviewEtlResponse;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        "/etls/preview"
+    }
+
+    fn body(&self) -> crate::Result<Option<bytes::Bytes>, RequestError> {
+        let json = serde_json::to_string(&self).map_err(RequestErrorKind::JsonEncode)?;
+        Ok(Some(bytes::Bytes::from(json)))
+    }
+}
+
+/// Response containing the transformed sample logs
+#[derive(Debug, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct PreviewEtlResponse {
+    /// Transformed logs, grouped by the sink they would be written to
+    #[serde(default)]
+    results: Vec<EtlPreviewResult>,
+}
+
+/// Transformed logs that would be written to one sink.
+#[derive(Debug, Clone, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct EtlPreviewResult {
+    /// Name of the sink
+    #[serde(default)]
+    sink: String,
+
+    /// Transformed logs, each as a map of fields
+    #[serde(default)]
+    logs: Vec<HashMap<String, String>>,
+}
+
+impl FromHttpResponse for PreviewEtlResponse {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
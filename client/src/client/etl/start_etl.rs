@@ -0,0 +1,82 @@
+use super::*;
+
+impl crate::client::Client {
+    /// Start a stopped data transformation (ETL) job, it resumes from where it stopped.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `etl_name` - The name of the job to start
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// client.start_etl("my-project", "route-payment-log")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn start_etl(
+        &self,
+        project: impl AsRef<str>,
+        etl_name: impl AsRef<str>,
+    ) -> StartEtlRequestBuilder {
+        StartEtlRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/etls/{}", etl_name.as_ref()),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+pub struct StartEtlRequestBuilder {
+    handle: HandleRef,
+    project: String,
+    path: String,
+}
+
+request_options!(StartEtlRequestBuilder);
+
+impl StartEtlRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<StartEtlRequest> {
+        Ok((
+            self.handle,
+            StartEtlRequest {
+                project: self.project,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+struct StartEtlRequest {
+    project: String,
+    path: String,
+}
+
+impl Request for StartEtlRequest {
+    type ResponseBody = ();
+    const HTTP_METHOD: http::Method = http::Method::PUT;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        Some(vec![("action".to_string(), "START".to_string())])
+    }
+}
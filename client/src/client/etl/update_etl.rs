@@ -0,0 +1,163 @@
+use super::*;
+use crate::RequestErrorKind;
+
+impl crate::client::Client {
+    /// Update an existing data transformation (ETL) job.
+    ///
+    /// The whole job definition is replaced, so all required fields must be set again.
+    /// A running job is restarted with the new configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `etl_name` - The name of the job to update
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::EtlSink;
+    ///
+    /// let resp = client.get_etl("my-project", "route-payment-log").send().await?;
+    /// let etl = resp.take_body();
+    ///
+    /// let mut configuration = etl.configuration().clone();
+    /// configuration.sinks.push(EtlSink::new(
+    ///     "order",
+    ///     "cn-hangzhou-intranet.log.aliyuncs.com",
+    ///     "my-project",
+    ///     "order-log",
+    /// ));
+    ///
+    /// client.update_etl("my-project", "route-payment-log")
+    ///     .display_name(etl.display_name())
+    ///     .configuration(configuration)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_etl(
+        &self,
+        project: impl AsRef<str>,
+        etl_name: impl AsRef<str>,
+    ) -> UpdateEtlRequestBuilder {
+        UpdateEtlRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/etls/{}", etl_name.as_ref()),
+            handle: self.handle.clone(),
+            etl_name: etl_name.as_ref().to_string(),
+            display_name: None,
+            description: None,
+            configuration: None,
+        }
+    }
+}
+
+pub struct UpdateEtlRequestBuilder {
+    project: String,
+    path: String,
+    handle: HandleRef,
+    etl_name: String,
+    display_name: Option<String>,
+    description: Option<String>,
+    configuration: Option<EtlConfiguration>,
+}
+
+request_options!(UpdateEtlRequestBuilder);
+
+impl UpdateEtlRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Set the display name of the job (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `display_name` - Display name of the job
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// Set the description of the job (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `description` - Description of the job
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the job configuration (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - Source, script and sinks of the job
+    pub fn configuration(mut self, configuration: EtlConfiguration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+
+    fn build(self) -> BuildResult<UpdateEtlRequest> {
+        check_required!(
+            ("display_name", self.display_name),
+            ("configuration", self.configuration)
+        );
+        Ok((
+            self.handle,
+            UpdateEtlRequest {
+                project: self.project,
+                path: self.path,
+                name: self.etl_name,
+                display_name: self.display_name.unwrap(),
+                description: self.description,
+                configuration: self.configuration.unwrap(),
+            },
+        ))
+    }
+}
+
+#[derive(Serialize)]
+struct UpdateEtlRequest {
+    #[serde(skip_serializing)]
+    project: String,
+
+    #[serde(skip_serializing)]
+    path: String,
+
+    name: String,
+
+    #[serde(rename = "displayName")]
+    display_name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    configuration: EtlConfiguration,
+}
+
+impl Request for UpdateEtlRequest {
+    const HTTP_METHOD: http::Method = http::Method::PUT;
+    const CONTENT_TYPE: Option<http::HeaderValue> = Some(LOG_JSON);
+    type ResponseBody = ();
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn body(&self) -> crate::Result<Option<bytes::Bytes>, RequestError> {
+        let json = serde_json::to_string(&self).map_err(RequestErrorKind::JsonEncode)?;
+        Ok(Some(bytes::Bytes::from(json)))
+    }
+}
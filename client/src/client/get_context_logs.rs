@@ -0,0 +1,188 @@
+use super::*;
+use crate::ResponseResult;
+use getset::Getters;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+impl crate::client::Client {
+    /// Get the logs written before and after a log from the same source, its context.
+    ///
+    /// The log is located by its pack, which is returned with the logs of `get_logs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project containing the logstore
+    /// * `logstore` - The name of the logstore containing the log
+    /// * `pack_id` - The `__tag__:__pack_id__` field of the log
+    /// * `pack_meta` - The `__pack_meta__` field of the log
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use chrono::Utc;
+    ///
+    /// let now = Utc::now().timestamp();
+    /// let resp = client.get_logs("my-project", "my-logstore")
+    ///     .from(now - 3600)
+    ///     .to(now)
+    ///     .query("level: ERROR")
+    ///     .offset(0)
+    ///     .lines(1)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// if let Some(Some(pack)) = resp.get_body().log_packs().next() {
+    ///     let context = client
+    ///         .get_context_logs("my-project", "my-logstore", pack.pack_id(), pack.pack_meta())
+    ///         .back_lines(10)
+    ///         .forward_lines(10)
+    ///         .send()
+    ///         .await?;
+    ///     for log in context.get_body().logs() {
+    ///         println!("{:?}", log);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_context_logs(
+        &self,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+        pack_id: impl AsRef<str>,
+        pack_meta: impl AsRef<str>,
+    ) -> GetContextLogsRequestBuilder {
+        GetContextLogsRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/logstores/{}", logstore.as_ref()),
+            handle: self.handle.clone(),
+            pack_id: pack_id.as_ref().to_string(),
+            pack_meta: pack_meta.as_ref().to_string(),
+            back_lines: 0,
+            forward_lines: 0,
+        }
+    }
+}
+
+pub struct GetContextLogsRequestBuilder {
+    project: String,
+    path: String,
+    handle: HandleRef,
+    pack_id: String,
+    pack_meta: String,
+    back_lines: u32,
+    forward_lines: u32,
+}
+
+request_options!(GetContextLogsRequestBuilder);
+
+impl GetContextLogsRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<GetContextLogsResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Optional, the number of logs to get before the log, from 0 to 100, default 0.
+    pub fn back_lines(mut self, back_lines: u32) -> Self {
+        self.back_lines = back_lines;
+        self
+    }
+
+    /// Optional, the number of logs to get after the log, from 0 to 100, default 0.
+    pub fn forward_lines(mut self, forward_lines: u32) -> Self {
+        self.forward_lines = forward_lines;
+        self
+    }
+
+    fn build(self) -> BuildResult<GetContextLogsRequest> {
+        Ok((
+            self.handle,
+            GetContextLogsRequest {
+                project: self.project,
+                path: self.path,
+                pack_id: self.pack_id,
+                pack_meta: self.pack_meta,
+                back_lines: self.back_lines,
+                forward_lines: self.forward_lines,
+            },
+        ))
+    }
+}
+
+struct GetContextLogsRequest {
+    project: String,
+    path: String,
+    pack_id: String,
+    pack_meta: String,
+    back_lines: u32,
+    forward_lines: u32,
+}
+
+impl Request for GetContextLogsRequest {
+    type ResponseBody = GetContextLogsResponse;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        Some(vec![
+            ("type".to_string(), "context_log".to_string()),
+            ("pack_id".to_string(), self.pack_id.clone()),
+            ("pack_meta".to_string(), self.pack_meta.clone()),
+            ("back_lines".to_string(), self.back_lines.to_string()),
+            ("forward_lines".to_string(), self.forward_lines.to_string()),
+        ])
+    }
+}
+
+#[derive(Debug, Deserialize, Getters)]
+pub struct GetContextLogsResponse {
+    /// Number of logs returned
+    #[getset(get = "pub")]
+    total_lines: i32,
+
+    /// Number of logs returned before the log
+    #[getset(get = "pub")]
+    back_lines: i32,
+
+    /// Number of logs returned after the log
+    #[getset(get = "pub")]
+    forward_lines: i32,
+
+    progress: String,
+
+    /// The logs in the order they were written, including the log itself.
+    ///
+    /// The `__index_number__` field of each log is its position relative to the log,
+    /// negative before it and positive after it.
+    #[getset(get = "pub")]
+    logs: Vec<HashMap<String, String>>,
+}
+
+impl GetContextLogsResponse {
+    /// Returns true if all the logs requested have been returned.
+    pub fn is_complete(&self) -> bool {
+        self.progress.eq_ignore_ascii_case("complete")
+    }
+
+    /// Takes out the logs from the response.
+    pub fn take_logs(self) -> Vec<HashMap<String, String>> {
+        self.logs
+    }
+}
+
+impl FromHttpResponse for GetContextLogsResponse {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
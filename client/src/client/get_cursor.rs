@@ -89,6 +89,8 @@ pub struct GetCursorRequestBuilder {
     cursor_pos: Option<get_cursor_models::CursorPos>,
 }
 
+request_options!(GetCursorRequestBuilder);
+
 impl GetCursorRequestBuilder {
     /// Required, the cursor position to get.
     pub fn cursor_pos(mut self, cursor_pos: get_cursor_models::CursorPos) -> Self {
@@ -118,6 +120,43 @@ impl GetCursorRequestBuilder {
 }
 
 pub mod get_cursor_models {
+    use base64::Engine;
+
+    /// The offset of a cursor in its shard, `None` if the cursor is not valid.
+    ///
+    /// The difference between the offsets of two cursors of a shard is the number of log groups between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aliyun_log_rust_sdk::get_cursor_models::cursor_offset;
+    ///
+    /// assert_eq!(cursor_offset("MTU0NzQ3MDY4MjM3NjUxMzQ0Ng=="), Some(1547470682376513446));
+    /// assert_eq!(cursor_offset("not a cursor"), None);
+    /// ```
+    pub fn cursor_offset(cursor: &str) -> Option<u64> {
+        // a cursor is the offset in decimal, encoded in base64
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(cursor)
+            .ok()?;
+        std::str::from_utf8(&decoded).ok()?.parse().ok()
+    }
+
+    /// The cursor at an offset of a shard, the inverse of [`cursor_offset`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aliyun_log_rust_sdk::get_cursor_models::{cursor_at_offset, cursor_offset};
+    ///
+    /// let cursor = "MTU0NzQ3MDY4MjM3NjUxMzQ0Ng==";
+    /// let previous = cursor_at_offset(cursor_offset(cursor).unwrap() - 1);
+    /// assert_eq!(cursor_offset(&previous), Some(1547470682376513445));
+    /// ```
+    pub fn cursor_at_offset(offset: u64) -> String {
+        base64::engine::general_purpose::STANDARD.encode(offset.to_string())
+    }
+
     #[derive(Clone, Default)]
     pub enum CursorPos {
         #[default]
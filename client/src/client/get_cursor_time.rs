@@ -0,0 +1,121 @@
+use super::*;
+use crate::ResponseResult;
+use getset::Getters;
+use serde::Deserialize;
+
+impl crate::client::Client {
+    /// Get the time a cursor of a shard points at.
+    ///
+    /// The time is the one the data at the cursor was received by the server,
+    /// which allows to tell how far behind the data read from a cursor is.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project containing the logstore
+    /// * `logstore` - The name of the logstore containing the shard
+    /// * `shard_id` - The ID of the shard the cursor belongs to
+    /// * `cursor` - The cursor, e.g. returned by `get_cursor` or a consumer group checkpoint
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::get_cursor_models::CursorPos;
+    ///
+    /// let cursor = client
+    ///     .get_cursor("my-project", "my-logstore", 0)
+    ///     .cursor_pos(CursorPos::End)
+    ///     .send()
+    ///     .await?;
+    /// let resp = client
+    ///     .get_cursor_time("my-project", "my-logstore", 0, cursor.get_body().cursor())
+    ///     .send()
+    ///     .await?;
+    /// println!("Last data received at {}", resp.get_body().cursor_time());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_cursor_time(
+        &self,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+        shard_id: i32,
+        cursor: impl AsRef<str>,
+    ) -> GetCursorTimeRequestBuilder {
+        GetCursorTimeRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/logstores/{}/shards/{}", logstore.as_ref(), shard_id),
+            handle: self.handle.clone(),
+            cursor: cursor.as_ref().to_string(),
+        }
+    }
+}
+
+pub struct GetCursorTimeRequestBuilder {
+    project: String,
+    path: String,
+    handle: HandleRef,
+    cursor: String,
+}
+
+request_options!(GetCursorTimeRequestBuilder);
+
+impl GetCursorTimeRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<GetCursorTimeResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<GetCursorTimeRequest> {
+        Ok((
+            self.handle,
+            GetCursorTimeRequest {
+                project: self.project,
+                path: self.path,
+                cursor: self.cursor,
+            },
+        ))
+    }
+}
+
+struct GetCursorTimeRequest {
+    project: String,
+    path: String,
+    cursor: String,
+}
+
+impl Request for GetCursorTimeRequest {
+    type ResponseBody = GetCursorTimeResponse;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        Some(vec![
+            ("type".to_string(), "cursor_time".to_string()),
+            ("cursor".to_string(), self.cursor.clone()),
+        ])
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Getters)]
+pub struct GetCursorTimeResponse {
+    /// The time the data at the cursor was received, in unix timestamp, in seconds
+    #[getset(get = "pub")]
+    cursor_time: i64,
+}
+
+impl FromHttpResponse for GetCursorTimeResponse {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
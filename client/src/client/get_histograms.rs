@@ -0,0 +1,260 @@
+use super::*;
+use crate::utils::ValueGetter;
+use crate::ResponseResult;
+use getset::Getters;
+use serde::Deserialize;
+
+impl crate::client::Client {
+    /// Get the number of logs matching a query over time, split into intervals.
+    ///
+    /// The time range is split into intervals by the server, with the number of logs in each of them.
+    /// This is useful to show the distribution of logs before fetching them with `get_logs`.
+    /// Only the search part of the query is used, the analytic statement after `|` is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project containing the logstore
+    /// * `logstore` - The name of the logstore to count logs in
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use chrono::Utc;
+    ///
+    /// let now = Utc::now().timestamp();
+    /// let resp = client.get_histograms("my-project", "my-logstore")
+    ///     .from(now - 3600)
+    ///     .to(now)
+    ///     .query("level: ERROR")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// if !resp.get_body().is_complete() {
+    ///     println!("Counts are incomplete, you may need to retry later");
+    /// }
+    /// for histogram in resp.get_body().histograms() {
+    ///     println!("[{}, {}): {}", histogram.from(), histogram.to(), histogram.count());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_histograms(
+        &self,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+    ) -> GetHistogramsRequestBuilder {
+        GetHistogramsRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/logstores/{}/index", logstore.as_ref()),
+            handle: self.handle.clone(),
+            from: None,
+            to: None,
+            topic: None,
+            query: None,
+        }
+    }
+}
+
+pub struct GetHistogramsRequestBuilder {
+    project: String,
+    path: String,
+    handle: HandleRef,
+    from: Option<i64>,
+    to: Option<i64>,
+    topic: Option<String>,
+    query: Option<String>,
+}
+
+request_options!(GetHistogramsRequestBuilder);
+
+impl GetHistogramsRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<GetHistogramsResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Required, the start time of the query, in unix timestamp, in seconds, e.g., 1609459200.
+    pub fn from(mut self, from: i64) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Required, the end time of the query, in unix timestamp, in seconds, e.g., 1609459200.
+    pub fn to(mut self, to: i64) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Optional, the topic of the logs to count.
+    pub fn topic<T: Into<String>>(mut self, topic: T) -> Self {
+        self.topic = Some(topic.into());
+        self
+    }
+
+    /// Optional, the query of the logs to count, all logs are counted by default.
+    ///
+    /// Conditions of the [`query`](crate::query) builder can be passed directly.
+    pub fn query<T: Into<String>>(mut self, query: T) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    fn build(self) -> BuildResult<GetHistogramsRequest> {
+        check_required!(("from", self.from), ("to", self.to));
+        Ok((
+            self.handle,
+            GetHistogramsRequest {
+                project: self.project,
+                path: self.path,
+                from: self.from.unwrap(),
+                to: self.to.unwrap(),
+                topic: self.topic,
+                query: self.query,
+            },
+        ))
+    }
+}
+
+struct GetHistogramsRequest {
+    project: String,
+    path: String,
+    from: i64,
+    to: i64,
+    topic: Option<String>,
+    query: Option<String>,
+}
+
+impl Request for GetHistogramsRequest {
+    type ResponseBody = GetHistogramsResponse;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        let mut params = vec![
+            ("type".to_string(), "histogram".to_string()),
+            ("from".to_string(), self.from.to_string()),
+            ("to".to_string(), self.to.to_string()),
+        ];
+        if let Some(topic) = &self.topic {
+            params.push(("topic".to_string(), topic.clone()));
+        }
+        if let Some(query) = &self.query {
+            params.push(("query".to_string(), query.clone()));
+        }
+        Some(params)
+    }
+}
+
+#[derive(Debug, Getters)]
+pub struct GetHistogramsResponse {
+    /// Number of logs in each interval, in time order
+    #[getset(get = "pub")]
+    histograms: Vec<get_histograms_models::Histogram>,
+
+    /// Total number of logs matching the query
+    #[getset(get = "pub")]
+    count: i64,
+
+    progress: String,
+}
+
+impl GetHistogramsResponse {
+    /// Returns true if the logs of all the intervals have been counted.
+    pub fn is_complete(&self) -> bool {
+        self.progress.eq_ignore_ascii_case("complete")
+    }
+
+    /// Takes out the intervals from the response.
+    pub fn take_histograms(self) -> Vec<get_histograms_models::Histogram> {
+        self.histograms
+    }
+}
+
+impl FromHttpResponse for GetHistogramsResponse {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        let histograms: Vec<get_histograms_models::Histogram> =
+            parse_json_response(body.as_ref(), http_headers)?;
+        let count = http_headers
+            .get_str("x-log-count")
+            .and_then(|count| count.parse().ok())
+            .unwrap_or_else(|| histograms.iter().map(|histogram| histogram.count).sum());
+        let progress = http_headers.get_str("x-log-progress").unwrap_or_else(|| {
+            // the progress of the whole range is the one of its intervals
+            let complete = histograms.iter().all(|histogram| histogram.is_complete());
+            if complete { "Complete" } else { "Incomplete" }.to_string()
+        });
+        Ok(GetHistogramsResponse {
+            histograms,
+            count,
+            progress,
+        })
+    }
+}
+
+pub mod get_histograms_models {
+    use super::*;
+
+    /// Number of logs matching the query in an interval of time.
+    #[derive(Debug, Clone, Deserialize, Getters)]
+    #[getset(get = "pub")]
+    pub struct Histogram {
+        /// Start time of the interval, in unix timestamp, in seconds, included
+        from: i64,
+        /// End time of the interval, in unix timestamp, in seconds, excluded
+        to: i64,
+        /// Number of logs in the interval
+        pub(super) count: i64,
+        /// Whether the logs of the interval have been counted, `Complete` or `Incomplete`
+        progress: String,
+    }
+
+    impl Histogram {
+        /// Returns true if the logs of the interval have been counted.
+        pub fn is_complete(&self) -> bool {
+            self.progress.eq_ignore_ascii_case("complete")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_histograms() {
+        let body = br#"[
+            {"from": 1700000000, "to": 1700000060, "count": 3, "progress": "Complete"},
+            {"from": 1700000060, "to": 1700000120, "count": 5, "progress": "Incomplete"}
+        ]"#;
+        let resp = <GetHistogramsResponse as FromHttpResponse>::try_from(
+            bytes::Bytes::from_static(body),
+            &http::HeaderMap::new(),
+        )
+        .unwrap();
+        assert_eq!(*resp.count(), 8);
+        assert!(!resp.is_complete());
+        assert_eq!(*resp.histograms()[1].from(), 1700000060);
+
+        let mut headers = http::HeaderMap::new();
+        headers.insert("x-log-progress", "Complete".parse().unwrap());
+        headers.insert("x-log-count", "10".parse().unwrap());
+        let resp = <GetHistogramsResponse as FromHttpResponse>::try_from(
+            bytes::Bytes::from_static(body),
+            &headers,
+        )
+        .unwrap();
+        assert_eq!(*resp.count(), 10);
+        assert!(resp.is_complete());
+    }
+}
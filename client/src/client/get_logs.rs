@@ -1,6 +1,8 @@
 use super::*;
+use crate::utils::ValueGetter;
 use crate::{compress::CompressType, error::Result};
 use crate::{RequestError, RequestErrorKind, ResponseResult};
+use futures_util::StreamExt;
 use getset::Getters;
 use http::header::ACCEPT_ENCODING;
 use serde::{Deserialize, Serialize};
@@ -77,6 +79,7 @@ impl crate::client::Client {
             need_highlight: None,
             from_ns_part: None,
             to_ns_part: None,
+            marker: None,
         }
     }
 }
@@ -120,6 +123,9 @@ pub struct GetLogsRequest {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     need_highlight: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<String>,
 }
 
 impl Request for GetLogsRequest {
@@ -152,6 +158,7 @@ impl Request for GetLogsRequest {
     }
 }
 
+#[derive(Clone)]
 pub struct GetLogsRequestBuilder {
     project: String,
     path: String,
@@ -168,8 +175,11 @@ pub struct GetLogsRequestBuilder {
     from_ns_part: Option<u32>,
     to_ns_part: Option<u32>,
     need_highlight: Option<bool>,
+    marker: Option<String>,
 }
 
+request_options!(GetLogsRequestBuilder);
+
 impl GetLogsRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<GetLogsResponse> {
@@ -215,6 +225,9 @@ impl GetLogsRequestBuilder {
     }
 
     /// Required, the query string to use.
+    ///
+    /// Conditions of the [`query`](crate::query) builder, escaping the values they contain,
+    /// can be passed directly, and queries with a SQL statement once rendered.
     pub fn query<T: Into<String>>(mut self, query: T) -> Self {
         self.query = Some(query.into());
         self
@@ -244,6 +257,94 @@ impl GetLogsRequestBuilder {
         self
     }
 
+    /// Optional, the marker in the meta of the previous page of a scan query, to continue from it.
+    pub fn marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.marker = Some(marker.into());
+        self
+    }
+
+    /// Stream all the logs matching the query from `offset`, fetching them in pages of `lines` logs.
+    ///
+    /// Pages are fetched until all the logs matching have been returned, instead of at most `lines` logs.
+    /// Scan and phrase queries continue where the previous page stopped scanning,
+    /// by the `phrase_query_info` or `marker` in its meta.
+    /// A page whose query is incomplete is retried, and the stream fails if it stays incomplete,
+    /// so that logs are not silently missed. Queries with a SQL statement return a single page,
+    /// and the stream fails after it if the server truncated the result, as reported by `limited`
+    /// in its meta, in which case an explicit `LIMIT` should be added to the statement.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use chrono::Utc;
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let now = Utc::now().timestamp();
+    /// let mut logs = client.get_logs("my-project", "my-logstore")
+    ///     .from(now - 86400)
+    ///     .to(now)
+    ///     .query("level: ERROR")
+    ///     .lines(100)
+    ///     .into_stream();
+    /// while let Some(log) = logs.try_next().await? {
+    ///     println!("{:?}", log);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> BoxStream<Result<HashMap<String, String>>> {
+        let lines = self.lines.unwrap_or(DEFAULT_PAGE_LINES);
+        let start = Some((self.offset.unwrap_or(0), self.marker.clone()));
+        let pages = futures_util::stream::unfold(start, move |next| {
+            let builder = self.clone();
+            async move {
+                let (offset, marker) = next?;
+                match builder.fetch_page(offset, lines, marker).await {
+                    Ok(body) => {
+                        let next = next_page(body.meta(), offset, lines, body.logs_count());
+                        let mut logs: Vec<_> = body.take_logs().into_iter().map(Ok).collect();
+                        match next {
+                            Ok(next) => Some((logs, next)),
+                            Err(err) => {
+                                logs.push(Err(err));
+                                Some((logs, None))
+                            }
+                        }
+                    }
+                    // stop at the first error, the following pages are unknown
+                    Err(err) => Some((vec![Err(err)], None)),
+                }
+            }
+        });
+        Box::pin(pages.flat_map(futures_util::stream::iter))
+    }
+
+    /// Fetch a page of logs, retrying it while the query is incomplete.
+    async fn fetch_page(
+        self,
+        offset: u32,
+        lines: u32,
+        marker: Option<String>,
+    ) -> Result<GetLogsResponse> {
+        let mut page = self.offset(offset).lines(lines);
+        page.marker = marker;
+        for attempt in 0..=INCOMPLETE_PAGE_RETRIES {
+            if attempt > 0 {
+                tokio::time::sleep(INCOMPLETE_PAGE_BACKOFF * attempt).await;
+            }
+            let body = page.clone().send().await?.take_body();
+            if body.is_complete() {
+                return Ok(body);
+            }
+        }
+        Err(crate::Error::Other(anyhow::anyhow!(
+            "query is still incomplete after {} attempts, at offset {}",
+            INCOMPLETE_PAGE_RETRIES + 1,
+            offset
+        )))
+    }
+
     fn build(self) -> BuildResult<GetLogsRequest> {
         check_required!(("from", self.from), ("to", self.to));
 
@@ -261,6 +362,7 @@ impl GetLogsRequestBuilder {
                 from_ns_part: self.from_ns_part,
                 to_ns_part: self.to_ns_part,
                 need_highlight: self.need_highlight,
+                marker: self.marker,
                 project: self.project,
                 path: self.path,
             },
@@ -268,6 +370,56 @@ impl GetLogsRequestBuilder {
     }
 }
 
+/// Number of logs of the pages of [`GetLogsRequestBuilder::into_stream`] if `lines` is not set.
+const DEFAULT_PAGE_LINES: u32 = 100;
+/// Number of times a page of [`GetLogsRequestBuilder::into_stream`] is retried while incomplete.
+const INCOMPLETE_PAGE_RETRIES: u32 = 3;
+const INCOMPLETE_PAGE_BACKOFF: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// The offset and marker of the page after a page of `count` logs at `offset`, `None` if it is the last one.
+///
+/// Fails if the page is the result of a SQL statement which the server truncated.
+fn next_page(
+    meta: &get_logs_models::GetLogsMeta,
+    offset: u32,
+    lines: u32,
+    count: usize,
+) -> Result<Option<(u32, Option<String>)>> {
+    if meta.has_sql().unwrap_or(false) {
+        if let Some(limited) = meta.limited().filter(|&limited| limited > 0) {
+            return Err(crate::Error::Other(anyhow::anyhow!(
+                "the result of the SQL statement was truncated to {limited} rows by the server, \
+                 add a LIMIT to the statement to get more rows"
+            )));
+        }
+        return Ok(None);
+    }
+    Ok(next_page_offset(meta, offset, lines, count))
+}
+
+fn next_page_offset(
+    meta: &get_logs_models::GetLogsMeta,
+    offset: u32,
+    lines: u32,
+    count: usize,
+) -> Option<(u32, Option<String>)> {
+    let count = u32::try_from(count).unwrap_or(u32::MAX);
+    let next_offset = offset.saturating_add(count);
+    // scan and phrase queries scan a part of the logs at a time, which may match no log
+    if let Some(phrase) = meta.phrase_query_info() {
+        if phrase.scan_all().unwrap_or(false) {
+            return None;
+        }
+        if let Some(end_offset) = phrase.end_offset().and_then(|end| u32::try_from(end).ok()) {
+            return (end_offset > offset).then_some((end_offset, None));
+        }
+    }
+    if let Some(marker) = meta.marker().as_ref().filter(|marker| !marker.is_empty()) {
+        return Some((next_offset, Some(marker.clone())));
+    }
+    (count > 0 && count >= lines).then_some((next_offset, None))
+}
+
 #[derive(Debug, Deserialize)]
 pub struct GetLogsResponse {
     meta: get_logs_models::GetLogsMeta,
@@ -299,10 +451,22 @@ impl GetLogsResponse {
     pub fn meta(&self) -> &get_logs_models::GetLogsMeta {
         &self.meta
     }
+    /// Returns the pack of each log, to query the logs around it with `get_context_logs`,
+    /// `None` for the logs written without a pack.
+    pub fn log_packs(&self) -> impl Iterator<Item = Option<get_logs_models::LogPack>> + '_ {
+        self.logs.iter().map(get_logs_models::LogPack::from_log)
+    }
 }
 
 impl FromHttpResponse for GetLogsResponse {
     fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        // project level queries return the rows only, with the meta in the headers
+        if body.trim_ascii_start().starts_with(b"[") {
+            return Ok(GetLogsResponse {
+                meta: get_logs_models::GetLogsMeta::from_headers(http_headers),
+                logs: parse_json_response(body.as_ref(), http_headers)?,
+            });
+        }
         parse_json_response(body.as_ref(), http_headers)
     }
 }
@@ -315,18 +479,27 @@ pub mod get_logs_models {
     #[getset(get = "pub")]
     pub struct GetLogsMeta {
         progress: String,
+        #[serde(alias = "aggQuery")]
         agg_query: Option<String>,
+        #[serde(alias = "whereQuery")]
         where_query: Option<String>,
         #[serde(rename = "hasSQL")]
         has_sql: Option<bool>,
+        #[serde(alias = "processedRows")]
         processed_rows: Option<i64>,
+        #[serde(alias = "elapsedMillisecond")]
         elapsed_millisecond: Option<i64>,
+        #[serde(alias = "cpuSec")]
         cpu_sec: Option<f64>,
+        #[serde(alias = "cpuCores")]
         cpu_cores: Option<f64>,
         limited: Option<i64>,
         count: Option<i64>,
+        #[serde(alias = "processedBytes")]
         processed_bytes: Option<i64>,
+        #[serde(alias = "telementryType")]
         telementry_type: Option<String>,
+        #[serde(alias = "powerSql")]
         power_sql: Option<bool>,
         #[serde(rename = "insertedSQL")]
         inserted_sql: Option<String>,
@@ -334,14 +507,71 @@ pub mod get_logs_models {
         terms: Option<Vec<MetaTerm>>,
         marker: Option<String>,
         mode: Option<i32>,
+        #[serde(alias = "phraseQueryInfo")]
         phrase_query_info: Option<PhraseQueryInfoV3>,
         shard: Option<i32>,
+        #[serde(alias = "scanBytes")]
         scan_bytes: Option<i64>,
+        #[serde(alias = "isAccurate")]
         is_accurate: Option<bool>,
+        #[serde(alias = "columnTypes")]
         column_types: Option<Vec<String>>,
         highlights: Option<Vec<HashMap<String, String>>>,
     }
 
+    impl GetLogsMeta {
+        pub(crate) fn from_headers(headers: &http::HeaderMap) -> Self {
+            let get_i64 = |key: &str| headers.get_str(key).and_then(|value| value.parse().ok());
+            GetLogsMeta {
+                progress: headers.get_str_or_default("x-log-progress", "Complete"),
+                agg_query: headers.get_str("x-log-agg-query"),
+                where_query: headers.get_str("x-log-where-query"),
+                has_sql: headers
+                    .get_str("x-log-has-sql")
+                    .map(|value| value.eq_ignore_ascii_case("true")),
+                processed_rows: get_i64("x-log-processed-rows"),
+                elapsed_millisecond: get_i64("x-log-elapsed-millisecond"),
+                count: get_i64("x-log-count"),
+                ..Default::default()
+            }
+        }
+    }
+
+    /// The pack a log was written in, from its `__tag__:__pack_id__` and `__pack_meta__` fields.
+    ///
+    /// Logs collected by Logtail are written in packs, which locate the logs written
+    /// before and after them from the same source, queried with `get_context_logs`.
+    #[derive(Debug, Clone, PartialEq, Eq, Getters)]
+    #[getset(get = "pub")]
+    pub struct LogPack {
+        /// The ID of the pack, the same for all the logs of a source
+        pack_id: String,
+        /// The position of the pack
+        pack_meta: String,
+    }
+
+    impl LogPack {
+        /// The field of a log holding the ID of its pack
+        pub const PACK_ID_KEY: &'static str = "__tag__:__pack_id__";
+        /// The field of a log holding the position of its pack
+        pub const PACK_META_KEY: &'static str = "__pack_meta__";
+
+        pub fn new(pack_id: impl Into<String>, pack_meta: impl Into<String>) -> Self {
+            LogPack {
+                pack_id: pack_id.into(),
+                pack_meta: pack_meta.into(),
+            }
+        }
+
+        /// Get the pack of a log returned by `get_logs`, `None` if it was written without a pack.
+        pub fn from_log(log: &HashMap<String, String>) -> Option<Self> {
+            Some(LogPack::new(
+                log.get(Self::PACK_ID_KEY)?,
+                log.get(Self::PACK_META_KEY)?,
+            ))
+        }
+    }
+
     #[derive(Debug, Deserialize, Getters)]
     #[allow(dead_code)]
     #[getset(get = "pub")]
@@ -354,9 +584,88 @@ pub mod get_logs_models {
     #[allow(dead_code)]
     #[getset(get = "pub")]
     pub struct PhraseQueryInfoV3 {
+        #[serde(alias = "scanAll")]
         scan_all: Option<bool>,
+        #[serde(alias = "beginOffset")]
         begin_offset: Option<i64>,
+        #[serde(alias = "endOffset")]
         end_offset: Option<i64>,
+        #[serde(alias = "endTime")]
         end_time: Option<i64>,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_project_logs() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert("x-log-progress", "Incomplete".parse().unwrap());
+        headers.insert("x-log-count", "1".parse().unwrap());
+        headers.insert("x-log-has-sql", "true".parse().unwrap());
+        let body = bytes::Bytes::from_static(br#"[{"status": "200", "pv": "42"}]"#);
+        let resp = <GetLogsResponse as FromHttpResponse>::try_from(body, &headers).unwrap();
+        assert!(!resp.is_complete());
+        assert_eq!(resp.logs()[0]["pv"], "42");
+        assert_eq!(*resp.meta().count(), Some(1));
+        assert_eq!(*resp.meta().has_sql(), Some(true));
+    }
+
+    #[test]
+    fn test_log_packs() {
+        let body = bytes::Bytes::from_static(
+            br#"{"meta": {"progress": "Complete"}, "data": [
+                {"__tag__:__pack_id__": "A1B2-0", "__pack_meta__": "1|MTY5|1|2", "level": "ERROR"},
+                {"level": "INFO"}
+            ]}"#,
+        );
+        let resp =
+            <GetLogsResponse as FromHttpResponse>::try_from(body, &http::HeaderMap::new()).unwrap();
+        let packs: Vec<_> = resp.log_packs().collect();
+        assert_eq!(
+            packs,
+            [
+                Some(get_logs_models::LogPack::new("A1B2-0", "1|MTY5|1|2")),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_page() {
+        let meta =
+            |meta: &str| -> get_logs_models::GetLogsMeta { serde_json::from_str(meta).unwrap() };
+        let complete = meta(r#"{"progress": "Complete"}"#);
+        assert_eq!(
+            next_page(&complete, 0, 100, 100).unwrap(),
+            Some((100, None))
+        );
+        assert_eq!(next_page(&complete, 100, 100, 42).unwrap(), None);
+        assert_eq!(next_page(&complete, 100, 100, 0).unwrap(), None);
+
+        let sql = meta(r#"{"progress": "Complete", "hasSQL": true}"#);
+        assert_eq!(next_page(&sql, 0, 100, 100).unwrap(), None);
+        // a truncated SQL result fails instead of ending the stream
+        let limited = meta(r#"{"progress": "Complete", "hasSQL": true, "limited": 1}"#);
+        assert!(next_page(&limited, 0, 100, 100).is_err());
+
+        // a scan may match fewer logs than the page size before the end
+        let scan = meta(
+            r#"{"progress": "Complete", "phraseQueryInfo": {"scanAll": false, "beginOffset": 0, "endOffset": 5000}}"#,
+        );
+        assert_eq!(next_page(&scan, 0, 100, 3).unwrap(), Some((5000, None)));
+        assert_eq!(next_page(&scan, 5000, 100, 0).unwrap(), None);
+        let scan_all = meta(
+            r#"{"progress": "Complete", "phraseQueryInfo": {"scanAll": true, "endOffset": 5000}}"#,
+        );
+        assert_eq!(next_page(&scan_all, 0, 100, 3).unwrap(), None);
+
+        let marker = meta(r#"{"progress": "Complete", "marker": "abc"}"#);
+        assert_eq!(
+            next_page(&marker, 0, 100, 7).unwrap(),
+            Some((7, Some("abc".to_string())))
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use super::*;
+use crate::compress::CompressType;
+use http::header::ACCEPT_ENCODING;
+
+impl crate::client::Client {
+    /// Query the logs of several logstores of a project with one SQL statement.
+    ///
+    /// Unlike `get_logs`, the query is a SQL statement, which selects the logstores queried
+    /// in its `FROM` clause, so that it can join or union them, and the time range in its `WHERE` clause.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project containing the logstores
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let resp = client.get_project_logs("my-project")
+    ///     .query(
+    ///         "SELECT status, count(*) AS pv FROM (
+    ///             SELECT status FROM \"access-log\" WHERE __time__ > to_unixtime(now()) - 3600
+    ///             UNION ALL
+    ///             SELECT status FROM \"api-log\" WHERE __time__ > to_unixtime(now()) - 3600
+    ///         ) GROUP BY status",
+    ///     )
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for row in resp.get_body().logs() {
+    ///     println!("{:?}", row);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_project_logs(&self, project: impl AsRef<str>) -> GetProjectLogsRequestBuilder {
+        GetProjectLogsRequestBuilder {
+            project: project.as_ref().to_string(),
+            handle: self.handle.clone(),
+            query: None,
+            power_sql: None,
+        }
+    }
+}
+
+pub struct GetProjectLogsRequestBuilder {
+    project: String,
+    handle: HandleRef,
+    query: Option<String>,
+    power_sql: Option<bool>,
+}
+
+request_options!(GetProjectLogsRequestBuilder);
+
+impl GetProjectLogsRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<GetLogsResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Required, the SQL statement to run, e.g. `SELECT count(*) FROM "my-logstore"`.
+    pub fn query<T: Into<String>>(mut self, query: T) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Optional, whether to use power SQL.
+    pub fn power_sql(mut self, power_sql: bool) -> Self {
+        self.power_sql = Some(power_sql);
+        self
+    }
+
+    fn build(self) -> BuildResult<GetProjectLogsRequest> {
+        check_required!(("query", self.query));
+        Ok((
+            self.handle,
+            GetProjectLogsRequest {
+                project: self.project,
+                query: self.query.unwrap(),
+                power_sql: self.power_sql,
+            },
+        ))
+    }
+}
+
+struct GetProjectLogsRequest {
+    project: String,
+    query: String,
+    power_sql: Option<bool>,
+}
+
+impl Request for GetProjectLogsRequest {
+    type ResponseBody = GetLogsResponse;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        "/logs"
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        let mut params = vec![("query".to_string(), self.query.clone())];
+        if let Some(power_sql) = self.power_sql {
+            params.push(("powerSql".to_string(), power_sql.to_string()));
+        }
+        Some(params)
+    }
+
+    fn headers(&self) -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            ACCEPT_ENCODING,
+            CompressType::Lz4
+                .to_string()
+                .parse()
+                .expect("fail to insert CompressType into headers"),
+        );
+        headers
+    }
+}
@@ -12,6 +12,19 @@ pub use get_index::*;
 mod delete_index;
 pub use delete_index::*;
 
+mod ensure_index;
+pub use ensure_index::*;
+
+mod infer_index;
+
+mod diff_index;
+pub use diff_index::*;
+
+mod validate_index;
+
+mod token_preset;
+pub use token_preset::*;
+
 use super::*;
 
 /// Builder for constructing Index configuration.
@@ -30,6 +43,7 @@ use super::*;
 ///     case_sensitive: false,
 ///     chn: true,
 ///     token: token_list![",", " ", ";"],
+///     ..Default::default()
 /// };
 ///
 /// let index = Index::builder()
@@ -46,6 +60,7 @@ use super::*;
 ///         chn: false,
 ///         token: token_list![],
 ///         doc_value: true,
+///         ..Default::default()
 ///     })
 /// );
 ///
@@ -161,6 +176,7 @@ impl IndexBuilder {
             log_reduce: self.log_reduce,
             log_reduce_white_list: self.log_reduce_white_list,
             log_reduce_black_list: self.log_reduce_black_list,
+            extra: Default::default(),
         }
     }
 }
@@ -181,6 +197,7 @@ impl IndexBuilder {
 ///     case_sensitive: false,
 ///     chn: true,
 ///     token: token_list![",", " ", ";"],
+///     ..Default::default()
 /// };
 ///
 /// let index = Index::builder()
@@ -197,6 +214,7 @@ impl IndexBuilder {
 ///         chn: false,
 ///         token: token_list![],
 ///         doc_value: true,
+///         ..Default::default()
 ///     })
 /// );
 ///
@@ -204,7 +222,7 @@ impl IndexBuilder {
 ///     .keys(keys)
 ///     .build();
 /// ```
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
 pub struct Index {
     /// Maximum length for statistics fields
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -227,6 +245,9 @@ pub struct Index {
     /// Blacklist for log clustering fields (only effective when log_reduce is enabled)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub log_reduce_black_list: Option<Vec<String>>,
+    /// Fields not known by this SDK, kept as is so that they are sent back on update
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl Index {
@@ -254,9 +275,10 @@ impl Index {
 ///     case_sensitive: false,
 ///     chn: true,
 ///     token: token_list![",", " ", ";", "\n", "\t"],
+///     ..Default::default()
 /// };
 /// ```
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
 pub struct FullTextIndex {
     /// Whether the search is case-sensitive
     #[serde(rename = "caseSensitive")]
@@ -265,6 +287,9 @@ pub struct FullTextIndex {
     pub chn: bool,
     /// List of delimiter tokens for tokenization
     pub token: Vec<String>,
+    /// Fields not known by this SDK, kept as is so that they are sent back on update
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Field index type enumeration.
@@ -289,9 +314,10 @@ pub struct FullTextIndex {
 ///     chn: false,
 ///     token: token_list![",", "\t", " "],
 ///     doc_value: true,
+///     ..Default::default()
 /// });
 /// ```
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum FieldIndex {
     Text(IndexKeyText),
@@ -319,9 +345,10 @@ pub enum FieldIndex {
 ///     max_depth: 3,
 ///     index_all: true,
 ///     json_keys: None,
+///     ..Default::default()
 /// };
 /// ```
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
 pub struct IndexKeyJson {
     /// Whether the search is case-sensitive
     #[serde(rename = "caseSensitive")]
@@ -342,6 +369,9 @@ pub struct IndexKeyJson {
     /// Specific JSON keys to index
     #[serde(skip_serializing_if = "Option::is_none")]
     pub json_keys: Option<std::collections::HashMap<String, IndexJsonKey>>,
+    /// Fields not known by this SDK, kept as is so that they are sent back on update
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON nested field index type enumeration.
@@ -359,20 +389,57 @@ pub struct IndexKeyJson {
 ///     chn: false,
 ///     token: token_list![",", "\t", " "],
 ///     doc_value: true,
+///     ..Default::default()
 /// });
 /// ```
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum IndexJsonKey {
+    /// Text field with its own tokenization settings
     Text(IndexKeyText),
+    /// Text field tokenized like the JSON field it is in, also of type `text` on the wire
+    #[serde(rename = "text")]
+    JsonText(IndexKeyJsonText),
     Long(IndexKeyLong),
     Double(IndexKeyDouble),
+    /// Nested JSON field
+    Json(IndexKeyJson),
+}
+
+impl<'de> Deserialize<'de> for IndexJsonKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let mut fields = serde_json::Map::deserialize(deserializer)?;
+        let index_type = match fields.remove("type") {
+            Some(serde_json::Value::String(index_type)) => index_type,
+            _ => return Err(D::Error::missing_field("type")),
+        };
+        // text fields without tokens are tokenized like the JSON field they are in
+        let has_token = fields.contains_key("token");
+        let fields = serde_json::Value::Object(fields);
+        match index_type.as_str() {
+            "text" if has_token => serde_json::from_value(fields).map(IndexJsonKey::Text),
+            "text" => serde_json::from_value(fields).map(IndexJsonKey::JsonText),
+            "long" => serde_json::from_value(fields).map(IndexJsonKey::Long),
+            "double" => serde_json::from_value(fields).map(IndexJsonKey::Double),
+            "json" => serde_json::from_value(fields).map(IndexJsonKey::Json),
+            other => {
+                return Err(D::Error::unknown_variant(
+                    other,
+                    &["text", "long", "double", "json"],
+                ))
+            }
+        }
+        .map_err(D::Error::custom)
+    }
 }
 
 /// Text field index configuration.
 ///
 /// Configures indexing for text-type fields with full-text search capabilities.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
 pub struct IndexKeyText {
     /// Whether the search is case-sensitive
     #[serde(rename = "caseSensitive")]
@@ -386,40 +453,92 @@ pub struct IndexKeyText {
     pub token: Vec<String>,
     /// Whether to enable doc value for analytics
     pub doc_value: bool,
+    /// Fields not known by this SDK, kept as is so that they are sent back on update
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Long integer field index configuration.
 ///
 /// Configures indexing for long integer fields.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
 pub struct IndexKeyLong {
     /// Field alias for display
     #[serde(skip_serializing_if = "Option::is_none")]
     pub alias: Option<String>,
     /// Whether to enable doc value for analytics
     pub doc_value: bool,
+    /// Fields not known by this SDK, kept as is so that they are sent back on update
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Double precision float field index configuration.
 ///
 /// Configures indexing for double precision floating-point fields.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
 pub struct IndexKeyDouble {
     /// Field alias for display
     #[serde(skip_serializing_if = "Option::is_none")]
     pub alias: Option<String>,
     /// Whether to enable doc value for analytics
     pub doc_value: bool,
+    /// Fields not known by this SDK, kept as is so that they are sent back on update
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// Text field index configuration.
+/// Text field index configuration within a JSON field.
 ///
-/// Configures indexing for text-type fields with full-text search capabilities.
-#[derive(Serialize, Deserialize)]
+/// The field is tokenized with the settings of the JSON field it is in.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
 pub struct IndexKeyJsonText {
     /// Field alias for display
     #[serde(skip_serializing_if = "Option::is_none")]
     pub alias: Option<String>,
     /// Whether to enable doc value for analytics
     pub doc_value: bool,
+    /// Fields not known by this SDK, kept as is so that they are sent back on update
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_index_round_trip() {
+        let value = json!({
+            "max_text_len": 2048,
+            "lastModifyTime": 1690254376,
+            "line": {"caseSensitive": false, "chn": false, "token": [","], "exclude_keys": ["body"]},
+            "keys": {
+                "body": {
+                    "type": "json", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true,
+                    "max_depth": -1, "index_all": true,
+                    "json_keys": {
+                        "path": {"type": "text", "doc_value": true},
+                        "method": {"type": "text", "caseSensitive": true, "chn": false, "token": ["/"], "doc_value": true},
+                        "headers": {
+                            "type": "json", "caseSensitive": false, "chn": false, "token": [","], "doc_value": false,
+                            "max_depth": 1, "index_all": false,
+                            "json_keys": {"host": {"type": "text", "doc_value": true}},
+                        },
+                    },
+                },
+            },
+        });
+        let index: Index = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(index.extra["lastModifyTime"], 1690254376);
+        let Some(FieldIndex::Json(body)) = &index.keys.as_ref().unwrap().get("body") else {
+            panic!("body is not a json field index");
+        };
+        let json_keys = body.json_keys.as_ref().unwrap();
+        assert!(matches!(json_keys["path"], IndexJsonKey::JsonText(_)));
+        assert!(matches!(json_keys["method"], IndexJsonKey::Text(_)));
+        assert!(matches!(json_keys["headers"], IndexJsonKey::Json(_)));
+        assert_eq!(serde_json::to_value(&index).unwrap(), value);
+    }
 }
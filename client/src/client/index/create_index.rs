@@ -47,6 +47,8 @@ pub struct CreateIndexRequestBuilder {
     index: Index,
 }
 
+request_options!(CreateIndexRequestBuilder);
+
 impl CreateIndexRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<()> {
@@ -57,6 +59,7 @@ impl CreateIndexRequestBuilder {
     }
 
     fn build(self) -> BuildResult<CreateIndexRequest> {
+        self.index.validate_request()?;
         Ok((
             self.handle,
             CreateIndexRequest {
@@ -37,6 +37,8 @@ pub struct DeleteIndexRequestBuilder {
     handle: HandleRef,
 }
 
+request_options!(DeleteIndexRequestBuilder);
+
 impl DeleteIndexRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<()> {
@@ -0,0 +1,825 @@
+use std::collections::{BTreeSet, HashMap};
+
+use super::*;
+
+impl Index {
+    /// Compare this index, such as the one returned by `get_index`, with a desired index.
+    ///
+    /// Field indexes and the full-text index are compared as a whole, since `update_index` replaces them,
+    /// while the other settings such as `max_text_len` are only compared if they are set in `desired`,
+    /// and are kept as they are by [`IndexDiff::apply`] otherwise.
+    /// Nested fields of JSON field indexes are reported with their dotted path, e.g. `body.status`.
+    ///
+    /// Settings not known by this SDK, in the `extra` fields, are compared if they are set in `desired`,
+    /// except the ones owned by the server such as `lastModifyTime`, and are reported as
+    /// [`IndexChange::SettingChanged`], e.g. `line.exclude_keys`. The ones only set in this index are
+    /// not changes, and are kept by [`IndexDiff::apply`] like the unset typed settings, for the
+    /// full-text index and the field indexes which keep their type.
+    ///
+    /// # Arguments
+    ///
+    /// * `desired` - Desired index configuration
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client, desired: aliyun_log_rust_sdk::Index) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let current = client.get_index("my-project", "my-logstore").send().await?;
+    /// let diff = current.get_body().diff(&desired);
+    /// for change in diff.changes() {
+    ///     println!("{:?}, needs reindex: {}", change, change.needs_reindex());
+    /// }
+    /// if diff.needs_reindex() {
+    ///     println!("historical data needs reindexing to be searched with the new index");
+    /// }
+    /// diff.apply(&client, "my-project", "my-logstore").send().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff(&self, desired: &Index) -> IndexDiff {
+        let mut changes = Vec::new();
+        if desired.max_text_len.is_some() && desired.max_text_len != self.max_text_len {
+            changes.push(IndexChange::setting("max_text_len"));
+        }
+        if desired.scan_index.is_some() && desired.scan_index != self.scan_index {
+            changes.push(IndexChange::setting("scan_index"));
+        }
+        if desired.log_reduce.is_some() && desired.log_reduce != self.log_reduce {
+            changes.push(IndexChange::setting("log_reduce"));
+        }
+        if desired.log_reduce_white_list.is_some()
+            && desired.log_reduce_white_list != self.log_reduce_white_list
+        {
+            changes.push(IndexChange::setting("log_reduce_white_list"));
+        }
+        if desired.log_reduce_black_list.is_some()
+            && desired.log_reduce_black_list != self.log_reduce_black_list
+        {
+            changes.push(IndexChange::setting("log_reduce_black_list"));
+        }
+
+        match (&self.line, &desired.line) {
+            (None, Some(_)) => changes.push(IndexChange::LineAdded),
+            (Some(_), None) => changes.push(IndexChange::LineRemoved),
+            (Some(current), Some(desired)) => {
+                diff_text(
+                    &mut changes,
+                    None,
+                    TextSettings::of_line(current),
+                    TextSettings::of_line(desired),
+                );
+                diff_extra(&mut changes, "line", &current.extra, &desired.extra);
+            }
+            (None, None) => {}
+        }
+
+        let empty = HashMap::new();
+        let current_keys = self.keys.as_ref().unwrap_or(&empty);
+        let desired_keys = desired.keys.as_ref().unwrap_or(&empty);
+        let names: BTreeSet<&String> = current_keys.keys().chain(desired_keys.keys()).collect();
+        for name in names {
+            match (current_keys.get(name), desired_keys.get(name)) {
+                (None, Some(_)) => changes.push(IndexChange::KeyAdded { key: name.clone() }),
+                (Some(_), None) => changes.push(IndexChange::KeyRemoved { key: name.clone() }),
+                (Some(current), Some(desired)) => diff_key(&mut changes, name, current, desired),
+                (None, None) => {}
+            }
+        }
+
+        for (name, value) in &desired.extra {
+            if !SERVER_OWNED_FIELDS.contains(&name.as_str()) && self.extra.get(name) != Some(value)
+            {
+                changes.push(IndexChange::setting(name.clone()));
+            }
+        }
+
+        IndexDiff {
+            changes,
+            desired: self.merge_unset(desired),
+        }
+    }
+
+    /// The desired index, with the typed settings it leaves unset and the settings not known by
+    /// this SDK it does not set taken from this one, since `update_index` replaces the whole index,
+    /// and without the fields owned by the server.
+    fn merge_unset(&self, desired: &Index) -> Index {
+        let mut merged = desired.clone();
+        merged.max_text_len = merged.max_text_len.or(self.max_text_len);
+        merged.scan_index = merged.scan_index.or(self.scan_index);
+        merged.log_reduce = merged.log_reduce.or(self.log_reduce);
+        if merged.log_reduce_white_list.is_none() {
+            merged.log_reduce_white_list = self.log_reduce_white_list.clone();
+        }
+        if merged.log_reduce_black_list.is_none() {
+            merged.log_reduce_black_list = self.log_reduce_black_list.clone();
+        }
+        keep_extra(&mut merged.extra, &self.extra);
+        if let (Some(merged), Some(current)) = (&mut merged.line, &self.line) {
+            keep_extra(&mut merged.extra, &current.extra);
+        }
+        if let (Some(merged), Some(current)) = (&mut merged.keys, &self.keys) {
+            for (name, merged) in merged.iter_mut() {
+                if let Some(current) = current.get(name) {
+                    merge_key_extra(merged, current);
+                }
+            }
+        }
+        merged
+            .extra
+            .retain(|name, _| !SERVER_OWNED_FIELDS.contains(&name.as_str()));
+        merged
+    }
+}
+
+/// Keep the settings not known by this SDK only set in `current`.
+fn keep_extra(
+    merged: &mut serde_json::Map<String, serde_json::Value>,
+    current: &serde_json::Map<String, serde_json::Value>,
+) {
+    for (name, value) in current {
+        if !merged.contains_key(name) {
+            merged.insert(name.clone(), value.clone());
+        }
+    }
+}
+
+/// Keep the settings not known by this SDK of a field index, and of its nested fields,
+/// unless its type changes.
+fn merge_key_extra(merged: &mut FieldIndex, current: &FieldIndex) {
+    match (merged, current) {
+        (FieldIndex::Text(merged), FieldIndex::Text(current)) => {
+            keep_extra(&mut merged.extra, &current.extra)
+        }
+        (FieldIndex::Long(merged), FieldIndex::Long(current)) => {
+            keep_extra(&mut merged.extra, &current.extra)
+        }
+        (FieldIndex::Double(merged), FieldIndex::Double(current)) => {
+            keep_extra(&mut merged.extra, &current.extra)
+        }
+        (FieldIndex::Json(merged), FieldIndex::Json(current)) => {
+            merge_json_key_extra(merged, current)
+        }
+        _ => {}
+    }
+}
+
+fn merge_json_key_extra(merged: &mut IndexKeyJson, current: &IndexKeyJson) {
+    keep_extra(&mut merged.extra, &current.extra);
+    let (Some(merged), Some(current)) = (&mut merged.json_keys, &current.json_keys) else {
+        return;
+    };
+    for (name, merged) in merged.iter_mut() {
+        let Some(current) = current.get(name) else {
+            continue;
+        };
+        match (merged, current) {
+            (IndexJsonKey::Json(merged), IndexJsonKey::Json(current)) => {
+                merge_json_key_extra(merged, current)
+            }
+            (merged, current) if json_key_type(merged) == json_key_type(current) => {
+                keep_extra(json_key_extra_mut(merged), json_key_extra(current))
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Fields of the index returned by `get_index` which are set by the server, not by updates.
+const SERVER_OWNED_FIELDS: &[&str] = &["lastModifyTime"];
+
+/// Differences between two index configurations, returned by [`Index::diff`].
+#[derive(Debug, Clone)]
+pub struct IndexDiff {
+    changes: Vec<IndexChange>,
+    desired: Index,
+}
+
+impl IndexDiff {
+    /// The changes from the current index to the desired one.
+    pub fn changes(&self) -> &[IndexChange] {
+        &self.changes
+    }
+
+    /// Whether the desired index is the same as the current one, so that an update is a no-op.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Whether any change only applies to data written after the update,
+    /// so that historical data needs reindexing.
+    pub fn needs_reindex(&self) -> bool {
+        self.changes.iter().any(IndexChange::needs_reindex)
+    }
+
+    /// The index configuration `apply` sends, the desired one with the typed settings it leaves
+    /// unset, and the settings not known by this SDK it does not set, taken from the current one.
+    pub fn desired(&self) -> &Index {
+        &self.desired
+    }
+
+    /// Update the index of a logstore to the desired configuration, unless there is no change.
+    ///
+    /// The result is whether the index was updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to update the index with
+    /// * `project` - The name of the project
+    /// * `logstore` - The name of the logstore
+    pub fn apply(
+        self,
+        client: &crate::Client,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+    ) -> ApplyIndexDiffRequestBuilder {
+        ApplyIndexDiffRequestBuilder {
+            project: project.as_ref().to_string(),
+            logstore: logstore.as_ref().to_string(),
+            handle: client.handle.clone(),
+            diff: self,
+        }
+    }
+}
+
+/// A change between two index configurations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IndexChange {
+    /// The full-text index is added
+    LineAdded,
+    /// The full-text index is removed
+    LineRemoved,
+    /// A field index is added
+    KeyAdded { key: String },
+    /// A field index is removed
+    KeyRemoved { key: String },
+    /// The type of a field index changes, e.g. from `text` to `long`
+    TypeChanged {
+        key: String,
+        from: String,
+        to: String,
+    },
+    /// The delimiter tokens change, of the full-text index if `key` is `None`
+    TokenChanged { key: Option<String> },
+    /// Case sensitivity changes, of the full-text index if `key` is `None`
+    CaseSensitiveChanged {
+        key: Option<String>,
+        case_sensitive: bool,
+    },
+    /// Chinese word segmentation changes, of the full-text index if `key` is `None`
+    ChnChanged { key: Option<String>, chn: bool },
+    /// Doc value, used by analytics, is enabled or disabled for a field index
+    DocValueChanged { key: String, doc_value: bool },
+    /// The alias of a field index changes
+    AliasChanged { key: String },
+    /// Another setting changes, such as `max_text_len`, `keys.<field>.max_depth`,
+    /// or one not known by this SDK, such as `line.exclude_keys`
+    SettingChanged { name: String },
+}
+
+impl IndexChange {
+    fn setting(name: impl Into<String>) -> IndexChange {
+        IndexChange::SettingChanged { name: name.into() }
+    }
+
+    /// Whether the change only applies to data written after the update,
+    /// so that historical data needs reindexing.
+    pub fn needs_reindex(&self) -> bool {
+        match self {
+            IndexChange::LineRemoved
+            | IndexChange::KeyRemoved { .. }
+            | IndexChange::AliasChanged { .. } => false,
+            IndexChange::DocValueChanged { doc_value, .. } => *doc_value,
+            // the settings of the full-text and field indexes, known or not, change how data is indexed
+            IndexChange::SettingChanged { name } => {
+                name == "max_text_len" || name.starts_with("line.") || name.starts_with("keys.")
+            }
+            _ => true,
+        }
+    }
+}
+
+pub struct ApplyIndexDiffRequestBuilder {
+    project: String,
+    logstore: String,
+    handle: HandleRef,
+    diff: IndexDiff,
+}
+
+request_options!(ApplyIndexDiffRequestBuilder);
+
+impl ApplyIndexDiffRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> BoxFuture<Result<bool>> {
+        Box::pin(async move {
+            if self.diff.is_empty() {
+                return Ok(false);
+            }
+            let client = crate::Client {
+                handle: self.handle,
+            };
+            client
+                .update_index(&self.project, &self.logstore, self.diff.desired)
+                .send()
+                .await?;
+            Ok(true)
+        })
+    }
+}
+
+/// Tokenization settings of the full-text index and of text or JSON field indexes.
+struct TextSettings<'a> {
+    case_sensitive: bool,
+    chn: bool,
+    token: &'a [String],
+}
+
+impl<'a> TextSettings<'a> {
+    fn of_line(line: &'a FullTextIndex) -> Self {
+        TextSettings {
+            case_sensitive: line.case_sensitive,
+            chn: line.chn,
+            token: &line.token,
+        }
+    }
+
+    fn of_text(text: &'a IndexKeyText) -> Self {
+        TextSettings {
+            case_sensitive: text.case_sensitive,
+            chn: text.chn,
+            token: &text.token,
+        }
+    }
+}
+
+fn diff_text(
+    changes: &mut Vec<IndexChange>,
+    key: Option<&str>,
+    current: TextSettings,
+    desired: TextSettings,
+) {
+    let key = key.map(str::to_string);
+    if current.token != desired.token {
+        changes.push(IndexChange::TokenChanged { key: key.clone() });
+    }
+    if current.case_sensitive != desired.case_sensitive {
+        changes.push(IndexChange::CaseSensitiveChanged {
+            key: key.clone(),
+            case_sensitive: desired.case_sensitive,
+        });
+    }
+    if current.chn != desired.chn {
+        changes.push(IndexChange::ChnChanged {
+            key,
+            chn: desired.chn,
+        });
+    }
+}
+
+fn diff_common(
+    changes: &mut Vec<IndexChange>,
+    key: &str,
+    current: (&Option<String>, bool),
+    desired: (&Option<String>, bool),
+) {
+    if current.0 != desired.0 {
+        changes.push(IndexChange::AliasChanged {
+            key: key.to_string(),
+        });
+    }
+    if current.1 != desired.1 {
+        changes.push(IndexChange::DocValueChanged {
+            key: key.to_string(),
+            doc_value: desired.1,
+        });
+    }
+}
+
+fn diff_key(changes: &mut Vec<IndexChange>, key: &str, current: &FieldIndex, desired: &FieldIndex) {
+    match (current, desired) {
+        (FieldIndex::Text(current), FieldIndex::Text(desired)) => {
+            diff_text_key(changes, key, current, desired)
+        }
+        (FieldIndex::Long(current), FieldIndex::Long(desired)) => {
+            diff_common(
+                changes,
+                key,
+                (&current.alias, current.doc_value),
+                (&desired.alias, desired.doc_value),
+            );
+            diff_key_extra(changes, key, &current.extra, &desired.extra);
+        }
+        (FieldIndex::Double(current), FieldIndex::Double(desired)) => {
+            diff_common(
+                changes,
+                key,
+                (&current.alias, current.doc_value),
+                (&desired.alias, desired.doc_value),
+            );
+            diff_key_extra(changes, key, &current.extra, &desired.extra);
+        }
+        (FieldIndex::Json(current), FieldIndex::Json(desired)) => {
+            diff_json_key(changes, key, current, desired)
+        }
+        (current, desired) => changes.push(IndexChange::TypeChanged {
+            key: key.to_string(),
+            from: field_type(current).to_string(),
+            to: field_type(desired).to_string(),
+        }),
+    }
+}
+
+fn diff_text_key(
+    changes: &mut Vec<IndexChange>,
+    key: &str,
+    current: &IndexKeyText,
+    desired: &IndexKeyText,
+) {
+    diff_text(
+        changes,
+        Some(key),
+        TextSettings::of_text(current),
+        TextSettings::of_text(desired),
+    );
+    diff_common(
+        changes,
+        key,
+        (&current.alias, current.doc_value),
+        (&desired.alias, desired.doc_value),
+    );
+    diff_key_extra(changes, key, &current.extra, &desired.extra);
+}
+
+fn diff_json_key(
+    changes: &mut Vec<IndexChange>,
+    key: &str,
+    current: &IndexKeyJson,
+    desired: &IndexKeyJson,
+) {
+    diff_text(
+        changes,
+        Some(key),
+        TextSettings {
+            case_sensitive: current.case_sensitive,
+            chn: current.chn,
+            token: &current.token,
+        },
+        TextSettings {
+            case_sensitive: desired.case_sensitive,
+            chn: desired.chn,
+            token: &desired.token,
+        },
+    );
+    diff_common(
+        changes,
+        key,
+        (&current.alias, current.doc_value),
+        (&desired.alias, desired.doc_value),
+    );
+    if current.max_depth != desired.max_depth {
+        changes.push(IndexChange::setting(format!("keys.{key}.max_depth")));
+    }
+    if current.index_all != desired.index_all {
+        changes.push(IndexChange::setting(format!("keys.{key}.index_all")));
+    }
+    diff_key_extra(changes, key, &current.extra, &desired.extra);
+
+    let empty = HashMap::new();
+    let current_keys = current.json_keys.as_ref().unwrap_or(&empty);
+    let desired_keys = desired.json_keys.as_ref().unwrap_or(&empty);
+    let names: BTreeSet<&String> = current_keys.keys().chain(desired_keys.keys()).collect();
+    for name in names {
+        let path = format!("{key}.{name}");
+        match (current_keys.get(name), desired_keys.get(name)) {
+            (None, Some(_)) => changes.push(IndexChange::KeyAdded { key: path }),
+            (Some(_), None) => changes.push(IndexChange::KeyRemoved { key: path }),
+            (Some(IndexJsonKey::Text(current)), Some(IndexJsonKey::Text(desired))) => {
+                diff_text_key(changes, &path, current, desired)
+            }
+            (Some(IndexJsonKey::Json(current)), Some(IndexJsonKey::Json(desired))) => {
+                diff_json_key(changes, &path, current, desired)
+            }
+            (Some(current), Some(desired))
+                if json_key_type(current) == json_key_type(desired)
+                    && !matches!(current, IndexJsonKey::Json(_)) =>
+            {
+                // text fields switching between their own tokens and the ones of the JSON field
+                if matches!(current, IndexJsonKey::Text(_))
+                    != matches!(desired, IndexJsonKey::Text(_))
+                {
+                    changes.push(IndexChange::TokenChanged {
+                        key: Some(path.clone()),
+                    });
+                }
+                diff_common(
+                    changes,
+                    &path,
+                    json_key_common(current),
+                    json_key_common(desired),
+                );
+                diff_key_extra(
+                    changes,
+                    &path,
+                    json_key_extra(current),
+                    json_key_extra(desired),
+                );
+            }
+            (Some(current), Some(desired)) => changes.push(IndexChange::TypeChanged {
+                key: path,
+                from: json_key_type(current).to_string(),
+                to: json_key_type(desired).to_string(),
+            }),
+            (None, None) => {}
+        }
+    }
+}
+
+/// Compare the settings not known by this SDK of a field index.
+fn diff_key_extra(
+    changes: &mut Vec<IndexChange>,
+    key: &str,
+    current: &serde_json::Map<String, serde_json::Value>,
+    desired: &serde_json::Map<String, serde_json::Value>,
+) {
+    diff_extra(changes, &format!("keys.{key}"), current, desired)
+}
+
+/// Compare the settings not known by this SDK set in `desired`, reported as `<prefix>.<name>`.
+fn diff_extra(
+    changes: &mut Vec<IndexChange>,
+    prefix: &str,
+    current: &serde_json::Map<String, serde_json::Value>,
+    desired: &serde_json::Map<String, serde_json::Value>,
+) {
+    let names: BTreeSet<&String> = desired.keys().collect();
+    for name in names {
+        if !SERVER_OWNED_FIELDS.contains(&name.as_str()) && current.get(name) != desired.get(name) {
+            changes.push(IndexChange::setting(format!("{prefix}.{name}")));
+        }
+    }
+}
+
+fn field_type(index: &FieldIndex) -> &'static str {
+    match index {
+        FieldIndex::Text(_) => "text",
+        FieldIndex::Long(_) => "long",
+        FieldIndex::Double(_) => "double",
+        FieldIndex::Json(_) => "json",
+    }
+}
+
+fn json_key_type(index: &IndexJsonKey) -> &'static str {
+    match index {
+        IndexJsonKey::Text(_) | IndexJsonKey::JsonText(_) => "text",
+        IndexJsonKey::Long(_) => "long",
+        IndexJsonKey::Double(_) => "double",
+        IndexJsonKey::Json(_) => "json",
+    }
+}
+
+fn json_key_common(index: &IndexJsonKey) -> (&Option<String>, bool) {
+    match index {
+        IndexJsonKey::Text(text) => (&text.alias, text.doc_value),
+        IndexJsonKey::JsonText(text) => (&text.alias, text.doc_value),
+        IndexJsonKey::Long(long) => (&long.alias, long.doc_value),
+        IndexJsonKey::Double(double) => (&double.alias, double.doc_value),
+        IndexJsonKey::Json(json) => (&json.alias, json.doc_value),
+    }
+}
+
+fn json_key_extra(index: &IndexJsonKey) -> &serde_json::Map<String, serde_json::Value> {
+    match index {
+        IndexJsonKey::Text(text) => &text.extra,
+        IndexJsonKey::JsonText(text) => &text.extra,
+        IndexJsonKey::Long(long) => &long.extra,
+        IndexJsonKey::Double(double) => &double.extra,
+        IndexJsonKey::Json(json) => &json.extra,
+    }
+}
+
+fn json_key_extra_mut(index: &mut IndexJsonKey) -> &mut serde_json::Map<String, serde_json::Value> {
+    match index {
+        IndexJsonKey::Text(text) => &mut text.extra,
+        IndexJsonKey::JsonText(text) => &mut text.extra,
+        IndexJsonKey::Long(long) => &mut long.extra,
+        IndexJsonKey::Double(double) => &mut double.extra,
+        IndexJsonKey::Json(json) => &mut json.extra,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn index(value: serde_json::Value) -> Index {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_diff() {
+        let current = index(json!({
+            "max_text_len": 2048,
+            "line": {"caseSensitive": false, "chn": false, "token": [",", " "]},
+            "keys": {
+                "level": {"type": "text", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true},
+                "latency": {"type": "long", "doc_value": false},
+                "host": {"type": "text", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true},
+                "body": {
+                    "type": "json", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true,
+                    "max_depth": -1, "index_all": true,
+                    "json_keys": {"status": {"type": "text", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true}},
+                },
+            },
+        }));
+        assert!(current.diff(&current).is_empty());
+
+        let desired = index(json!({
+            "line": {"caseSensitive": false, "chn": false, "token": [","]},
+            "keys": {
+                "level": {"type": "text", "caseSensitive": true, "chn": false, "token": [","], "doc_value": true},
+                "latency": {"type": "long", "doc_value": true},
+                "host": {"type": "long", "doc_value": true},
+                "body": {
+                    "type": "json", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true,
+                    "max_depth": -1, "index_all": true,
+                    "json_keys": {"status": {"type": "long", "doc_value": true}},
+                },
+                "ip": {"type": "text", "caseSensitive": false, "chn": false, "token": [","], "doc_value": false},
+            },
+            "log_reduce": true,
+        }));
+        let diff = current.diff(&desired);
+        assert_eq!(
+            diff.changes(),
+            [
+                IndexChange::setting("log_reduce"),
+                IndexChange::TokenChanged { key: None },
+                IndexChange::TypeChanged {
+                    key: "body.status".to_string(),
+                    from: "text".to_string(),
+                    to: "long".to_string(),
+                },
+                IndexChange::TypeChanged {
+                    key: "host".to_string(),
+                    from: "text".to_string(),
+                    to: "long".to_string(),
+                },
+                IndexChange::KeyAdded {
+                    key: "ip".to_string()
+                },
+                IndexChange::DocValueChanged {
+                    key: "latency".to_string(),
+                    doc_value: true,
+                },
+                IndexChange::CaseSensitiveChanged {
+                    key: Some("level".to_string()),
+                    case_sensitive: true,
+                },
+            ]
+        );
+        assert!(diff.needs_reindex());
+
+        let diff = desired.diff(&index(json!({"keys": {}})));
+        assert_eq!(diff.changes().len(), 6);
+        assert!(!diff.needs_reindex());
+    }
+
+    #[test]
+    fn test_diff_keeps_unset_settings() {
+        let current = index(json!({
+            "max_text_len": 2048,
+            "log_reduce": true,
+            "log_reduce_white_list": ["level"],
+            "line": {"caseSensitive": false, "chn": false, "token": [","]},
+            "lastModifyTime": 1700000000,
+        }));
+        let desired = index(json!({
+            "line": {"caseSensitive": false, "chn": false, "token": [","]},
+        }));
+        let diff = current.diff(&desired);
+        assert!(diff.is_empty());
+        let mut expected = serde_json::to_value(&current).unwrap();
+        expected.as_object_mut().unwrap().remove("lastModifyTime");
+        assert_eq!(serde_json::to_value(diff.desired()).unwrap(), expected);
+
+        let desired = index(json!({"max_text_len": 4096, "keys": {}}));
+        let body = serde_json::to_value(current.diff(&desired).desired()).unwrap();
+        assert_eq!(body["max_text_len"], 4096);
+        assert_eq!(body["log_reduce"], true);
+        assert!(body.get("line").is_none());
+        // fields owned by the server are not sent back
+        assert!(body.get("lastModifyTime").is_none());
+    }
+
+    #[test]
+    fn test_diff_extra() {
+        let current = index(json!({
+            "line": {"caseSensitive": false, "chn": false, "token": [","], "exclude_keys": ["body"]},
+            "keys": {
+                "latency": {"type": "long", "doc_value": true},
+                "body": {
+                    "type": "json", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true,
+                    "max_depth": -1, "index_all": true,
+                    "json_keys": {"code": {"type": "long", "doc_value": true}},
+                },
+            },
+            "lastModifyTime": 1700000000,
+        }));
+        let desired = index(json!({
+            "line": {"caseSensitive": false, "chn": false, "token": [","], "exclude_keys": ["body", "raw"]},
+            "keys": {
+                "latency": {"type": "long", "doc_value": true, "vector_index": "hnsw"},
+                "body": {
+                    "type": "json", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true,
+                    "max_depth": -1, "index_all": true,
+                    "json_keys": {"code": {"type": "long", "doc_value": true, "vector_index": "hnsw"}},
+                },
+            },
+            "lastModifyTime": 1600000000,
+        }));
+        let diff = current.diff(&desired);
+        assert_eq!(
+            diff.changes(),
+            [
+                IndexChange::setting("line.exclude_keys"),
+                IndexChange::setting("keys.body.code.vector_index"),
+                IndexChange::setting("keys.latency.vector_index"),
+            ]
+        );
+        assert!(diff.needs_reindex());
+
+        // settings only returned by the server are not changes
+        let current = desired;
+        let desired = index(json!({
+            "line": {"caseSensitive": false, "chn": false, "token": [","], "exclude_keys": ["body", "raw"]},
+            "keys": {
+                "latency": {"type": "long", "doc_value": true},
+                "body": {
+                    "type": "json", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true,
+                    "max_depth": -1, "index_all": true,
+                    "json_keys": {"code": {"type": "long", "doc_value": true}},
+                },
+            },
+        }));
+        assert!(current.diff(&desired).is_empty());
+    }
+
+    #[test]
+    fn test_diff_keeps_unknown_settings() {
+        let current = index(json!({
+            "line": {"caseSensitive": false, "chn": false, "token": [","], "exclude_keys": ["body"]},
+            "keys": {
+                "latency": {"type": "long", "doc_value": true, "vector_index": "hnsw"},
+                "host": {"type": "text", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true, "embedding": "text"},
+                "body": {
+                    "type": "json", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true,
+                    "max_depth": -1, "index_all": true, "sampling": 10,
+                    "json_keys": {"code": {"type": "long", "doc_value": true, "vector_index": "hnsw"}},
+                },
+            },
+            "storage": "hot",
+            "lastModifyTime": 1700000000,
+        }));
+        let desired = index(json!({
+            "line": {"caseSensitive": false, "chn": false, "token": [","]},
+            "keys": {
+                "latency": {"type": "long", "doc_value": false},
+                "host": {"type": "long", "doc_value": true},
+                "body": {
+                    "type": "json", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true,
+                    "max_depth": -1, "index_all": true,
+                    "json_keys": {"code": {"type": "long", "doc_value": true}},
+                },
+            },
+        }));
+        let diff = current.diff(&desired);
+        assert_eq!(
+            diff.changes(),
+            [
+                IndexChange::TypeChanged {
+                    key: "host".to_string(),
+                    from: "text".to_string(),
+                    to: "long".to_string(),
+                },
+                IndexChange::DocValueChanged {
+                    key: "latency".to_string(),
+                    doc_value: false,
+                },
+            ]
+        );
+        let body = serde_json::to_value(diff.desired()).unwrap();
+        assert_eq!(body["storage"], "hot");
+        assert_eq!(body["line"]["exclude_keys"], json!(["body"]));
+        assert_eq!(body["keys"]["latency"]["vector_index"], "hnsw");
+        assert_eq!(body["keys"]["latency"]["doc_value"], false);
+        assert_eq!(body["keys"]["body"]["sampling"], 10);
+        assert_eq!(
+            body["keys"]["body"]["json_keys"]["code"]["vector_index"],
+            "hnsw"
+        );
+        // settings of a field index whose type changes are dropped with it
+        assert!(body["keys"]["host"].get("embedding").is_none());
+        assert!(body.get("lastModifyTime").is_none());
+    }
+}
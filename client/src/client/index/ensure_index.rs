@@ -0,0 +1,172 @@
+use super::*;
+use crate::ErrorCode;
+
+impl crate::client::Client {
+    /// Create the index of a logstore if it does not exist, or replace it if it differs from `index`.
+    ///
+    /// The index is compared with [`Index::diff`], so that the full-text index and field indexes are
+    /// compared as a whole while the other settings are only compared if set in `index`.
+    /// The outcome lists the fields which differ, with a `keys.<field>` entry for each field index
+    /// added, changed or removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `logstore` - The name of the logstore
+    /// * `index` - Desired index configuration
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::{FullTextIndex, Index, token_list};
+    ///
+    /// let index = Index::builder()
+    ///     .line(FullTextIndex {
+    ///         case_sensitive: false,
+    ///         chn: false,
+    ///         token: token_list![",", " ", ";"],
+    ///         ..Default::default()
+    ///     })
+    ///     .build();
+    /// let outcome = client.ensure_index("my-project", "my-logstore", index)
+    ///     .send()
+    ///     .await?;
+    /// println!("changed fields: {:?}", outcome.changed_fields());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ensure_index(
+        &self,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+        index: Index,
+    ) -> EnsureIndexRequestBuilder {
+        EnsureIndexRequestBuilder {
+            project: project.as_ref().to_string(),
+            logstore: logstore.as_ref().to_string(),
+            handle: self.handle.clone(),
+            index,
+        }
+    }
+}
+
+pub struct EnsureIndexRequestBuilder {
+    project: String,
+    logstore: String,
+    handle: HandleRef,
+    index: Index,
+}
+
+request_options!(EnsureIndexRequestBuilder);
+
+impl EnsureIndexRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> BoxFuture<Result<EnsureOutcome>> {
+        Box::pin(async move {
+            let client = crate::Client {
+                handle: self.handle,
+            };
+            let get = || client.get_index(&self.project, &self.logstore).send();
+            let current = match get().await {
+                Ok(resp) => resp.take_body(),
+                Err(err) if err.code() == Some(ErrorCode::IndexConfigNotExist) => {
+                    let create =
+                        client.create_index(&self.project, &self.logstore, self.index.clone());
+                    match create.send().await {
+                        Ok(_) => return Ok(EnsureOutcome::Created),
+                        // created concurrently, compare with what was created instead
+                        Err(err) if err.code() == Some(ErrorCode::IndexAlreadyExist) => {
+                            get().await?.take_body()
+                        }
+                        Err(err) => return Err(err),
+                    }
+                }
+                Err(err) => return Err(err),
+            };
+
+            let diff = current.diff(&self.index);
+            let changes = changed_fields(&diff);
+            diff.apply(&client, &self.project, &self.logstore)
+                .send()
+                .await?;
+            Ok(changes.into_outcome())
+        })
+    }
+}
+
+/// The fields of the index which differ, each once.
+fn changed_fields(diff: &IndexDiff) -> FieldChanges {
+    let mut fields: Vec<String> = diff.changes().iter().map(changed_field).collect();
+    fields.dedup();
+    let mut changes = FieldChanges::default();
+    fields.into_iter().for_each(|field| changes.push(field));
+    changes
+}
+
+/// The field of the index a change is reported under, e.g. `line` or `keys.<field>`.
+fn changed_field(change: &IndexChange) -> String {
+    match change {
+        IndexChange::LineAdded | IndexChange::LineRemoved => "line".to_string(),
+        IndexChange::TokenChanged { key }
+        | IndexChange::CaseSensitiveChanged { key, .. }
+        | IndexChange::ChnChanged { key, .. } => match key {
+            Some(key) => format!("keys.{key}"),
+            None => "line".to_string(),
+        },
+        IndexChange::KeyAdded { key }
+        | IndexChange::KeyRemoved { key }
+        | IndexChange::TypeChanged { key, .. }
+        | IndexChange::DocValueChanged { key, .. }
+        | IndexChange::AliasChanged { key } => format!("keys.{key}"),
+        IndexChange::SettingChanged { name } => name.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_changed_fields() {
+        let index = |value| serde_json::from_value::<Index>(value).unwrap();
+        let current = index(json!({
+            "max_text_len": 2048,
+            "line": {"caseSensitive": false, "chn": false, "token": [","]},
+            "keys": {
+                "level": {"type": "text", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true},
+                "latency": {"type": "long", "doc_value": true, "lastModifyTime": 1700000000},
+            },
+        }));
+        let desired = index(json!({
+            "line": {"caseSensitive": false, "chn": false, "token": [","]},
+            "keys": {
+                "level": {"type": "text", "caseSensitive": true, "chn": true, "token": [","], "doc_value": true},
+                "host": {"type": "text", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true},
+            },
+            "log_reduce": true,
+        }));
+        assert_eq!(
+            changed_fields(&current.diff(&desired)).into_outcome(),
+            EnsureOutcome::Updated(
+                ["log_reduce", "keys.host", "keys.latency", "keys.level"]
+                    .map(String::from)
+                    .to_vec()
+            )
+        );
+
+        // fields returned by the server only are not changes
+        let desired = index(json!({
+            "line": {"caseSensitive": false, "chn": false, "token": [","]},
+            "keys": {
+                "level": {"type": "text", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true},
+                "latency": {"type": "long", "doc_value": true},
+            },
+        }));
+        assert_eq!(
+            changed_fields(&current.diff(&desired)).into_outcome(),
+            EnsureOutcome::Unchanged
+        );
+    }
+}
@@ -38,6 +38,8 @@ pub struct GetIndexRequestBuilder {
     handle: HandleRef,
 }
 
+request_options!(GetIndexRequestBuilder);
+
 impl GetIndexRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<Index> {
@@ -0,0 +1,269 @@
+use std::collections::{BTreeMap, HashMap};
+
+use aliyun_log_sdk_protobuf::LogGroup;
+
+use super::*;
+
+impl Index {
+    /// Propose an index for logs like the ones in `log_groups`, such as a sample from `pull_logs`.
+    ///
+    /// Each field found is indexed with doc value enabled, by the type of all its non-empty values:
+    /// `long` if they are all integers, `double` if they are all numbers,
+    /// `json` if they are all JSON objects, with `json_keys` proposed the same way
+    /// for their nested fields, and `text` otherwise.
+    /// Text is split by the [`TokenPreset::Default`] tokens, which are also used by the full-text index.
+    ///
+    /// # Arguments
+    ///
+    /// * `log_groups` - Sample of the logs to index
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::get_cursor_models::CursorPos;
+    /// use aliyun_log_rust_sdk::Index;
+    ///
+    /// let cursor = client.get_cursor("my-project", "my-logstore", 0)
+    ///     .cursor_pos(CursorPos::Begin)
+    ///     .send()
+    ///     .await?;
+    /// let sample = client.pull_logs("my-project", "my-logstore", 0)
+    ///     .cursor(cursor.get_body().cursor())
+    ///     .count(100)
+    ///     .send()
+    ///     .await?;
+    /// let index = Index::infer(sample.get_body().log_group_list());
+    /// client.create_index("my-project", "my-logstore", index).send().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn infer<'a>(log_groups: impl IntoIterator<Item = &'a LogGroup>) -> Index {
+        let mut fields = BTreeMap::new();
+        for log_group in log_groups {
+            for log in log_group.logs() {
+                for content in log.contents() {
+                    merge_field(&mut fields, content.key(), FieldType::of(content.value()));
+                }
+            }
+        }
+        let keys: HashMap<String, FieldIndex> = fields
+            .into_iter()
+            .map(|(name, field_type)| (name, field_type.field_index()))
+            .collect();
+        Index::builder()
+            .line(FullTextIndex {
+                case_sensitive: false,
+                chn: false,
+                token: TokenPreset::Default.tokens(),
+                ..Default::default()
+            })
+            .keys(keys)
+            .build()
+    }
+}
+
+/// Type of a field, widened as more values are seen.
+#[derive(Debug, PartialEq)]
+enum FieldType {
+    /// Only empty values seen yet
+    Unknown,
+    Long,
+    Double,
+    Json(BTreeMap<String, FieldType>),
+    Text,
+}
+
+impl FieldType {
+    fn of(value: &str) -> FieldType {
+        let value = value.trim();
+        if value.is_empty() {
+            return FieldType::Unknown;
+        }
+        if value.parse::<i64>().is_ok() {
+            return FieldType::Long;
+        }
+        if value.parse::<f64>().is_ok_and(f64::is_finite) {
+            return FieldType::Double;
+        }
+        match serde_json::from_str::<serde_json::Value>(value) {
+            Ok(serde_json::Value::Object(object)) => {
+                let mut keys = BTreeMap::new();
+                flatten_json(&mut keys, "", &object);
+                FieldType::Json(keys)
+            }
+            _ => FieldType::Text,
+        }
+    }
+
+    fn of_json(value: &serde_json::Value) -> FieldType {
+        match value {
+            serde_json::Value::Null => FieldType::Unknown,
+            serde_json::Value::Number(number) if number.is_i64() || number.is_u64() => {
+                FieldType::Long
+            }
+            serde_json::Value::Number(_) => FieldType::Double,
+            _ => FieldType::Text,
+        }
+    }
+
+    fn merge(self, other: FieldType) -> FieldType {
+        match (self, other) {
+            (FieldType::Unknown, other) | (other, FieldType::Unknown) => other,
+            (FieldType::Long, FieldType::Long) => FieldType::Long,
+            (FieldType::Long | FieldType::Double, FieldType::Long | FieldType::Double) => {
+                FieldType::Double
+            }
+            (FieldType::Json(mut keys), FieldType::Json(other)) => {
+                for (key, field_type) in other {
+                    merge_field(&mut keys, &key, field_type);
+                }
+                FieldType::Json(keys)
+            }
+            _ => FieldType::Text,
+        }
+    }
+
+    fn field_index(self) -> FieldIndex {
+        match self {
+            FieldType::Long => FieldIndex::Long(IndexKeyLong {
+                alias: None,
+                doc_value: true,
+                ..Default::default()
+            }),
+            FieldType::Double => FieldIndex::Double(IndexKeyDouble {
+                alias: None,
+                doc_value: true,
+                ..Default::default()
+            }),
+            FieldType::Json(keys) => FieldIndex::Json(IndexKeyJson {
+                case_sensitive: false,
+                alias: None,
+                chn: false,
+                token: TokenPreset::Default.tokens(),
+                doc_value: true,
+                max_depth: -1,
+                index_all: true,
+                json_keys: Some(
+                    keys.into_iter()
+                        .map(|(key, field_type)| (key, field_type.json_key()))
+                        .collect(),
+                ),
+                ..Default::default()
+            }),
+            FieldType::Unknown | FieldType::Text => FieldIndex::Text(text_key()),
+        }
+    }
+
+    fn json_key(self) -> IndexJsonKey {
+        match self {
+            FieldType::Long => IndexJsonKey::Long(IndexKeyLong {
+                alias: None,
+                doc_value: true,
+                ..Default::default()
+            }),
+            FieldType::Double => IndexJsonKey::Double(IndexKeyDouble {
+                alias: None,
+                doc_value: true,
+                ..Default::default()
+            }),
+            // tokenized like the JSON field
+            _ => IndexJsonKey::JsonText(IndexKeyJsonText {
+                alias: None,
+                doc_value: true,
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+fn text_key() -> IndexKeyText {
+    IndexKeyText {
+        case_sensitive: false,
+        alias: None,
+        chn: false,
+        token: TokenPreset::Default.tokens(),
+        doc_value: true,
+        ..Default::default()
+    }
+}
+
+fn merge_field(fields: &mut BTreeMap<String, FieldType>, name: &str, field_type: FieldType) {
+    let merged = match fields.remove(name) {
+        Some(current) => current.merge(field_type),
+        None => field_type,
+    };
+    fields.insert(name.to_string(), merged);
+}
+
+/// Collect the leaf fields of a JSON object, nested fields named by their dotted path.
+fn flatten_json(
+    keys: &mut BTreeMap<String, FieldType>,
+    prefix: &str,
+    object: &serde_json::Map<String, serde_json::Value>,
+) {
+    for (key, value) in object {
+        let path = format!("{prefix}{key}");
+        match value {
+            serde_json::Value::Object(nested) => flatten_json(keys, &format!("{path}."), nested),
+            value => merge_field(keys, &path, FieldType::of_json(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aliyun_log_sdk_protobuf::Log;
+
+    #[test]
+    fn test_field_type() {
+        assert_eq!(FieldType::of("42"), FieldType::Long);
+        assert_eq!(FieldType::of("-4.2e3"), FieldType::Double);
+        assert_eq!(FieldType::of("NaN"), FieldType::Text);
+        assert_eq!(FieldType::of("[1, 2]"), FieldType::Text);
+        assert_eq!(FieldType::of(" "), FieldType::Unknown);
+        assert_eq!(FieldType::Long.merge(FieldType::Double), FieldType::Double);
+        assert_eq!(FieldType::Long.merge(FieldType::Unknown), FieldType::Long);
+        assert_eq!(FieldType::Double.merge(FieldType::Text), FieldType::Text);
+        assert_eq!(
+            FieldType::of(r#"{"a": {"b": 1, "c": null}, "d": "x"}"#),
+            FieldType::Json(BTreeMap::from([
+                ("a.b".to_string(), FieldType::Long),
+                ("a.c".to_string(), FieldType::Unknown),
+                ("d".to_string(), FieldType::Text),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_infer() {
+        let mut log_group = LogGroup::new();
+        let mut log = Log::from_unixtime(1690254376);
+        log.add_content_kv("latency", "12")
+            .add_content_kv("ratio", "0.5")
+            .add_content_kv("body", r#"{"status": 200, "cost": 1}"#)
+            .add_content_kv("message", "hello world");
+        log_group.add_log(log);
+        let mut log = Log::from_unixtime(1690254377);
+        log.add_content_kv("latency", "")
+            .add_content_kv("ratio", "1")
+            .add_content_kv("body", r#"{"status": 404, "cost": 1.5, "path": "/"}"#)
+            .add_content_kv("message", "42");
+        log_group.add_log(log);
+
+        let index = serde_json::to_value(Index::infer([&log_group])).unwrap();
+        let keys = &index["keys"];
+        assert_eq!(keys["latency"]["type"], "long");
+        assert_eq!(keys["ratio"]["type"], "double");
+        assert_eq!(keys["message"]["type"], "text");
+        assert_eq!(keys["body"]["type"], "json");
+        assert_eq!(keys["body"]["json_keys"]["status"]["type"], "long");
+        assert_eq!(keys["body"]["json_keys"]["cost"]["type"], "double");
+        assert_eq!(keys["body"]["json_keys"]["path"]["type"], "text");
+        assert_eq!(
+            index["line"]["token"].as_array().unwrap().len(),
+            TokenPreset::Default.as_slice().len()
+        );
+    }
+}
@@ -0,0 +1,50 @@
+/// Standard lists of delimiter tokens for the full-text index and text field indexes.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_log_rust_sdk::{FullTextIndex, TokenPreset};
+///
+/// let full_text_index = FullTextIndex {
+///     token: TokenPreset::Default.tokens(),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TokenPreset {
+    /// The tokens used by default in the console: whitespace and common punctuation
+    Default,
+    /// Whitespace only, to search whole words including punctuation
+    Whitespace,
+    /// Tokens splitting URLs into scheme, host, path segments and query parameters
+    Url,
+    /// Tokens splitting `key=value` pairs separated by `&`, `;` or `,`
+    KeyValue,
+    /// Tokens splitting file paths into directories and file names
+    Path,
+}
+
+impl TokenPreset {
+    /// The delimiter tokens of the preset.
+    pub fn as_slice(&self) -> &'static [&'static str] {
+        match self {
+            TokenPreset::Default => &[
+                ",", " ", "'", "\"", ";", "=", "(", ")", "[", "]", "{", "}", "?", "@", "&", "<",
+                ">", "/", ":", "\n", "\t", "\r",
+            ],
+            TokenPreset::Whitespace => &[" ", "\n", "\t", "\r"],
+            TokenPreset::Url => &["/", ":", "?", "&", "=", "#", " ", "\n", "\t", "\r"],
+            TokenPreset::KeyValue => &["=", "&", ";", ",", " ", "\n", "\t", "\r"],
+            TokenPreset::Path => &["/", "\\", ".", " ", "\n", "\t", "\r"],
+        }
+    }
+
+    /// The delimiter tokens of the preset, as used by the index configuration.
+    pub fn tokens(&self) -> Vec<String> {
+        self.as_slice()
+            .iter()
+            .map(|token| token.to_string())
+            .collect()
+    }
+}
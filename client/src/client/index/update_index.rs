@@ -45,6 +45,8 @@ pub struct UpdateIndexRequestBuilder {
     index: Index,
 }
 
+request_options!(UpdateIndexRequestBuilder);
+
 impl UpdateIndexRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<()> {
@@ -55,6 +57,7 @@ impl UpdateIndexRequestBuilder {
     }
 
     fn build(self) -> BuildResult<UpdateIndexRequest> {
+        self.index.validate_request()?;
         Ok((
             self.handle,
             UpdateIndexRequest {
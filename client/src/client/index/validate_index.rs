@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use super::*;
+use crate::RequestErrorKind;
+
+/// Range of `max_text_len` accepted by the server.
+const MAX_TEXT_LEN_RANGE: std::ops::RangeInclusive<i32> = 64..=16384;
+
+impl Index {
+    /// Check the configuration for errors the server would reject it for.
+    ///
+    /// `create_index` and `update_index` check the index before sending it, so that errors name the
+    /// setting at fault instead of being a `ParameterInvalid` error from the server. The checks are:
+    ///
+    /// * `max_text_len` is between 64 and 16384
+    /// * Delimiter tokens are single ASCII characters
+    /// * `max_depth` of JSON fields is -1, for no limit, or positive
+    /// * Field names are not empty, and aliases are unique and differ from the field names
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aliyun_log_rust_sdk::{FullTextIndex, Index, token_list};
+    ///
+    /// let index = Index::builder()
+    ///     .line(FullTextIndex {
+    ///         token: token_list![",", "->"],
+    ///         ..Default::default()
+    ///     })
+    ///     .build();
+    /// assert!(index.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        Ok(self.validate_request()?)
+    }
+
+    pub(super) fn validate_request(&self) -> std::result::Result<(), RequestError> {
+        self.check()
+            .map_err(|message| RequestError::from(RequestErrorKind::InvalidParameter(message)))
+    }
+
+    fn check(&self) -> std::result::Result<(), String> {
+        if let Some(max_text_len) = self.max_text_len {
+            if !MAX_TEXT_LEN_RANGE.contains(&max_text_len) {
+                return Err(format!(
+                    "max_text_len: {max_text_len} is not between {} and {}",
+                    MAX_TEXT_LEN_RANGE.start(),
+                    MAX_TEXT_LEN_RANGE.end()
+                ));
+            }
+        }
+        if let Some(line) = &self.line {
+            check_tokens("line", &line.token)?;
+        }
+        let Some(keys) = &self.keys else {
+            return Ok(());
+        };
+        // field names and aliases share one namespace in queries
+        let mut aliases = HashMap::new();
+        for (name, field) in keys {
+            if name.is_empty() {
+                return Err("keys: field name is empty".to_string());
+            }
+            let path = format!("keys.{name}");
+            let alias = match field {
+                FieldIndex::Text(text) => {
+                    check_tokens(&path, &text.token)?;
+                    &text.alias
+                }
+                FieldIndex::Long(long) => &long.alias,
+                FieldIndex::Double(double) => &double.alias,
+                FieldIndex::Json(json) => {
+                    check_json(&path, json, &mut aliases)?;
+                    &json.alias
+                }
+            };
+            check_alias(&path, alias, &mut aliases)?;
+        }
+        for (alias, path) in &aliases {
+            if keys.contains_key(*alias) && path != &format!("keys.{alias}") {
+                return Err(format!(
+                    "{path}.alias: `{alias}` is the name of another field"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl IndexBuilder {
+    /// Build the Index configuration, checking it with [`Index::validate`].
+    pub fn try_build(self) -> Result<Index> {
+        let index = self.build();
+        index.validate()?;
+        Ok(index)
+    }
+}
+
+fn check_tokens(path: &str, tokens: &[String]) -> std::result::Result<(), String> {
+    for token in tokens {
+        let mut chars = token.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii() => {}
+            _ => {
+                return Err(format!(
+                    "{path}.token: {token:?} is not a single ASCII character"
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_alias<'a>(
+    path: &str,
+    alias: &'a Option<String>,
+    aliases: &mut HashMap<&'a str, String>,
+) -> std::result::Result<(), String> {
+    let Some(alias) = alias else {
+        return Ok(());
+    };
+    if alias.is_empty() {
+        return Err(format!("{path}.alias: alias is empty"));
+    }
+    if let Some(other) = aliases.insert(alias, path.to_string()) {
+        return Err(format!(
+            "{path}.alias: `{alias}` is also the alias of {other}"
+        ));
+    }
+    Ok(())
+}
+
+fn check_json<'a>(
+    path: &str,
+    json: &'a IndexKeyJson,
+    aliases: &mut HashMap<&'a str, String>,
+) -> std::result::Result<(), String> {
+    check_tokens(path, &json.token)?;
+    if json.max_depth != -1 && json.max_depth < 1 {
+        return Err(format!(
+            "{path}.max_depth: {} is neither -1 nor positive",
+            json.max_depth
+        ));
+    }
+    for (name, key) in json.json_keys.iter().flatten() {
+        if name.is_empty() {
+            return Err(format!("{path}.json_keys: field name is empty"));
+        }
+        let path = format!("{path}.json_keys.{name}");
+        let alias = match key {
+            IndexJsonKey::Text(text) => {
+                check_tokens(&path, &text.token)?;
+                &text.alias
+            }
+            IndexJsonKey::JsonText(text) => &text.alias,
+            IndexJsonKey::Long(long) => &long.alias,
+            IndexJsonKey::Double(double) => &double.alias,
+            IndexJsonKey::Json(json) => {
+                check_json(&path, json, aliases)?;
+                &json.alias
+            }
+        };
+        check_alias(&path, alias, aliases)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn check(index: serde_json::Value) -> std::result::Result<(), String> {
+        serde_json::from_value::<Index>(index).unwrap().check()
+    }
+
+    #[test]
+    fn test_validate() {
+        let text = json!({"type": "text", "caseSensitive": false, "chn": false, "token": [",", "\n"], "doc_value": true});
+        assert_eq!(
+            check(json!({"max_text_len": 2048, "line": text, "keys": {"a": text}})),
+            Ok(())
+        );
+        assert!(check(json!({"max_text_len": 20000})).is_err());
+        assert_eq!(
+            check(
+                json!({"keys": {"a": {"type": "text", "caseSensitive": false, "chn": false, "token": ["ab"], "doc_value": true}}})
+            ),
+            Err(r#"keys.a.token: "ab" is not a single ASCII character"#.to_string())
+        );
+        assert_eq!(
+            check(json!({"keys": {"a": {
+                "type": "json", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true,
+                "max_depth": 0, "index_all": true,
+            }}})),
+            Err("keys.a.max_depth: 0 is neither -1 nor positive".to_string())
+        );
+        assert_eq!(
+            check(json!({"keys": {
+                "a": {"type": "long", "doc_value": true, "alias": "b"},
+                "b": {"type": "long", "doc_value": true},
+            }})),
+            Err("keys.a.alias: `b` is the name of another field".to_string())
+        );
+        assert!(check(json!({"keys": {
+            "a": {"type": "long", "doc_value": true, "alias": "x"},
+            "b": {
+                "type": "json", "caseSensitive": false, "chn": false, "token": [","], "doc_value": true,
+                "max_depth": -1, "index_all": true,
+                "json_keys": {"c": {"type": "text", "doc_value": true, "alias": "x"}},
+            },
+        }}))
+        .is_err());
+    }
+}
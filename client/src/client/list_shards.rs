@@ -51,6 +51,8 @@ pub struct ListShardsRequestBuilder {
     path: String,
 }
 
+request_options!(ListShardsRequestBuilder);
+
 impl ListShardsRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<ListShardsResponse> {
@@ -14,3 +14,6 @@ pub use get_logstore::*;
 
 mod list_logstores;
 pub use list_logstores::*;
+
+mod ensure_logstore;
+pub use ensure_logstore::*;
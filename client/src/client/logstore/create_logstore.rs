@@ -70,6 +70,8 @@ pub struct CreateLogstoreRequestBuilder {
     processor_id: Option<String>,
 }
 
+request_options!(CreateLogstoreRequestBuilder);
+
 impl CreateLogstoreRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<()> {
@@ -160,6 +162,8 @@ impl CreateLogstoreRequestBuilder {
     /// * `telemetry_type` - Type of telemetry data. Valid values:
     ///   - `None`: Log data (default)
     ///   - `Metrics`: Time series data
+    ///
+    /// Use [`create_metricstore`](crate::Client::create_metricstore) to create a metricstore instead.
     pub fn telemetry_type(mut self, telemetry_type: impl Into<String>) -> Self {
         self.telemetry_type = Some(telemetry_type.into());
         self
@@ -238,7 +242,7 @@ impl CreateLogstoreRequestBuilder {
 }
 
 /// Encryption configuration for logstore
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncryptConf {
     /// Whether to enable encryption
     pub enable: bool,
@@ -276,7 +280,7 @@ impl EncryptConf {
 }
 
 /// User-provided CMK configuration for BYOK
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncryptUserCmkConf {
     /// CMK (Customer Master Key) ID
     pub cmk_key_id: String,
@@ -40,6 +40,8 @@ pub struct DeleteLogstoreRequestBuilder {
     path: String,
 }
 
+request_options!(DeleteLogstoreRequestBuilder);
+
 impl DeleteLogstoreRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<()> {
@@ -0,0 +1,332 @@
+use super::*;
+use crate::ErrorCode;
+
+impl crate::client::Client {
+    /// Create a logstore if it does not exist, or update it to the desired settings.
+    ///
+    /// The logstore is created with all the settings set, so `shard_count` and `ttl` are required
+    /// when it does not exist. Otherwise the settings which can be updated are compared and updated
+    /// when they differ, and the outcome lists the fields updated.
+    /// `shard_count` and `telemetry_type` are only used if the logstore is created.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `logstore_name` - The name of the logstore
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let outcome = client.ensure_logstore("my-project", "my-logstore")
+    ///     .shard_count(2)
+    ///     .ttl(30)
+    ///     .auto_split(true)
+    ///     .max_split_shard(64)
+    ///     .send()
+    ///     .await?;
+    /// println!("changed fields: {:?}", outcome.changed_fields());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ensure_logstore(
+        &self,
+        project: impl AsRef<str>,
+        logstore_name: impl AsRef<str>,
+    ) -> EnsureLogstoreRequestBuilder {
+        EnsureLogstoreRequestBuilder {
+            project: project.as_ref().to_string(),
+            handle: self.handle.clone(),
+            logstore_name: logstore_name.as_ref().to_string(),
+            shard_count: None,
+            ttl: None,
+            encrypt_conf: None,
+            auto_split: None,
+            enable_tracking: None,
+            max_split_shard: None,
+            append_meta: None,
+            telemetry_type: None,
+            hot_ttl: None,
+            mode: None,
+            infrequent_access_ttl: None,
+            processor_id: None,
+        }
+    }
+}
+
+pub struct EnsureLogstoreRequestBuilder {
+    project: String,
+    handle: HandleRef,
+    logstore_name: String,
+    shard_count: Option<i32>,
+    ttl: Option<i32>,
+    encrypt_conf: Option<EncryptConf>,
+    auto_split: Option<bool>,
+    enable_tracking: Option<bool>,
+    max_split_shard: Option<i32>,
+    append_meta: Option<bool>,
+    telemetry_type: Option<String>,
+    hot_ttl: Option<i32>,
+    mode: Option<String>,
+    infrequent_access_ttl: Option<i32>,
+    processor_id: Option<String>,
+}
+
+request_options!(EnsureLogstoreRequestBuilder);
+
+impl EnsureLogstoreRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> BoxFuture<Result<EnsureOutcome>> {
+        Box::pin(async move {
+            let client = crate::Client {
+                handle: self.handle.clone(),
+            };
+            let get = || {
+                client
+                    .get_logstore(&self.project, &self.logstore_name)
+                    .send()
+            };
+            let current = match get().await {
+                Ok(resp) => resp.take_body(),
+                Err(err) if err.code() == Some(ErrorCode::LogStoreNotExist) => {
+                    match self.create(&client).send().await {
+                        Ok(_) => return Ok(EnsureOutcome::Created),
+                        // created concurrently, compare with what was created instead
+                        Err(err) if err.code() == Some(ErrorCode::LogStoreAlreadyExist) => {
+                            get().await?.take_body()
+                        }
+                        Err(err) => return Err(err),
+                    }
+                }
+                Err(err) => return Err(err),
+            };
+
+            let mut changes = FieldChanges::default();
+            let mut update = client.update_logstore(&self.project, &self.logstore_name);
+            if let Some(days) = changes.check("ttl", self.ttl, Some(*current.ttl())) {
+                update = update.ttl(days);
+            }
+            if let Some(encrypt_conf) = changes.check(
+                "encrypt_conf",
+                self.encrypt_conf.as_ref(),
+                current.encrypt_conf().as_ref(),
+            ) {
+                update = update.encrypt_conf(encrypt_conf.clone());
+            }
+            if let Some(enabled) =
+                changes.check("auto_split", self.auto_split, Some(*current.auto_split()))
+            {
+                update = update.auto_split(enabled);
+            }
+            if let Some(enabled) = changes.check(
+                "enable_tracking",
+                self.enable_tracking,
+                Some(*current.enable_tracking()),
+            ) {
+                update = update.enable_tracking(enabled);
+            }
+            if let Some(max) = changes.check(
+                "max_split_shard",
+                self.max_split_shard,
+                *current.max_split_shard(),
+            ) {
+                update = update.max_split_shard(max);
+            }
+            if let Some(enabled) = changes.check(
+                "append_meta",
+                self.append_meta,
+                Some(*current.append_meta()),
+            ) {
+                update = update.append_meta(enabled);
+            }
+            if let Some(days) = changes.check("hot_ttl", self.hot_ttl, *current.hot_ttl()) {
+                update = update.hot_ttl(days);
+            }
+            if let Some(mode) =
+                changes.check("mode", self.mode.as_deref(), Some(current.mode().as_str()))
+            {
+                update = update.mode(mode);
+            }
+            if let Some(days) = changes.check(
+                "infrequent_access_ttl",
+                self.infrequent_access_ttl,
+                *current.infrequent_access_ttl(),
+            ) {
+                update = update.infrequent_access_ttl(days);
+            }
+            if let Some(processor_id) = changes.check(
+                "processor_id",
+                self.processor_id.as_deref(),
+                current.processor_id().as_deref(),
+            ) {
+                update = update.processor_id(processor_id);
+            }
+            if !changes.is_empty() {
+                update.send().await?;
+            }
+            Ok(changes.into_outcome())
+        })
+    }
+
+    fn create(&self, client: &crate::Client) -> CreateLogstoreRequestBuilder {
+        let mut create = client.create_logstore(&self.project, &self.logstore_name);
+        if let Some(count) = self.shard_count {
+            create = create.shard_count(count);
+        }
+        if let Some(days) = self.ttl {
+            create = create.ttl(days);
+        }
+        if let Some(encrypt_conf) = &self.encrypt_conf {
+            create = create.encrypt_conf(encrypt_conf.clone());
+        }
+        if let Some(enabled) = self.auto_split {
+            create = create.auto_split(enabled);
+        }
+        if let Some(enabled) = self.enable_tracking {
+            create = create.enable_tracking(enabled);
+        }
+        if let Some(max) = self.max_split_shard {
+            create = create.max_split_shard(max);
+        }
+        if let Some(enabled) = self.append_meta {
+            create = create.append_meta(enabled);
+        }
+        if let Some(telemetry_type) = &self.telemetry_type {
+            create = create.telemetry_type(telemetry_type);
+        }
+        if let Some(days) = self.hot_ttl {
+            create = create.hot_ttl(days);
+        }
+        if let Some(mode) = &self.mode {
+            create = create.mode(mode);
+        }
+        if let Some(days) = self.infrequent_access_ttl {
+            create = create.infrequent_access_ttl(days);
+        }
+        if let Some(processor_id) = &self.processor_id {
+            create = create.processor_id(processor_id);
+        }
+        create
+    }
+
+    /// Set the number of shards, required if the logstore does not exist,
+    /// and only used if the logstore is created.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of shards, minimum 1, maximum 256
+    pub fn shard_count(mut self, count: i32) -> Self {
+        self.shard_count = Some(count);
+        self
+    }
+
+    /// Set the data retention time in days, required if the logstore does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `days` - Retention time in days, range: 1-3650. Use 3650 for permanent storage
+    pub fn ttl(mut self, days: i32) -> Self {
+        self.ttl = Some(days);
+        self
+    }
+
+    /// Set encryption configuration (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `encrypt_conf` - Encryption configuration
+    pub fn encrypt_conf(mut self, encrypt_conf: EncryptConf) -> Self {
+        self.encrypt_conf = Some(encrypt_conf);
+        self
+    }
+
+    /// Set whether to automatically split shards (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Enable automatic shard splitting
+    pub fn auto_split(mut self, enabled: bool) -> Self {
+        self.auto_split = Some(enabled);
+        self
+    }
+
+    /// Set whether to enable WebTracking (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Enable WebTracking
+    pub fn enable_tracking(mut self, enabled: bool) -> Self {
+        self.enable_tracking = Some(enabled);
+        self
+    }
+
+    /// Set the maximum number of shards for auto-splitting (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Maximum number of shards, range: 1-256
+    pub fn max_split_shard(mut self, max: i32) -> Self {
+        self.max_split_shard = Some(max);
+        self
+    }
+
+    /// Set whether to append metadata such as the client IP and receive time (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Enable metadata appending
+    pub fn append_meta(mut self, enabled: bool) -> Self {
+        self.append_meta = Some(enabled);
+        self
+    }
+
+    /// Set the telemetry data type, only used if the logstore is created.
+    ///
+    /// # Arguments
+    ///
+    /// * `telemetry_type` - Type of observable data, `None` for logs or `Metrics` for metrics
+    pub fn telemetry_type(mut self, telemetry_type: impl Into<String>) -> Self {
+        self.telemetry_type = Some(telemetry_type.into());
+        self
+    }
+
+    /// Set the hot storage TTL in days (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `days` - Hot storage TTL in days, minimum 7, must be less than `ttl`
+    pub fn hot_ttl(mut self, days: i32) -> Self {
+        self.hot_ttl = Some(days);
+        self
+    }
+
+    /// Set the logstore mode (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Logstore mode, `standard` or `query`
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+
+    /// Set the infrequent access storage TTL in days (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `days` - Infrequent access TTL in days, minimum 30
+    pub fn infrequent_access_ttl(mut self, days: i32) -> Self {
+        self.infrequent_access_ttl = Some(days);
+        self
+    }
+
+    /// Set the ingest processor ID (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `processor_id` - ID of the ingest processor
+    pub fn processor_id(mut self, processor_id: impl Into<String>) -> Self {
+        self.processor_id = Some(processor_id.into());
+        self
+    }
+}
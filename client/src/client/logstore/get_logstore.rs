@@ -44,6 +44,8 @@ pub struct GetLogstoreRequestBuilder {
     path: String,
 }
 
+request_options!(GetLogstoreRequestBuilder);
+
 impl GetLogstoreRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<GetLogstoreResponse> {
@@ -1,5 +1,6 @@
 use super::*;
 use crate::ResponseResult;
+use futures_util::TryStreamExt;
 use getset::Getters;
 use serde::Deserialize;
 
@@ -51,10 +52,12 @@ impl crate::client::Client {
             logstore_name: None,
             telemetry_type: None,
             mode: None,
+            concurrency: None,
         }
     }
 }
 
+#[derive(Clone)]
 pub struct ListLogstoresRequestBuilder {
     project: String,
     handle: HandleRef,
@@ -63,8 +66,11 @@ pub struct ListLogstoresRequestBuilder {
     logstore_name: Option<String>,
     telemetry_type: Option<String>,
     mode: Option<String>,
+    concurrency: Option<usize>,
 }
 
+request_options!(ListLogstoresRequestBuilder);
+
 impl ListLogstoresRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<ListLogstoresResponse> {
@@ -108,6 +114,69 @@ impl ListLogstoresRequestBuilder {
         self
     }
 
+    /// Set how many pages [`into_stream`](Self::into_stream) and [`collect_all`](Self::collect_all)
+    /// fetch at a time, default is 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `concurrency` - Maximum number of pages fetched concurrently
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = Some(concurrency);
+        self
+    }
+
+    /// Stream the names of all the logstores from `offset`,
+    /// fetching them in pages of `size` logstores, at most 500.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut logstores = client.list_logstores("my-project", 0, 100).into_stream();
+    /// while let Some(logstore) = logstores.try_next().await? {
+    ///     println!("Logstore: {}", logstore);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> BoxStream<Result<String>> {
+        let (offset, size) = (self.offset, self.size);
+        let concurrency = self.concurrency.unwrap_or(1);
+        paginate(offset, size, concurrency, move |offset, size| {
+            let mut page = self.clone();
+            page.offset = offset;
+            page.size = size;
+            Box::pin(async move {
+                let body = page.send().await?.take_body();
+                Ok(Page {
+                    items: body.logstores,
+                    total: body.total,
+                })
+            })
+        })
+    }
+
+    /// Collect the names of all the logstores from `offset`,
+    /// fetching them in pages of `size` logstores, at most 500.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let logstores = client.list_logstores("my-project", 0, 500)
+    ///     .collect_all()
+    ///     .await?;
+    /// println!("Total logstores: {}", logstores.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use = "the result future must be awaited"]
+    pub fn collect_all(self) -> BoxFuture<Result<Vec<String>>> {
+        Box::pin(self.into_stream().try_collect())
+    }
+
     fn build(self) -> BuildResult<ListLogstoresRequest> {
         Ok((
             self.handle,
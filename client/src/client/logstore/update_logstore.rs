@@ -64,6 +64,8 @@ pub struct UpdateLogstoreRequestBuilder {
     processor_id: Option<String>,
 }
 
+request_options!(UpdateLogstoreRequestBuilder);
+
 impl UpdateLogstoreRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<()> {
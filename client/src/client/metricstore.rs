@@ -0,0 +1,13 @@
+use super::*;
+
+mod create_metricstore;
+pub use create_metricstore::*;
+
+mod delete_metricstore;
+pub use delete_metricstore::*;
+
+mod get_metricstore;
+pub use get_metricstore::*;
+
+mod list_metricstores;
+pub use list_metricstores::*;
@@ -0,0 +1,222 @@
+use super::*;
+use crate::RequestErrorKind;
+use serde::Serialize;
+
+impl crate::client::Client {
+    /// Create a new metricstore in a project.
+    ///
+    /// A metricstore stores time series data, which can be written with
+    /// [`put_metrics`](crate::Client::put_metrics) and queried with PromQL.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `metricstore_name` - The name of the metricstore to create, following the logstore naming rules
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// client.create_metricstore("my-project", "my-metricstore")
+    ///     .shard_count(2)
+    ///     .ttl(30)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_metricstore(
+        &self,
+        project: impl AsRef<str>,
+        metricstore_name: impl AsRef<str>,
+    ) -> CreateMetricstoreRequestBuilder {
+        CreateMetricstoreRequestBuilder {
+            project: project.as_ref().to_string(),
+            handle: self.handle.clone(),
+            metricstore_name: metricstore_name.as_ref().to_string(),
+            shard_count: None,
+            ttl: None,
+            auto_split: None,
+            max_split_shard: None,
+            hot_ttl: None,
+            infrequent_access_ttl: None,
+            mode: None,
+        }
+    }
+}
+
+pub struct CreateMetricstoreRequestBuilder {
+    project: String,
+    handle: HandleRef,
+    metricstore_name: String,
+    shard_count: Option<i32>,
+    ttl: Option<i32>,
+    auto_split: Option<bool>,
+    max_split_shard: Option<i32>,
+    hot_ttl: Option<i32>,
+    infrequent_access_ttl: Option<i32>,
+    mode: Option<String>,
+}
+
+request_options!(CreateMetricstoreRequestBuilder);
+
+impl CreateMetricstoreRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Set the number of shards (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of shards, minimum 1, maximum 256
+    pub fn shard_count(mut self, count: i32) -> Self {
+        self.shard_count = Some(count);
+        self
+    }
+
+    /// Set the data retention time in days (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `days` - Retention time in days, range: 1-3650. Use 3650 for permanent storage
+    pub fn ttl(mut self, days: i32) -> Self {
+        self.ttl = Some(days);
+        self
+    }
+
+    /// Set whether to automatically split shards (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Enable automatic shard splitting
+    pub fn auto_split(mut self, enabled: bool) -> Self {
+        self.auto_split = Some(enabled);
+        self
+    }
+
+    /// Set the maximum number of shards when auto-splitting (optional).
+    ///
+    /// Required when auto_split is true. Range: 1-256.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Maximum number of shards
+    pub fn max_split_shard(mut self, max: i32) -> Self {
+        self.max_split_shard = Some(max);
+        self
+    }
+
+    /// Set hot storage TTL in days (optional).
+    ///
+    /// Data older than this will be moved to infrequent access storage.
+    ///
+    /// # Arguments
+    ///
+    /// * `days` - Hot storage TTL in days
+    pub fn hot_ttl(mut self, days: i32) -> Self {
+        self.hot_ttl = Some(days);
+        self
+    }
+
+    /// Set infrequent access TTL in days (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `days` - Infrequent access TTL in days
+    pub fn infrequent_access_ttl(mut self, days: i32) -> Self {
+        self.infrequent_access_ttl = Some(days);
+        self
+    }
+
+    /// Set the metricstore mode (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Metricstore mode. Valid values:
+    ///   - `standard`: Standard mode
+    ///   - `query`: Query mode
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+
+    fn build(self) -> BuildResult<CreateMetricstoreRequest> {
+        check_required!(("shard_count", self.shard_count), ("ttl", self.ttl));
+
+        Ok((
+            self.handle,
+            CreateMetricstoreRequest {
+                project: self.project,
+                name: self.metricstore_name,
+                shard_count: self.shard_count.unwrap(),
+                ttl: self.ttl.unwrap(),
+                auto_split: self.auto_split,
+                max_split_shard: self.max_split_shard,
+                hot_ttl: self.hot_ttl,
+                infrequent_access_ttl: self.infrequent_access_ttl,
+                mode: self.mode,
+                metric_type: METRIC_TYPE_PROMETHEUS,
+            },
+        ))
+    }
+}
+
+pub(crate) const METRIC_TYPE_PROMETHEUS: &str = "prometheus";
+
+#[derive(Serialize)]
+struct CreateMetricstoreRequest {
+    #[serde(skip_serializing)]
+    project: String,
+
+    name: String,
+
+    #[serde(rename = "shardCount")]
+    shard_count: i32,
+
+    ttl: i32,
+
+    #[serde(rename = "autoSplit", skip_serializing_if = "Option::is_none")]
+    auto_split: Option<bool>,
+
+    #[serde(rename = "maxSplitShard", skip_serializing_if = "Option::is_none")]
+    max_split_shard: Option<i32>,
+
+    #[serde(rename = "hot_ttl", skip_serializing_if = "Option::is_none")]
+    hot_ttl: Option<i32>,
+
+    #[serde(
+        rename = "infrequentAccessTTL",
+        skip_serializing_if = "Option::is_none"
+    )]
+    infrequent_access_ttl: Option<i32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<String>,
+
+    #[serde(rename = "metricType")]
+    metric_type: &'static str,
+}
+
+impl Request for CreateMetricstoreRequest {
+    const HTTP_METHOD: http::Method = http::Method::POST;
+    const CONTENT_TYPE: Option<http::HeaderValue> = Some(LOG_JSON);
+    type ResponseBody = ();
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        "/metricstores"
+    }
+
+    fn body(&self) -> crate::Result<Option<bytes::Bytes>, RequestError> {
+        let json = serde_json::to_string(&self).map_err(RequestErrorKind::JsonEncode)?;
+        Ok(Some(bytes::Bytes::from(json)))
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+impl crate::client::Client {
+    /// Delete a metricstore.
+    ///
+    /// This method deletes an existing metricstore and all its associated data.
+    /// This operation is irreversible, so use it with caution.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project containing the metricstore
+    /// * `metricstore_name` - The name of the metricstore to delete
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// client.delete_metricstore("my-project", "my-metricstore")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_metricstore(
+        &self,
+        project: impl AsRef<str>,
+        metricstore_name: impl AsRef<str>,
+    ) -> DeleteMetricstoreRequestBuilder {
+        DeleteMetricstoreRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/metricstores/{}", metricstore_name.as_ref()),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+pub struct DeleteMetricstoreRequestBuilder {
+    handle: HandleRef,
+    project: String,
+    path: String,
+}
+
+request_options!(DeleteMetricstoreRequestBuilder);
+
+impl DeleteMetricstoreRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<DeleteMetricstoreRequest> {
+        Ok((
+            self.handle,
+            DeleteMetricstoreRequest {
+                project: self.project,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+struct DeleteMetricstoreRequest {
+    project: String,
+    path: String,
+}
+
+impl Request for DeleteMetricstoreRequest {
+    type ResponseBody = ();
+    const HTTP_METHOD: http::Method = http::Method::DELETE;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+}
@@ -0,0 +1,138 @@
+use super::*;
+use crate::ResponseResult;
+use getset::Getters;
+use serde::Deserialize;
+
+impl crate::client::Client {
+    /// Get metricstore details.
+    ///
+    /// This method retrieves detailed information about a metricstore, including its
+    /// shard count and storage settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project containing the metricstore
+    /// * `metricstore_name` - The name of the metricstore to get
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let response = client.get_metricstore("my-project", "my-metricstore")
+    ///     .send()
+    ///     .await?;
+    /// println!("TTL: {} days", response.get_body().ttl());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_metricstore(
+        &self,
+        project: impl AsRef<str>,
+        metricstore_name: impl AsRef<str>,
+    ) -> GetMetricstoreRequestBuilder {
+        GetMetricstoreRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/metricstores/{}", metricstore_name.as_ref()),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+pub struct GetMetricstoreRequestBuilder {
+    handle: HandleRef,
+    project: String,
+    path: String,
+}
+
+request_options!(GetMetricstoreRequestBuilder);
+
+impl GetMetricstoreRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<GetMetricstoreResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<GetMetricstoreRequest> {
+        Ok((
+            self.handle,
+            GetMetricstoreRequest {
+                project: self.project,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+struct GetMetricstoreRequest {
+    project: String,
+    path: String,
+}
+
+impl Request for GetMetricstoreRequest {
+    type ResponseBody = GetMetricstoreResponse;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+/// Metricstore information
+#[derive(Debug, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct GetMetricstoreResponse {
+    /// Metricstore name
+    name: String,
+
+    /// Data retention time in days
+    ttl: i32,
+
+    /// Hot storage TTL in days
+    #[serde(rename = "hot_ttl", default)]
+    hot_ttl: Option<i32>,
+
+    /// Infrequent access TTL in days
+    #[serde(rename = "infrequentAccessTTL", default)]
+    infrequent_access_ttl: Option<i32>,
+
+    /// Number of shards
+    #[serde(rename = "shardCount")]
+    shard_count: i32,
+
+    /// Whether automatic shard splitting is enabled
+    #[serde(rename = "autoSplit", default)]
+    auto_split: bool,
+
+    /// Maximum number of shards when auto-splitting
+    #[serde(rename = "maxSplitShard", default)]
+    max_split_shard: Option<i32>,
+
+    /// Creation time (Unix timestamp)
+    #[serde(rename = "createTime", default)]
+    create_time: i64,
+
+    /// Last modification time (Unix timestamp)
+    #[serde(rename = "lastModifyTime", default)]
+    last_modify_time: i64,
+
+    /// Metricstore mode (standard or query)
+    #[serde(default)]
+    mode: String,
+
+    /// Metric type, e.g. `prometheus`
+    #[serde(rename = "metricType", default)]
+    metric_type: String,
+}
+
+impl FromHttpResponse for GetMetricstoreResponse {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
@@ -0,0 +1,158 @@
+use super::*;
+use crate::ResponseResult;
+use getset::Getters;
+use serde::Deserialize;
+
+impl crate::client::Client {
+    /// List metricstores with pagination and filtering.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `offset` - The offset for pagination (starting from 0)
+    /// * `size` - The number of metricstores to return (page size)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let resp = client.list_metricstores("my-project", 0, 10)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// println!("Total metricstores: {}", resp.get_body().total());
+    /// for metricstore in resp.get_body().metricstores() {
+    ///     println!("Metricstore: {}", metricstore);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_metricstores(
+        &self,
+        project: impl AsRef<str>,
+        offset: i32,
+        size: i32,
+    ) -> ListMetricstoresRequestBuilder {
+        ListMetricstoresRequestBuilder {
+            project: project.as_ref().to_string(),
+            handle: self.handle.clone(),
+            offset,
+            size,
+            metricstore_name: None,
+            mode: None,
+        }
+    }
+}
+
+pub struct ListMetricstoresRequestBuilder {
+    project: String,
+    handle: HandleRef,
+    offset: i32,
+    size: i32,
+    metricstore_name: Option<String>,
+    mode: Option<String>,
+}
+
+request_options!(ListMetricstoresRequestBuilder);
+
+impl ListMetricstoresRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<ListMetricstoresResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Filter metricstores by name (fuzzy search).
+    ///
+    /// # Arguments
+    ///
+    /// * `metricstore_name` - Metricstore name to search for (supports partial matching)
+    pub fn metricstore_name(mut self, metricstore_name: impl Into<String>) -> Self {
+        self.metricstore_name = Some(metricstore_name.into());
+        self
+    }
+
+    /// Filter metricstores by mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Metricstore mode, `standard` or `query`
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+
+    fn build(self) -> BuildResult<ListMetricstoresRequest> {
+        Ok((
+            self.handle,
+            ListMetricstoresRequest {
+                project: self.project,
+                offset: self.offset,
+                size: self.size,
+                metricstore_name: self.metricstore_name,
+                mode: self.mode,
+            },
+        ))
+    }
+}
+
+struct ListMetricstoresRequest {
+    project: String,
+    offset: i32,
+    size: i32,
+    metricstore_name: Option<String>,
+    mode: Option<String>,
+}
+
+impl Request for ListMetricstoresRequest {
+    type ResponseBody = ListMetricstoresResponse;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        "/metricstores"
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        let mut params = vec![
+            ("offset".to_string(), self.offset.to_string()),
+            ("size".to_string(), self.size.to_string()),
+        ];
+
+        if let Some(ref metricstore_name) = self.metricstore_name {
+            params.push(("name".to_string(), metricstore_name.clone()));
+        }
+
+        if let Some(ref mode) = self.mode {
+            params.push(("mode".to_string(), mode.clone()));
+        }
+
+        Some(params)
+    }
+}
+
+/// Response containing a list of metricstores
+#[derive(Debug, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct ListMetricstoresResponse {
+    /// Number of metricstores returned in this response
+    count: i32,
+
+    /// Total number of metricstores matching the filter criteria
+    total: i32,
+
+    /// List of metricstore names
+    #[serde(default)]
+    metricstores: Vec<String>,
+}
+
+impl FromHttpResponse for ListMetricstoresResponse {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
@@ -0,0 +1,157 @@
+use aliyun_log_sdk_protobuf::{Log, LogGroup};
+use opentelemetry::{KeyValue, Value};
+use opentelemetry_sdk::error::{OTelSdkError, OTelSdkResult};
+use opentelemetry_sdk::Resource;
+use std::time::SystemTime;
+
+use super::*;
+
+mod log_exporter;
+mod span_exporter;
+pub use log_exporter::*;
+pub use span_exporter::*;
+
+/// Writes the logs converted by the exporters to a logstore with batched `put_logs`.
+#[derive(Clone)]
+struct OtelLogWriter {
+    handle: HandleRef,
+    project: String,
+    logstore: String,
+    runtime: Option<tokio::runtime::Handle>,
+}
+
+impl OtelLogWriter {
+    fn new(client: &Client, project: impl AsRef<str>, logstore: impl AsRef<str>) -> Self {
+        Self {
+            handle: client.handle.clone(),
+            project: project.as_ref().to_string(),
+            logstore: logstore.as_ref().to_string(),
+            runtime: tokio::runtime::Handle::try_current().ok(),
+        }
+    }
+
+    /// Split the logs into log groups within the `put_logs` limits and write them one by one.
+    ///
+    /// The batch processors of the OpenTelemetry SDK drive exports on their own threads,
+    /// so the requests are spawned on the tokio runtime captured at construction.
+    async fn write(&self, logs: Vec<Log>) -> OTelSdkResult {
+        let runtime = match &self.runtime {
+            Some(runtime) => runtime.clone(),
+            None => tokio::runtime::Handle::try_current().map_err(|_| {
+                OTelSdkError::InternalFailure(
+                    "no tokio runtime to send logs on, create the exporter inside a tokio runtime"
+                        .to_string(),
+                )
+            })?,
+        };
+        for log_group in split_log_groups(logs) {
+            let client = Client {
+                handle: self.handle.clone(),
+            };
+            let request = client
+                .put_logs(&self.project, &self.logstore)
+                .log_group(log_group)
+                .send();
+            runtime
+                .spawn(request)
+                .await
+                .map_err(|err| OTelSdkError::InternalFailure(err.to_string()))?
+                .map_err(|err| OTelSdkError::InternalFailure(err.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Group logs so that each group stays under the `put_logs` limits on log count and size.
+fn split_log_groups(logs: Vec<Log>) -> Vec<LogGroup> {
+    let mut groups = Vec::new();
+    let mut group = LogGroup::new();
+    let (mut count, mut size) = (0, 0);
+    for log in logs {
+        let log_size: usize = log
+            .contents()
+            .iter()
+            .map(|c| c.key().len() + c.value().len())
+            .sum();
+        if count > 0 && (count >= MAX_LOGS_PER_GROUP || size + log_size > MAX_BYTES_PER_GROUP) {
+            groups.push(std::mem::replace(&mut group, LogGroup::new()));
+            (count, size) = (0, 0);
+        }
+        group.add_log(log);
+        count += 1;
+        size += log_size;
+    }
+    if count > 0 {
+        groups.push(group);
+    }
+    groups
+}
+
+/// Resource of the telemetry, pre-encoded for the `resource`, `service` and `host` fields.
+#[derive(Debug, Clone, Default)]
+struct OtelResource {
+    json: String,
+    service: String,
+    host: String,
+}
+
+impl From<&Resource> for OtelResource {
+    fn from(resource: &Resource) -> Self {
+        let attribute = |key: &'static str| {
+            resource
+                .get(&key.into())
+                .map(|v| v.to_string())
+                .unwrap_or_default()
+        };
+        Self {
+            json: attributes_to_json(resource.iter().map(|(k, v)| (k.as_str(), v))),
+            service: attribute("service.name"),
+            host: attribute("host.name"),
+        }
+    }
+}
+
+/// Encode attributes as a JSON object of string values.
+fn attributes_to_json<'a>(attributes: impl IntoIterator<Item = (&'a str, &'a Value)>) -> String {
+    let map: serde_json::Map<String, serde_json::Value> = attributes
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), serde_json::Value::String(v.to_string())))
+        .collect();
+    serde_json::Value::Object(map).to_string()
+}
+
+fn key_values_to_json(attributes: &[KeyValue]) -> String {
+    attributes_to_json(attributes.iter().map(|kv| (kv.key.as_str(), &kv.value)))
+}
+
+fn unix_nanos(time: SystemTime) -> u128 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default()
+}
+
+const MAX_LOGS_PER_GROUP: usize = 4096;
+const MAX_BYTES_PER_GROUP: usize = 5 * 1024 * 1024;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_log_groups() {
+        let log = |value: &str| {
+            let mut log = Log::from_unixtime(0);
+            log.add_content_kv("k", value);
+            log
+        };
+        let groups = split_log_groups((0..5000).map(|_| log("v")).collect());
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].logs().len(), MAX_LOGS_PER_GROUP);
+        assert_eq!(groups[1].logs().len(), 5000 - MAX_LOGS_PER_GROUP);
+
+        let large = "x".repeat(MAX_BYTES_PER_GROUP / 2);
+        let groups = split_log_groups(vec![log(&large), log(&large), log(&large)]);
+        assert_eq!(groups.len(), 3);
+        assert!(split_log_groups(Vec::new()).is_empty());
+    }
+}
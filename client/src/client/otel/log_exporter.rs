@@ -0,0 +1,144 @@
+use opentelemetry::logs::AnyValue;
+use opentelemetry::InstrumentationScope;
+use opentelemetry_sdk::logs::{LogBatch, LogExporter, SdkLogRecord};
+
+use super::*;
+
+/// An OpenTelemetry [`LogExporter`] that writes log records to a logstore.
+///
+/// Each record is converted to a log with the fields `timeUnixNano`, `severityNumber`,
+/// `severityText`, `content` for the body, `attribute`, `resource`, `traceID` and `spanID`
+/// of the span the record was emitted in, `otlp.name`, `service` and `host`.
+/// Records of a batch are written with as few `put_logs` requests as possible.
+///
+/// This exporter is only available with the `opentelemetry` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+/// use aliyun_log_rust_sdk::SlsLogExporter;
+/// use opentelemetry_sdk::{logs::SdkLoggerProvider, Resource};
+///
+/// // create the exporter inside a tokio runtime, requests are sent on it
+/// let exporter = SlsLogExporter::new(&client, "my-project", "my-instance-logs");
+/// let provider = SdkLoggerProvider::builder()
+///     .with_resource(Resource::builder().with_service_name("my-service").build())
+///     .with_batch_exporter(exporter)
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SlsLogExporter {
+    writer: OtelLogWriter,
+    resource: OtelResource,
+}
+
+impl SlsLogExporter {
+    /// Create an exporter writing log records to a logstore.
+    ///
+    /// It must be created inside a tokio runtime, which is used to send the requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client used to write log records
+    /// * `project` - The name of the project containing the logstore
+    /// * `logstore` - The name of the logstore to write log records to, e.g. `{instance}-logs`
+    pub fn new(client: &Client, project: impl AsRef<str>, logstore: impl AsRef<str>) -> Self {
+        Self {
+            writer: OtelLogWriter::new(client, project, logstore),
+            resource: OtelResource::default(),
+        }
+    }
+}
+
+impl std::fmt::Debug for SlsLogExporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SlsLogExporter")
+            .field("project", &self.writer.project)
+            .field("logstore", &self.writer.logstore)
+            .finish()
+    }
+}
+
+impl LogExporter for SlsLogExporter {
+    async fn export(&self, batch: LogBatch<'_>) -> OTelSdkResult {
+        let logs = batch
+            .iter()
+            .map(|(record, scope)| record_to_log(record, scope, &self.resource))
+            .collect();
+        self.writer.write(logs).await
+    }
+
+    fn set_resource(&mut self, resource: &Resource) {
+        self.resource = resource.into();
+    }
+}
+
+fn record_to_log(
+    record: &SdkLogRecord,
+    scope: &InstrumentationScope,
+    resource: &OtelResource,
+) -> Log {
+    let time = unix_nanos(
+        record
+            .timestamp()
+            .or(record.observed_timestamp())
+            .unwrap_or_else(SystemTime::now),
+    );
+    let content = match record.body() {
+        Some(AnyValue::String(s)) => s.to_string(),
+        Some(body) => any_value_to_json(body).to_string(),
+        None => String::new(),
+    };
+    let attribute: serde_json::Map<_, _> = record
+        .attributes_iter()
+        .map(|(k, v)| (k.to_string(), any_value_to_json(v)))
+        .collect();
+    let (trace_id, span_id) = match record.trace_context() {
+        Some(context) => (context.trace_id.to_string(), context.span_id.to_string()),
+        None => (String::new(), String::new()),
+    };
+
+    let mut log = Log::from_unixtime((time / 1_000_000_000) as u32);
+    log.add_content_kv("host", resource.host.as_str())
+        .add_content_kv("service", resource.service.as_str())
+        .add_content_kv("resource", resource.json.as_str())
+        .add_content_kv("otlp.name", scope.name())
+        .add_content_kv("timeUnixNano", time.to_string())
+        .add_content_kv(
+            "severityNumber",
+            record
+                .severity_number()
+                .map(|n| (n as i32).to_string())
+                .unwrap_or_default(),
+        )
+        .add_content_kv("severityText", record.severity_text().unwrap_or_default())
+        .add_content_kv("name", record.event_name().unwrap_or_default())
+        .add_content_kv("content", content)
+        .add_content_kv(
+            "attribute",
+            serde_json::Value::Object(attribute).to_string(),
+        )
+        .add_content_kv("traceID", trace_id)
+        .add_content_kv("spanID", span_id);
+    log
+}
+
+fn any_value_to_json(value: &AnyValue) -> serde_json::Value {
+    match value {
+        AnyValue::Int(v) => (*v).into(),
+        AnyValue::Double(v) => (*v).into(),
+        AnyValue::String(v) => v.as_str().into(),
+        AnyValue::Boolean(v) => (*v).into(),
+        AnyValue::ListAny(values) => values.iter().map(any_value_to_json).collect(),
+        AnyValue::Map(map) => map
+            .iter()
+            .map(|(k, v)| (k.to_string(), any_value_to_json(v)))
+            .collect::<serde_json::Map<_, _>>()
+            .into(),
+        // bytes and variants added in later versions are kept in their debug format
+        other => format!("{other:?}").into(),
+    }
+}
@@ -0,0 +1,218 @@
+use opentelemetry::trace::{SpanId, SpanKind, Status};
+use opentelemetry_sdk::trace::{SpanData, SpanExporter};
+
+use super::*;
+
+/// An OpenTelemetry [`SpanExporter`] that writes spans to a logstore of an SLS trace instance.
+///
+/// Each span is converted to a log with the fields of the trace instance layout:
+/// `traceID`, `spanID`, `parentSpanID`, `name`, `kind`, `start`, `end` and `duration`
+/// in microseconds, `attribute`, `resource`, `links`, `logs` for span events,
+/// `statusCode`, `statusMessage`, `service` and `host`.
+/// Spans of a batch are written with as few `put_logs` requests as possible.
+///
+/// This exporter is only available with the `opentelemetry` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+/// use aliyun_log_rust_sdk::SlsSpanExporter;
+/// use opentelemetry_sdk::{trace::SdkTracerProvider, Resource};
+///
+/// // create the exporter inside a tokio runtime, requests are sent on it
+/// let exporter = SlsSpanExporter::new(&client, "my-project", "my-instance-traces");
+/// let provider = SdkTracerProvider::builder()
+///     .with_resource(Resource::builder().with_service_name("my-service").build())
+///     .with_batch_exporter(exporter)
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SlsSpanExporter {
+    writer: OtelLogWriter,
+    resource: OtelResource,
+}
+
+impl SlsSpanExporter {
+    /// Create an exporter writing spans to a logstore.
+    ///
+    /// It must be created inside a tokio runtime, which is used to send the requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client used to write spans
+    /// * `project` - The name of the project containing the logstore
+    /// * `logstore` - The name of the logstore to write spans to, e.g. `{instance}-traces`
+    pub fn new(client: &Client, project: impl AsRef<str>, logstore: impl AsRef<str>) -> Self {
+        Self {
+            writer: OtelLogWriter::new(client, project, logstore),
+            resource: OtelResource::default(),
+        }
+    }
+}
+
+impl std::fmt::Debug for SlsSpanExporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SlsSpanExporter")
+            .field("project", &self.writer.project)
+            .field("logstore", &self.writer.logstore)
+            .finish()
+    }
+}
+
+impl SpanExporter for SlsSpanExporter {
+    async fn export(&self, batch: Vec<SpanData>) -> OTelSdkResult {
+        let logs = batch
+            .iter()
+            .map(|span| span_to_log(span, &self.resource))
+            .collect();
+        self.writer.write(logs).await
+    }
+
+    fn set_resource(&mut self, resource: &Resource) {
+        self.resource = resource.into();
+    }
+}
+
+fn span_to_log(span: &SpanData, resource: &OtelResource) -> Log {
+    let start = unix_nanos(span.start_time);
+    let end = unix_nanos(span.end_time);
+    let parent_span_id = if span.parent_span_id == SpanId::INVALID {
+        String::new()
+    } else {
+        span.parent_span_id.to_string()
+    };
+    let (status_code, status_message) = match &span.status {
+        Status::Unset => ("UNSET", ""),
+        Status::Ok => ("OK", ""),
+        Status::Error { description } => ("ERROR", description.as_ref()),
+    };
+    let links: Vec<_> = span
+        .links
+        .iter()
+        .map(|link| {
+            serde_json::json!({
+                "traceID": link.span_context.trace_id().to_string(),
+                "spanID": link.span_context.span_id().to_string(),
+                "traceState": link.span_context.trace_state().header(),
+                "attribute": key_values_to_json(&link.attributes),
+            })
+        })
+        .collect();
+    let events: Vec<_> = span
+        .events
+        .iter()
+        .map(|event| {
+            serde_json::json!({
+                "name": event.name,
+                "time": unix_nanos(event.timestamp) as u64,
+                "attribute": key_values_to_json(&event.attributes),
+            })
+        })
+        .collect();
+
+    let mut log = Log::from_unixtime((start / 1_000_000_000) as u32);
+    log.add_content_kv("host", resource.host.as_str())
+        .add_content_kv("service", resource.service.as_str())
+        .add_content_kv("resource", resource.json.as_str())
+        .add_content_kv("otlp.name", span.instrumentation_scope.name())
+        .add_content_kv(
+            "otlp.version",
+            span.instrumentation_scope.version().unwrap_or_default(),
+        )
+        .add_content_kv("name", span.name.as_ref())
+        .add_content_kv("kind", span_kind(&span.span_kind))
+        .add_content_kv("traceID", span.span_context.trace_id().to_string())
+        .add_content_kv("spanID", span.span_context.span_id().to_string())
+        .add_content_kv("parentSpanID", parent_span_id)
+        .add_content_kv("traceState", span.span_context.trace_state().header())
+        .add_content_kv("links", serde_json::Value::from(links).to_string())
+        .add_content_kv("logs", serde_json::Value::from(events).to_string())
+        .add_content_kv("start", (start / 1000).to_string())
+        .add_content_kv("end", (end / 1000).to_string())
+        .add_content_kv("duration", (end.saturating_sub(start) / 1000).to_string())
+        .add_content_kv("attribute", key_values_to_json(&span.attributes))
+        .add_content_kv("statusCode", status_code)
+        .add_content_kv("statusMessage", status_message);
+    log
+}
+
+fn span_kind(kind: &SpanKind) -> &'static str {
+    match kind {
+        SpanKind::Client => "client",
+        SpanKind::Server => "server",
+        SpanKind::Producer => "producer",
+        SpanKind::Consumer => "consumer",
+        SpanKind::Internal => "internal",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::trace::{SpanContext, TraceFlags, TraceId, TraceState};
+    use opentelemetry::InstrumentationScope;
+    use opentelemetry_sdk::trace::{SpanEvents, SpanLinks};
+    use std::time::Duration;
+
+    fn content(log: &Log, key: &str) -> String {
+        log.contents()
+            .iter()
+            .find(|c| c.key() == key)
+            .map(|c| c.value().clone())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_span_to_log() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_micros(1_700_000_000_000_001);
+        let span = SpanData {
+            span_context: SpanContext::new(
+                TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+                SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+                TraceFlags::SAMPLED,
+                false,
+                TraceState::NONE,
+            ),
+            parent_span_id: SpanId::INVALID,
+            parent_span_is_remote: false,
+            span_kind: SpanKind::Server,
+            name: "GET /users".into(),
+            start_time: start,
+            end_time: start + Duration::from_millis(25),
+            attributes: vec![KeyValue::new("http.status_code", 200)],
+            dropped_attributes_count: 0,
+            events: SpanEvents::default(),
+            links: SpanLinks::default(),
+            status: Status::error("timeout"),
+            instrumentation_scope: InstrumentationScope::builder("my-lib").build(),
+        };
+        let resource = OtelResource::from(
+            &Resource::builder_empty()
+                .with_service_name("user-service")
+                .build(),
+        );
+
+        let log = span_to_log(&span, &resource);
+        assert_eq!(*log.time(), 1_700_000_000);
+        assert_eq!(content(&log, "traceID"), "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(content(&log, "spanID"), "00f067aa0ba902b7");
+        assert_eq!(content(&log, "parentSpanID"), "");
+        assert_eq!(content(&log, "kind"), "server");
+        assert_eq!(content(&log, "start"), "1700000000000001");
+        assert_eq!(content(&log, "end"), "1700000000025001");
+        assert_eq!(content(&log, "duration"), "25000");
+        assert_eq!(content(&log, "attribute"), r#"{"http.status_code":"200"}"#);
+        assert_eq!(content(&log, "service"), "user-service");
+        assert_eq!(
+            content(&log, "resource"),
+            r#"{"service.name":"user-service"}"#
+        );
+        assert_eq!(content(&log, "statusCode"), "ERROR");
+        assert_eq!(content(&log, "statusMessage"), "timeout");
+        assert_eq!(content(&log, "otlp.name"), "my-lib");
+        assert_eq!(content(&log, "links"), "[]");
+    }
+}
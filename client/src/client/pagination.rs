@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use futures_util::{future, stream, StreamExt, TryStreamExt};
+
+use super::{BoxFuture, BoxStream};
+use crate::Result;
+
+/// Maximum number of items the list APIs return in one page.
+pub(crate) const MAX_PAGE_SIZE: i32 = 500;
+
+/// A page of a list API.
+pub(crate) struct Page<T> {
+    pub(crate) items: Vec<T>,
+    /// Total number of items, all pages included
+    pub(crate) total: i32,
+}
+
+/// Stream the items of all the pages of a list API, starting at `offset`.
+///
+/// The first page is fetched alone to learn the total number of items,
+/// then up to `concurrency` pages are fetched at a time, and yielded in order.
+/// The stream ends after the first error.
+pub(crate) fn paginate<T, F>(
+    offset: i32,
+    page_size: i32,
+    concurrency: usize,
+    fetch: F,
+) -> BoxStream<Result<T>>
+where
+    T: Send + 'static,
+    F: Fn(i32, i32) -> BoxFuture<Result<Page<T>>> + Send + Sync + 'static,
+{
+    let page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+    let concurrency = concurrency.max(1);
+    let fetch = Arc::new(fetch);
+    let first = fetch(offset, page_size);
+    let pages = stream::once(first)
+        .map(move |first| match first {
+            Ok(page) => {
+                let fetch = fetch.clone();
+                let offsets =
+                    (offset.saturating_add(page_size)..page.total).step_by(page_size as usize);
+                let rest = stream::iter(offsets)
+                    .map(move |offset| fetch(offset, page_size))
+                    .buffered(concurrency);
+                stream::once(future::ready(Ok(page))).chain(rest).boxed()
+            }
+            Err(err) => stream::once(future::ready(Err(err))).boxed(),
+        })
+        .flatten()
+        .scan(false, |failed, page| {
+            if *failed {
+                return future::ready(None);
+            }
+            *failed = page.is_err();
+            future::ready(Some(page))
+        });
+    Box::pin(
+        pages
+            .map_ok(|page| stream::iter(page.items.into_iter().map(Ok)))
+            .try_flatten(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    fn fake_pages(total: i32, fail_at: Option<i32>) -> BoxStream<Result<i32>> {
+        paginate(0, 3, 2, move |offset, size| {
+            Box::pin(async move {
+                if Some(offset) == fail_at {
+                    return Err(Error::Other(anyhow::anyhow!("page failed")));
+                }
+                Ok(Page {
+                    items: (offset..(offset + size).min(total)).collect(),
+                    total,
+                })
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_paginate() {
+        let items: Vec<i32> = fake_pages(8, None).try_collect().await.unwrap();
+        assert_eq!(items, (0..8).collect::<Vec<_>>());
+
+        let items: Vec<i32> = fake_pages(0, None).try_collect().await.unwrap();
+        assert!(items.is_empty());
+
+        let items: Vec<Result<i32>> = fake_pages(8, Some(3)).collect().await;
+        assert_eq!(items.len(), 4);
+        assert!(items[3].is_err());
+    }
+}
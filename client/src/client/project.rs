@@ -14,3 +14,6 @@ pub use get_project::*;
 
 mod list_projects;
 pub use list_projects::*;
+
+mod ensure_project;
+pub use ensure_project::*;
@@ -48,6 +48,8 @@ pub struct CreateProjectRequestBuilder {
     recycle_bin_enabled: Option<bool>,
 }
 
+request_options!(CreateProjectRequestBuilder);
+
 impl CreateProjectRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<()> {
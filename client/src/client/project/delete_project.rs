@@ -33,6 +33,8 @@ pub struct DeleteProjectRequestBuilder {
     project_name: String,
 }
 
+request_options!(DeleteProjectRequestBuilder);
+
 impl DeleteProjectRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<()> {
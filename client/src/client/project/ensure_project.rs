@@ -0,0 +1,149 @@
+use super::*;
+use crate::ErrorCode;
+
+impl crate::client::Client {
+    /// Create a project if it does not exist, or update it to the desired settings.
+    ///
+    /// The project is created with all the settings set, so `description` is required when it does not exist.
+    /// Otherwise only the settings which can be updated, `description` and `recycle_bin_enabled`,
+    /// are compared and updated when they differ, and the outcome lists the fields updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_name` - Name of the project
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let outcome = client.ensure_project("test-project")
+    ///     .description("this is test")
+    ///     .recycle_bin_enabled(true)
+    ///     .send()
+    ///     .await?;
+    /// println!("changed fields: {:?}", outcome.changed_fields());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ensure_project(&self, project_name: impl AsRef<str>) -> EnsureProjectRequestBuilder {
+        EnsureProjectRequestBuilder {
+            handle: self.handle.clone(),
+            project_name: project_name.as_ref().to_string(),
+            description: None,
+            resource_group_id: None,
+            data_redundancy_type: None,
+            recycle_bin_enabled: None,
+        }
+    }
+}
+
+pub struct EnsureProjectRequestBuilder {
+    handle: HandleRef,
+    project_name: String,
+    description: Option<String>,
+    resource_group_id: Option<String>,
+    data_redundancy_type: Option<String>,
+    recycle_bin_enabled: Option<bool>,
+}
+
+request_options!(EnsureProjectRequestBuilder);
+
+impl EnsureProjectRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> BoxFuture<Result<EnsureOutcome>> {
+        Box::pin(async move {
+            let client = crate::Client {
+                handle: self.handle,
+            };
+            let get = || client.get_project(&self.project_name).send();
+            let current = match get().await {
+                Ok(resp) => resp.take_body(),
+                Err(err) if err.code() == Some(ErrorCode::ProjectNotExist) => {
+                    let mut create = client.create_project(&self.project_name);
+                    if let Some(description) = &self.description {
+                        create = create.description(description);
+                    }
+                    if let Some(resource_group_id) = &self.resource_group_id {
+                        create = create.resource_group_id(resource_group_id);
+                    }
+                    if let Some(data_redundancy_type) = &self.data_redundancy_type {
+                        create = create.data_redundancy_type(data_redundancy_type);
+                    }
+                    if let Some(enabled) = self.recycle_bin_enabled {
+                        create = create.recycle_bin_enabled(enabled);
+                    }
+                    match create.send().await {
+                        Ok(_) => return Ok(EnsureOutcome::Created),
+                        // created concurrently, compare with what was created instead
+                        Err(err) if err.code() == Some(ErrorCode::ProjectAlreadyExist) => {
+                            get().await?.take_body()
+                        }
+                        Err(err) => return Err(err),
+                    }
+                }
+                Err(err) => return Err(err),
+            };
+
+            let mut changes = FieldChanges::default();
+            let mut update = client.update_project(&self.project_name);
+            if let Some(description) = changes.check(
+                "description",
+                self.description.as_deref(),
+                Some(current.description().as_str()),
+            ) {
+                update = update.description(description);
+            }
+            if let Some(enabled) = changes.check(
+                "recycle_bin_enabled",
+                self.recycle_bin_enabled,
+                Some(current.recycle_bin_enabled().unwrap_or_default()),
+            ) {
+                update = update.recycle_bin_enabled(enabled);
+            }
+            if !changes.is_empty() {
+                update.send().await?;
+            }
+            Ok(changes.into_outcome())
+        })
+    }
+
+    /// Set the project description, required if the project does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `description` - Description of the project
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the resource group ID, only used if the project is created.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_group_id` - Resource group ID to create project into.
+    pub fn resource_group_id(mut self, resource_group_id: impl Into<String>) -> Self {
+        self.resource_group_id = Some(resource_group_id.into());
+        self
+    }
+
+    /// Set the data redundancy type, only used if the project is created.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_redundancy_type` - Data redundancy type, `LRS` or `ZRS`
+    pub fn data_redundancy_type(mut self, data_redundancy_type: impl Into<String>) -> Self {
+        self.data_redundancy_type = Some(data_redundancy_type.into());
+        self
+    }
+
+    /// Set whether to enable recycle bin.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to enable recycle bin
+    pub fn recycle_bin_enabled(mut self, enabled: bool) -> Self {
+        self.recycle_bin_enabled = Some(enabled);
+        self
+    }
+}
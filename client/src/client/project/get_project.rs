@@ -36,6 +36,8 @@ pub struct GetProjectRequestBuilder {
     project_name: String,
 }
 
+request_options!(GetProjectRequestBuilder);
+
 impl GetProjectRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<GetProjectResponse> {
@@ -1,5 +1,6 @@
 use super::*;
 use crate::ResponseResult;
+use futures_util::TryStreamExt;
 use getset::Getters;
 use serde::Deserialize;
 
@@ -44,10 +45,12 @@ impl crate::client::Client {
             project_name: None,
             description: None,
             resource_group_id: None,
+            concurrency: None,
         }
     }
 }
 
+#[derive(Clone)]
 pub struct ListProjectsRequestBuilder {
     handle: HandleRef,
     offset: i32,
@@ -55,8 +58,11 @@ pub struct ListProjectsRequestBuilder {
     project_name: Option<String>,
     description: Option<String>,
     resource_group_id: Option<String>,
+    concurrency: Option<usize>,
 }
 
+request_options!(ListProjectsRequestBuilder);
+
 impl ListProjectsRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<ListProjectsResponse> {
@@ -96,6 +102,68 @@ impl ListProjectsRequestBuilder {
         self
     }
 
+    /// Set how many pages [`into_stream`](Self::into_stream) and [`collect_all`](Self::collect_all)
+    /// fetch at a time, default is 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `concurrency` - Maximum number of pages fetched concurrently
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = Some(concurrency);
+        self
+    }
+
+    /// Stream all the projects from `offset`, fetching them in pages of `size` projects, at most 500.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut projects = client.list_projects(0, 100).into_stream();
+    /// while let Some(project) = projects.try_next().await? {
+    ///     println!("Project: {}", project.project_name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> BoxStream<Result<ListProjectsProject>> {
+        let (offset, size) = (self.offset, self.size);
+        let concurrency = self.concurrency.unwrap_or(1);
+        paginate(offset, size, concurrency, move |offset, size| {
+            let mut page = self.clone();
+            page.offset = offset;
+            page.size = size;
+            Box::pin(async move {
+                let body = page.send().await?.take_body();
+                Ok(Page {
+                    items: body.projects,
+                    total: body.total,
+                })
+            })
+        })
+    }
+
+    /// Collect all the projects from `offset`, fetching them in pages of `size` projects, at most 500.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let projects = client.list_projects(0, 500)
+    ///     .concurrency(4)
+    ///     .collect_all()
+    ///     .await?;
+    /// println!("Total projects: {}", projects.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use = "the result future must be awaited"]
+    pub fn collect_all(self) -> BoxFuture<Result<Vec<ListProjectsProject>>> {
+        Box::pin(self.into_stream().try_collect())
+    }
+
     fn build(self) -> BuildResult<ListProjectsRequest> {
         Ok((
             self.handle,
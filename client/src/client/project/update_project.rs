@@ -41,6 +41,8 @@ pub struct UpdateProjectRequestBuilder {
     recycle_bin_enabled: Option<bool>,
 }
 
+request_options!(UpdateProjectRequestBuilder);
+
 impl UpdateProjectRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<()> {
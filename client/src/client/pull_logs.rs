@@ -121,6 +121,8 @@ pub struct PullLogsRequestBuilder {
     query_id: Option<String>,
 }
 
+request_options!(PullLogsRequestBuilder);
+
 impl PullLogsRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<PullLogsResponse> {
@@ -149,6 +151,8 @@ impl PullLogsRequestBuilder {
     }
 
     /// Optional, the query to filter logs, using the spl syntax, e.g, "* | where name = 'Mike'".
+    ///
+    /// It can be rendered from a condition of the [`query`](crate::query) builder with `to_spl`.
     pub fn query<T: Into<String>>(mut self, query: T) -> Self {
         self.query = Some(query.into());
         self
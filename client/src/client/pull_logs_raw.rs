@@ -113,6 +113,8 @@ pub struct PullLogsRawRequestBuilder {
     query_id: Option<String>,
 }
 
+request_options!(PullLogsRawRequestBuilder);
+
 impl PullLogsRawRequestBuilder {
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<PullLogsRawResponse> {
@@ -4,6 +4,7 @@ use crate::{common::*, RequestError, RequestErrorKind};
 use aliyun_log_sdk_protobuf::LogGroup;
 
 use super::*;
+use crate::rate_limit::WriteTarget;
 
 impl crate::client::Client {
     /// Write logs to a logstore.
@@ -49,7 +50,8 @@ impl crate::client::Client {
         PutLogsRequestBuilder {
             handle: self.handle.clone(),
             project: project.as_ref().to_string(),
-            path: format!("/logstores/{}/shards/lb", logstore.as_ref()),
+            logstore: logstore.as_ref().to_string(),
+            hash_key: None,
             log_group: None,
         }
     }
@@ -57,11 +59,14 @@ impl crate::client::Client {
 
 pub struct PutLogsRequestBuilder {
     project: String,
-    path: String,
+    logstore: String,
+    hash_key: Option<String>,
     log_group: Option<LogGroup>,
-    handle: HandleRef,
+    pub(super) handle: HandleRef,
 }
 
+request_options!(PutLogsRequestBuilder);
+
 impl PutLogsRequestBuilder {
     /// The log group to write to the destination logstore, which contains multiple logs.
     pub fn log_group(mut self, log_group: LogGroup) -> Self {
@@ -69,6 +74,15 @@ impl PutLogsRequestBuilder {
         self
     }
 
+    /// Write the logs to the shard whose hash key range contains `hash_key`,
+    /// instead of a shard chosen by the server.
+    ///
+    /// The hash key is a 128-bit hexadecimal MD5 string, e.g. `00000000000000000000000000000000`.
+    pub fn hash_key(mut self, hash_key: impl AsRef<str>) -> Self {
+        self.hash_key = Some(hash_key.as_ref().to_string());
+        self
+    }
+
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<PutLogsResponse> {
         Box::pin(async move {
@@ -83,8 +97,10 @@ impl PutLogsRequestBuilder {
             self.handle,
             PutLogsRequest {
                 log_group: self.log_group.unwrap(),
-                path: self.path,
+                path: write_path(&self.logstore, self.hash_key.is_some()),
                 project: self.project,
+                logstore: self.logstore,
+                hash_key: self.hash_key,
             },
         ))
     }
@@ -92,8 +108,19 @@ impl PutLogsRequestBuilder {
 
 type PutLogsResponse = ();
 
+/// Path of a write to a logstore, routed by hash key or load balanced by the server.
+pub(super) fn write_path(logstore: &str, hash_key: bool) -> String {
+    if hash_key {
+        format!("/logstores/{logstore}/shards/route")
+    } else {
+        format!("/logstores/{logstore}/shards/lb")
+    }
+}
+
 struct PutLogsRequest {
     project: String,
+    logstore: String,
+    hash_key: Option<String>,
     path: String,
     log_group: LogGroup,
 }
@@ -111,6 +138,18 @@ impl Request for PutLogsRequest {
         &self.path
     }
 
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        let hash_key = self.hash_key.as_ref()?;
+        Some(vec![("key".to_string(), hash_key.clone())])
+    }
+
+    fn write_target(&self) -> Option<WriteTarget<'_>> {
+        Some(WriteTarget {
+            logstore: &self.logstore,
+            hash_key: self.hash_key.as_deref(),
+        })
+    }
+
     fn body(&self) -> Result<Option<bytes::Bytes>, RequestError> {
         let body = self
             .log_group
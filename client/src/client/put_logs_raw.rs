@@ -1,7 +1,9 @@
 use crate::error::Result;
 use crate::{common::*, RequestError};
 
+use super::put_logs::write_path;
 use super::*;
+use crate::rate_limit::WriteTarget;
 
 impl crate::client::Client {
     #[doc(hidden)]
@@ -13,7 +15,8 @@ impl crate::client::Client {
         PutLogsRawRequestBuilder {
             handle: self.handle.clone(),
             project: project.as_ref().to_string(),
-            path: format!("/logstores/{}/shards/lb", logstore.as_ref()),
+            logstore: logstore.as_ref().to_string(),
+            hash_key: None,
             data: None,
             raw_size: None,
             compress_type: None,
@@ -23,14 +26,26 @@ impl crate::client::Client {
 
 pub struct PutLogsRawRequestBuilder {
     project: String,
-    path: String,
+    logstore: String,
+    hash_key: Option<String>,
     data: Option<bytes::Bytes>,
     raw_size: Option<usize>,
     compress_type: Option<String>,
     handle: HandleRef,
 }
 
+request_options!(PutLogsRawRequestBuilder);
+
 impl PutLogsRawRequestBuilder {
+    /// Write the logs to the shard whose hash key range contains `hash_key`,
+    /// instead of a shard chosen by the server.
+    ///
+    /// The hash key is a 128-bit hexadecimal MD5 string, e.g. `00000000000000000000000000000000`.
+    pub fn hash_key(mut self, hash_key: impl AsRef<str>) -> Self {
+        self.hash_key = Some(hash_key.as_ref().to_string());
+        self
+    }
+
     #[must_use = "the result future must be awaited"]
     pub fn send(self) -> ResponseResultBoxFuture<PutLogsRawResponse> {
         Box::pin(async move {
@@ -66,8 +81,10 @@ impl PutLogsRawRequestBuilder {
         Ok((
             self.handle,
             PutLogsRawRequest {
-                path: self.path,
+                path: write_path(&self.logstore, self.hash_key.is_some()),
                 project: self.project,
+                logstore: self.logstore,
+                hash_key: self.hash_key,
                 data: self.data.unwrap(),
                 raw_size: self.raw_size.unwrap(),
                 compress_type: self.compress_type.unwrap(),
@@ -80,6 +97,8 @@ type PutLogsRawResponse = ();
 
 struct PutLogsRawRequest {
     project: String,
+    logstore: String,
+    hash_key: Option<String>,
     path: String,
     data: bytes::Bytes,
     raw_size: usize,
@@ -98,6 +117,18 @@ impl Request for PutLogsRawRequest {
         &self.path
     }
 
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        let hash_key = self.hash_key.as_ref()?;
+        Some(vec![("key".to_string(), hash_key.clone())])
+    }
+
+    fn write_target(&self) -> Option<WriteTarget<'_>> {
+        Some(WriteTarget {
+            logstore: &self.logstore,
+            hash_key: self.hash_key.as_deref(),
+        })
+    }
+
     fn body(&self) -> Result<Option<bytes::Bytes>, RequestError> {
         Ok(Some(self.data.clone()))
     }
@@ -0,0 +1,307 @@
+use crate::{RequestError, RequestErrorKind};
+use aliyun_log_sdk_protobuf::{Log, LogGroup};
+use getset::Getters;
+use std::collections::BTreeMap;
+
+use super::*;
+
+impl crate::client::Client {
+    /// Write Prometheus-style metric samples to a metricstore.
+    ///
+    /// Each sample is encoded into the metricstore log layout, with the metric name in
+    /// `__name__`, the sorted labels in `__labels__`, the value in `__value__` and
+    /// the nanosecond timestamp in `__time_nano__`, and the samples are written with `put_logs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project containing the metricstore
+    /// * `metricstore` - The name of the metricstore to write samples to
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::MetricSample;
+    ///
+    /// client.put_metrics("my-project", "my-metricstore")
+    ///     .sample(
+    ///         MetricSample::new("http_requests_total", 1027.0)
+    ///             .label("method", "post")
+    ///             .label("code", "200"),
+    ///     )
+    ///     .sample(MetricSample::new("process_cpu_usage", 0.35).label("host", "web-1"))
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn put_metrics(
+        &self,
+        project: impl AsRef<str>,
+        metricstore: impl AsRef<str>,
+    ) -> PutMetricsRequestBuilder {
+        PutMetricsRequestBuilder {
+            inner: self.put_logs(project, metricstore),
+            samples: None,
+        }
+    }
+}
+
+pub struct PutMetricsRequestBuilder {
+    inner: PutLogsRequestBuilder,
+    samples: Option<Vec<MetricSample>>,
+}
+
+request_options!(PutMetricsRequestBuilder, inner.handle);
+
+impl PutMetricsRequestBuilder {
+    /// Add a sample to write, at least one is required.
+    pub fn sample(mut self, sample: MetricSample) -> Self {
+        self.samples.get_or_insert_with(Vec::new).push(sample);
+        self
+    }
+
+    /// Add samples to write, at least one is required.
+    pub fn samples(mut self, samples: impl IntoIterator<Item = MetricSample>) -> Self {
+        self.samples.get_or_insert_with(Vec::new).extend(samples);
+        self
+    }
+
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move { self.build()?.send().await })
+    }
+
+    fn build(self) -> std::result::Result<PutLogsRequestBuilder, RequestError> {
+        check_required!(("samples", self.samples));
+        let mut log_group = LogGroup::new();
+        for sample in self.samples.unwrap() {
+            log_group.add_log(Log::try_from(sample)?);
+        }
+        Ok(self.inner.log_group(log_group))
+    }
+}
+
+/// A Prometheus-style metric sample: a metric name, a set of labels, a value and a timestamp.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_log_rust_sdk::MetricSample;
+/// use aliyun_log_sdk_protobuf::Log;
+///
+/// let sample = MetricSample::new("http_requests_total", 1027.0)
+///     .label("method", "post")
+///     .label("code", "200")
+///     .with_time_nano(1_700_000_000_123_456_789);
+///
+/// let log = Log::try_from(sample).unwrap();
+/// assert_eq!(*log.time(), 1_700_000_000);
+/// ```
+#[derive(Debug, Clone, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct MetricSample {
+    /// Metric name
+    name: String,
+
+    /// Labels of the sample, sorted by name
+    labels: BTreeMap<String, String>,
+
+    /// Sample value
+    value: f64,
+
+    /// Unix timestamp of the sample, in nanoseconds
+    time_nano: i64,
+}
+
+impl MetricSample {
+    /// Create a sample of `name` with `value`, timestamped with the current time.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Metric name, e.g. `http_requests_total`
+    /// * `value` - Sample value
+    pub fn new(name: impl Into<String>, value: f64) -> Self {
+        let time_nano = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as i64)
+            .unwrap_or_default();
+        Self {
+            name: name.into(),
+            labels: BTreeMap::new(),
+            value,
+            time_nano,
+        }
+    }
+
+    /// Add a label to the sample.
+    ///
+    /// Label names and values must not contain the `|` or `#$#` separators of the
+    /// metricstore label encoding, `put_metrics` rejects the samples with such labels.
+    pub fn label(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(name.into(), value.into());
+        self
+    }
+
+    /// Set the unix timestamp of the sample, in nanoseconds.
+    ///
+    /// The timestamp must be between 1970 and 2106, `put_metrics` rejects the samples out of this range.
+    pub fn with_time_nano(mut self, time_nano: i64) -> Self {
+        self.time_nano = time_nano;
+        self
+    }
+
+    /// Encode the labels as `name#$#value` pairs sorted by name and joined by `|`.
+    fn encode_labels(&self) -> String {
+        self.labels
+            .iter()
+            .map(|(name, value)| format!("{name}{METRIC_LABEL_KV_SEPARATOR}{value}"))
+            .collect::<Vec<_>>()
+            .join(METRIC_LABEL_SEPARATOR)
+    }
+
+    /// Check that the labels can be encoded, as separators in them would split them differently.
+    fn check_labels(&self) -> std::result::Result<(), String> {
+        let separators = [METRIC_LABEL_SEPARATOR, METRIC_LABEL_KV_SEPARATOR];
+        for (name, value) in &self.labels {
+            if [name, value]
+                .iter()
+                .any(|text| separators.iter().any(|separator| text.contains(separator)))
+            {
+                return Err(format!(
+                    "label {name:?}={value:?} of metric {:?} contains `{METRIC_LABEL_SEPARATOR}` or `{METRIC_LABEL_KV_SEPARATOR}`",
+                    self.name
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The time of the log of the sample, in seconds, as the time of a log is from 1970 to 2106.
+    fn log_time(&self) -> std::result::Result<u32, String> {
+        let time = self.time_nano.div_euclid(1_000_000_000);
+        u32::try_from(time).map_err(|_| {
+            format!(
+                "timestamp {} of metric {:?} is out of the range of log times",
+                self.time_nano, self.name
+            )
+        })
+    }
+
+    /// Format the value the way Prometheus does, e.g. `+Inf` instead of `inf`.
+    fn encode_value(&self) -> String {
+        if self.value.is_nan() {
+            "NaN".to_string()
+        } else if self.value == f64::INFINITY {
+            "+Inf".to_string()
+        } else if self.value == f64::NEG_INFINITY {
+            "-Inf".to_string()
+        } else {
+            self.value.to_string()
+        }
+    }
+}
+
+/// Encode a sample into the metricstore log layout.
+///
+/// Fails with [`RequestErrorKind::InvalidParameter`] if a label contains the label separators,
+/// or if the timestamp is before 1970 or after 2106.
+impl TryFrom<MetricSample> for Log {
+    type Error = RequestError;
+
+    fn try_from(sample: MetricSample) -> std::result::Result<Self, Self::Error> {
+        let time = sample
+            .check_labels()
+            .and_then(|_| sample.log_time())
+            .map_err(RequestErrorKind::InvalidParameter)?;
+        let mut log = Log::from_unixtime(time);
+        log.add_content_kv("__name__", sample.name.as_str())
+            .add_content_kv("__labels__", sample.encode_labels())
+            .add_content_kv("__time_nano__", sample.time_nano.to_string())
+            .add_content_kv("__value__", sample.encode_value());
+        Ok(log)
+    }
+}
+
+const METRIC_LABEL_SEPARATOR: &str = "|";
+const METRIC_LABEL_KV_SEPARATOR: &str = "#$#";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content(log: &Log, key: &str) -> String {
+        log.contents()
+            .iter()
+            .find(|c| c.key() == key)
+            .map(|c| c.value().clone())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_metric_sample_to_log() {
+        let log = Log::try_from(
+            MetricSample::new("http_requests_total", 1027.0)
+                .label("method", "post")
+                .label("code", "200")
+                .with_time_nano(1_700_000_000_123_456_789),
+        )
+        .unwrap();
+
+        assert_eq!(*log.time(), 1_700_000_000);
+        assert_eq!(content(&log, "__name__"), "http_requests_total");
+        assert_eq!(content(&log, "__labels__"), "code#$#200|method#$#post");
+        assert_eq!(content(&log, "__time_nano__"), "1700000000123456789");
+        assert_eq!(content(&log, "__value__"), "1027");
+    }
+
+    #[test]
+    fn test_metric_sample_special_values() {
+        let encode = |value| {
+            let log = Log::try_from(MetricSample::new("m", value)).unwrap();
+            content(&log, "__value__")
+        };
+        assert_eq!(encode(0.25), "0.25");
+        assert_eq!(encode(f64::NAN), "NaN");
+        assert_eq!(encode(f64::INFINITY), "+Inf");
+        assert_eq!(encode(f64::NEG_INFINITY), "-Inf");
+        let log = Log::try_from(MetricSample::new("m", 1.0)).unwrap();
+        assert_eq!(content(&log, "__labels__"), "");
+    }
+
+    #[test]
+    fn test_metric_sample_labels() {
+        let sample = MetricSample::new("m", 1.0).label("path", "/a/b?c=d");
+        assert_eq!(sample.check_labels(), Ok(()));
+        assert!(MetricSample::new("m", 1.0)
+            .label("path", "a|b")
+            .check_labels()
+            .is_err());
+        assert!(MetricSample::new("m", 1.0)
+            .label("a#$#b", "c")
+            .check_labels()
+            .is_err());
+    }
+
+    #[test]
+    fn test_metric_sample_time() {
+        let sample = MetricSample::new("m", 1.0);
+        assert!(sample.log_time().is_ok());
+        assert!(sample.clone().with_time_nano(-1).log_time().is_err());
+        assert!(sample
+            .clone()
+            .with_time_nano((u32::MAX as i64 + 1) * 1_000_000_000)
+            .log_time()
+            .is_err());
+        assert_eq!(
+            sample
+                .clone()
+                .with_time_nano(u32::MAX as i64 * 1_000_000_000)
+                .log_time(),
+            Ok(u32::MAX)
+        );
+
+        // the conversion to a log does not wrap out-of-range timestamps
+        assert!(Log::try_from(sample.with_time_nano(-1)).is_err());
+    }
+}
@@ -0,0 +1,310 @@
+use super::*;
+use crate::ResponseResult;
+use getset::{CopyGetters, Getters};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+impl crate::client::Client {
+    /// Evaluate a PromQL query against a metricstore.
+    ///
+    /// The query is sent to the Prometheus-compatible API of the metricstore.
+    /// It is evaluated at a single point in time by default, or over a time range
+    /// if [`range`](QueryMetricsRequestBuilder::range) is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project containing the metricstore
+    /// * `metricstore` - The name of the metricstore to query
+    /// * `query` - The PromQL expression to evaluate
+    ///
+    /// # Examples
+    ///
+    /// Instant query:
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::MetricQueryResult;
+    ///
+    /// let resp = client
+    ///     .query_metrics("my-project", "my-metricstore", "sum(rate(http_requests_total[5m])) by (host)")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// if let MetricQueryResult::Vector(samples) = resp.get_body().data() {
+    ///     for sample in samples {
+    ///         println!("{:?} = {}", sample.metric().get("host"), sample.value().value());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Range query over the last day, with a point every hour:
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::MetricQueryResult;
+    ///
+    /// let now = chrono::Utc::now().timestamp();
+    /// let resp = client
+    ///     .query_metrics("my-project", "my-metricstore", "max(process_cpu_usage) by (host)")
+    ///     .range(now - 86400, now, 3600)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// if let MetricQueryResult::Matrix(series) = resp.get_body().data() {
+    ///     for s in series {
+    ///         let peak = s.values().iter().map(|p| p.value()).fold(f64::MIN, f64::max);
+    ///         println!("{:?} peak: {}", s.metric().get("host"), peak);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_metrics(
+        &self,
+        project: impl AsRef<str>,
+        metricstore: impl AsRef<str>,
+        query: impl AsRef<str>,
+    ) -> QueryMetricsRequestBuilder {
+        QueryMetricsRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!(
+                "/prometheus/{}/{}/api/v1",
+                project.as_ref(),
+                metricstore.as_ref()
+            ),
+            handle: self.handle.clone(),
+            query: query.as_ref().to_string(),
+            time: None,
+            range: None,
+            timeout: None,
+        }
+    }
+}
+
+pub struct QueryMetricsRequestBuilder {
+    project: String,
+    path: String,
+    handle: HandleRef,
+    query: String,
+    time: Option<i64>,
+    range: Option<(i64, i64, i64)>,
+    timeout: Option<String>,
+}
+
+request_options!(QueryMetricsRequestBuilder);
+
+impl QueryMetricsRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<QueryMetricsResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Evaluate the query at this unix timestamp in seconds, default is the current time.
+    ///
+    /// Ignored if [`range`](Self::range) is set.
+    pub fn time(mut self, time: i64) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Evaluate the query over a time range instead of at a single point in time.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Start of the range, in unix timestamp, in seconds
+    /// * `end` - End of the range, in unix timestamp, in seconds
+    /// * `step` - Interval between evaluated points, in seconds
+    pub fn range(mut self, start: i64, end: i64, step: i64) -> Self {
+        self.range = Some((start, end, step));
+        self
+    }
+
+    /// Set the evaluation timeout, e.g. `30s`.
+    pub fn timeout(mut self, timeout: impl Into<String>) -> Self {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    fn build(self) -> BuildResult<QueryMetricsRequest> {
+        let mut params = vec![("query".to_string(), self.query)];
+        let path = match self.range {
+            Some((start, end, step)) => {
+                params.push(("start".to_string(), start.to_string()));
+                params.push(("end".to_string(), end.to_string()));
+                params.push(("step".to_string(), step.to_string()));
+                format!("{}/query_range", self.path)
+            }
+            None => {
+                if let Some(time) = self.time {
+                    params.push(("time".to_string(), time.to_string()));
+                }
+                format!("{}/query", self.path)
+            }
+        };
+        if let Some(timeout) = self.timeout {
+            params.push(("timeout".to_string(), timeout));
+        }
+        Ok((
+            self.handle,
+            QueryMetricsRequest {
+                project: self.project,
+                path,
+                params,
+            },
+        ))
+    }
+}
+
+struct QueryMetricsRequest {
+    project: String,
+    path: String,
+    params: Vec<(String, String)>,
+}
+
+impl Request for QueryMetricsRequest {
+    type ResponseBody = QueryMetricsResponse;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        Some(self.params.clone())
+    }
+}
+
+/// Response of a PromQL query
+#[derive(Debug, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct QueryMetricsResponse {
+    /// Query status, `success` or `error`
+    status: String,
+
+    /// Query result
+    data: MetricQueryResult,
+
+    /// Warnings raised while evaluating the query
+    #[serde(default)]
+    warnings: Vec<String>,
+}
+
+impl FromHttpResponse for QueryMetricsResponse {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
+
+/// Result of a PromQL query, depending on the type of the expression.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "resultType", content = "result", rename_all = "lowercase")]
+pub enum MetricQueryResult {
+    /// A set of time series, each with a range of points, returned by range queries
+    Matrix(Vec<MetricSeries>),
+    /// A set of time series, each with a single point, returned by instant queries
+    Vector(Vec<MetricVectorSample>),
+    /// A single numeric point
+    Scalar(MetricPoint),
+    /// A single string value, as `[time, value]`
+    String((f64, String)),
+}
+
+/// A time series with a range of points.
+#[derive(Debug, Clone, PartialEq, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct MetricSeries {
+    /// Labels of the series, including the metric name in `__name__` if kept by the query
+    #[serde(default)]
+    metric: HashMap<String, String>,
+
+    /// Points of the series, in ascending time order
+    #[serde(default)]
+    values: Vec<MetricPoint>,
+}
+
+/// A time series with a single point.
+#[derive(Debug, Clone, PartialEq, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct MetricVectorSample {
+    /// Labels of the series, including the metric name in `__name__` if kept by the query
+    #[serde(default)]
+    metric: HashMap<String, String>,
+
+    /// The point of the series
+    value: MetricPoint,
+}
+
+/// A point of a time series.
+#[derive(Debug, Clone, Copy, PartialEq, CopyGetters, Deserialize)]
+#[getset(get_copy = "pub")]
+#[serde(try_from = "(f64, String)")]
+pub struct MetricPoint {
+    /// Unix timestamp of the point, in seconds
+    time: f64,
+
+    /// Value of the point, `NaN` and infinities are kept as is
+    value: f64,
+}
+
+impl TryFrom<(f64, String)> for MetricPoint {
+    type Error = std::num::ParseFloatError;
+
+    fn try_from((time, value): (f64, String)) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            time,
+            value: value.parse()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_metric_query_results() {
+        let matrix: QueryMetricsResponse = serde_json::from_str(
+            r#"{"status":"success","data":{"resultType":"matrix","result":[
+                {"metric":{"__name__":"up","host":"web-1"},"values":[[1700000000,"1"],[1700000060,"+Inf"]]}
+            ]}}"#,
+        )
+        .unwrap();
+        let MetricQueryResult::Matrix(series) = matrix.data() else {
+            panic!("expected matrix, got {:?}", matrix.data());
+        };
+        assert_eq!(series[0].metric()["host"], "web-1");
+        assert_eq!(series[0].values()[0].time(), 1700000000.0);
+        assert_eq!(series[0].values()[0].value(), 1.0);
+        assert_eq!(series[0].values()[1].value(), f64::INFINITY);
+
+        let vector: QueryMetricsResponse = serde_json::from_str(
+            r#"{"status":"success","data":{"resultType":"vector","result":[
+                {"metric":{"host":"web-1"},"value":[1700000000.5,"0.25"]}
+            ]}}"#,
+        )
+        .unwrap();
+        let MetricQueryResult::Vector(samples) = vector.data() else {
+            panic!("expected vector, got {:?}", vector.data());
+        };
+        assert_eq!(samples[0].value().time(), 1700000000.5);
+        assert_eq!(samples[0].value().value(), 0.25);
+
+        let scalar: QueryMetricsResponse = serde_json::from_str(
+            r#"{"status":"success","data":{"resultType":"scalar","result":[1700000000,"NaN"]}}"#,
+        )
+        .unwrap();
+        let MetricQueryResult::Scalar(point) = scalar.data() else {
+            panic!("expected scalar, got {:?}", scalar.data());
+        };
+        assert!(point.value().is_nan());
+    }
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+/// Schedule of a job, e.g. how often an alert or a scheduled SQL job runs.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_log_rust_sdk::Schedule;
+///
+/// // Run every minute
+/// let schedule = Schedule::fixed_rate("1m");
+///
+/// // Run at 08:00 every day
+/// let schedule = Schedule::cron("0 8 * * *").time_zone("+0800");
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Schedule {
+    /// Schedule type, `FixedRate`, `Cron`, `Hourly`, `Daily`, `Weekly` or `Resident`
+    #[serde(rename = "type")]
+    pub schedule_type: String,
+    /// Interval of `FixedRate` schedules, e.g. `1m`, `1h`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+    /// Cron expression of `Cron` schedules
+    #[serde(rename = "cronExpression", skip_serializing_if = "Option::is_none")]
+    pub cron_expression: Option<String>,
+    /// Delay in seconds of each run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay: Option<i32>,
+    /// Whether to run immediately after the job is created
+    #[serde(rename = "runImmediately", skip_serializing_if = "Option::is_none")]
+    pub run_immediately: Option<bool>,
+    /// Time zone of the cron expression, e.g. `+0800`
+    #[serde(rename = "timeZone", skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+}
+
+impl Schedule {
+    /// Run at a fixed interval, e.g. `1m`, `1h`.
+    pub fn fixed_rate(interval: impl Into<String>) -> Self {
+        Self {
+            schedule_type: "FixedRate".to_string(),
+            interval: Some(interval.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Run at the times given by a cron expression.
+    pub fn cron(cron_expression: impl Into<String>) -> Self {
+        Self {
+            schedule_type: "Cron".to_string(),
+            cron_expression: Some(cron_expression.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the delay in seconds of each run.
+    pub fn delay(mut self, delay: i32) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Set whether to run immediately after the job is created.
+    pub fn run_immediately(mut self, run_immediately: bool) -> Self {
+        self.run_immediately = Some(run_immediately);
+        self
+    }
+
+    /// Set the time zone of the cron expression, e.g. `+0800`.
+    pub fn time_zone(mut self, time_zone: impl Into<String>) -> Self {
+        self.time_zone = Some(time_zone.into());
+        self
+    }
+}
@@ -0,0 +1,392 @@
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+mod create_scheduled_sql;
+pub use create_scheduled_sql::*;
+
+mod update_scheduled_sql;
+pub use update_scheduled_sql::*;
+
+mod delete_scheduled_sql;
+pub use delete_scheduled_sql::*;
+
+mod get_scheduled_sql;
+pub use get_scheduled_sql::*;
+
+mod list_scheduled_sqls;
+pub use list_scheduled_sqls::*;
+
+mod list_scheduled_sql_job_instances;
+pub use list_scheduled_sql_job_instances::*;
+
+mod rerun_scheduled_sql_job_instance;
+pub use rerun_scheduled_sql_job_instance::*;
+
+use super::*;
+
+/// Scheduled SQL job information returned by the server.
+#[derive(Debug, Clone, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct ScheduledSql {
+    /// Job name, unique within the project
+    name: String,
+
+    /// Display name of the job
+    #[serde(rename = "displayName", default)]
+    display_name: String,
+
+    /// Job description
+    #[serde(default)]
+    description: Option<String>,
+
+    /// Job state, `Enabled` or `Disabled`
+    #[serde(default)]
+    state: String,
+
+    /// Job configuration
+    configuration: ScheduledSqlConfiguration,
+
+    /// Schedule of the job
+    schedule: Schedule,
+
+    /// Creation time (Unix timestamp)
+    #[serde(rename = "createTime", default)]
+    create_time: Option<i64>,
+
+    /// Last modification time (Unix timestamp)
+    #[serde(rename = "lastModifiedTime", default)]
+    last_modified_time: Option<i64>,
+}
+
+/// Builder for constructing a scheduled SQL configuration.
+///
+/// # Examples
+///
+/// Roll up access logs into a logstore every 5 minutes:
+///
+/// ```
+/// use aliyun_log_rust_sdk::ScheduledSqlConfiguration;
+///
+/// let configuration = ScheduledSqlConfiguration::builder()
+///     .source_logstore("access-log")
+///     .script("* | select status, count(*) as cnt group by status")
+///     .destination("cn-hangzhou-intranet.log.aliyuncs.com", "my-project", "access-log-5m")
+///     .role_arn("acs:ram::123456:role/aliyunlogetlrole")
+///     .time_window("@m-5m", "@m")
+///     .from_time(1700000000)
+///     .build();
+/// ```
+///
+/// Write the results into a metricstore:
+///
+/// ```
+/// use aliyun_log_rust_sdk::{ScheduledSqlConfiguration, ScheduledSqlDataFormat};
+///
+/// let configuration = ScheduledSqlConfiguration::builder()
+///     .source_logstore("access-log")
+///     .script("* | select host, avg(latency) as latency group by host")
+///     .destination("cn-hangzhou-intranet.log.aliyuncs.com", "my-project", "access-metrics")
+///     .role_arn("acs:ram::123456:role/aliyunlogetlrole")
+///     .data_format(ScheduledSqlDataFormat::LogToMetric)
+///     .metric_keys(&["latency"])
+///     .label_keys(&["host"])
+///     .time_window("@m-1m", "@m")
+///     .from_time(1700000000)
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct ScheduledSqlConfigurationBuilder {
+    configuration: ScheduledSqlConfiguration,
+}
+
+impl ScheduledSqlConfigurationBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the source logstore or metricstore to query (required).
+    pub fn source_logstore(mut self, source_logstore: impl Into<String>) -> Self {
+        self.configuration.source_logstore = source_logstore.into();
+        self
+    }
+
+    /// Set the query to run on each schedule (required).
+    pub fn script(mut self, script: impl Into<String>) -> Self {
+        self.configuration.script = script.into();
+        self
+    }
+
+    /// Set the query type, default [`ScheduledSqlType::SearchQuery`].
+    pub fn sql_type(mut self, sql_type: ScheduledSqlType) -> Self {
+        self.configuration.sql_type = sql_type;
+        self
+    }
+
+    /// Set the SQL resource pool, `standard` or `enhanced`, default `enhanced`.
+    pub fn resource_pool(mut self, resource_pool: impl Into<String>) -> Self {
+        self.configuration.resource_pool = resource_pool.into();
+        self
+    }
+
+    /// Set where the query results are written to (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_endpoint` - Endpoint of the destination project, e.g. `cn-hangzhou-intranet.log.aliyuncs.com`
+    /// * `dest_project` - Destination project
+    /// * `dest_logstore` - Destination logstore or metricstore
+    pub fn destination(
+        mut self,
+        dest_endpoint: impl Into<String>,
+        dest_project: impl Into<String>,
+        dest_logstore: impl Into<String>,
+    ) -> Self {
+        self.configuration.dest_endpoint = dest_endpoint.into();
+        self.configuration.dest_project = dest_project.into();
+        self.configuration.dest_logstore = dest_logstore.into();
+        self
+    }
+
+    /// Set the RAM role used to read the source and, unless
+    /// [`dest_role_arn`](Self::dest_role_arn) is set, to write the destination (required).
+    pub fn role_arn(mut self, role_arn: impl Into<String>) -> Self {
+        self.configuration.role_arn = role_arn.into();
+        self
+    }
+
+    /// Set the RAM role used to write the destination.
+    pub fn dest_role_arn(mut self, dest_role_arn: impl Into<String>) -> Self {
+        self.configuration.dest_role_arn = dest_role_arn.into();
+        self
+    }
+
+    /// Set the time window queried on each run, relative to the scheduled time (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `from_time_expr` - Start of the window, e.g. `@m-5m`
+    /// * `to_time_expr` - End of the window, e.g. `@m`
+    pub fn time_window(
+        mut self,
+        from_time_expr: impl Into<String>,
+        to_time_expr: impl Into<String>,
+    ) -> Self {
+        self.configuration.from_time_expr = from_time_expr.into();
+        self.configuration.to_time_expr = to_time_expr.into();
+        self
+    }
+
+    /// Set the unix timestamp in seconds from which the job is scheduled (required).
+    pub fn from_time(mut self, from_time: i64) -> Self {
+        self.configuration.from_time = from_time;
+        self
+    }
+
+    /// Set the unix timestamp in seconds at which the job stops, default 0 means never.
+    pub fn to_time(mut self, to_time: i64) -> Self {
+        self.configuration.to_time = to_time;
+        self
+    }
+
+    /// Set the maximum run time in seconds of each instance, default 600.
+    pub fn max_run_time_in_seconds(mut self, max_run_time_in_seconds: i32) -> Self {
+        self.configuration.max_run_time_in_seconds = max_run_time_in_seconds;
+        self
+    }
+
+    /// Set the maximum number of retries of each instance, default 20.
+    pub fn max_retries(mut self, max_retries: i32) -> Self {
+        self.configuration.max_retries = max_retries;
+        self
+    }
+
+    /// Set how results are converted, default [`ScheduledSqlDataFormat::LogToLog`].
+    pub fn data_format(mut self, data_format: ScheduledSqlDataFormat) -> Self {
+        self.configuration.data_format = data_format;
+        self
+    }
+
+    /// Set a parameter of the data format, e.g. `timeKey`.
+    pub fn parameter(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.configuration
+            .parameters
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the result columns written as metric values, used by metric data formats.
+    pub fn metric_keys(self, metric_keys: &[&str]) -> Self {
+        let value = serde_json::to_string(metric_keys).unwrap_or_default();
+        self.parameter("metricKeys", value)
+    }
+
+    /// Set the result columns written as metric labels, used by metric data formats.
+    pub fn label_keys(self, label_keys: &[&str]) -> Self {
+        let value = serde_json::to_string(label_keys).unwrap_or_default();
+        self.parameter("labelKeys", value)
+    }
+
+    /// Build the scheduled SQL configuration.
+    pub fn build(self) -> ScheduledSqlConfiguration {
+        self.configuration
+    }
+}
+
+/// Scheduled SQL configuration, defines what to query and where the results go.
+///
+/// Use [`ScheduledSqlConfiguration::builder`] to construct one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScheduledSqlConfiguration {
+    /// Source logstore or metricstore
+    #[serde(rename = "sourceLogstore")]
+    pub source_logstore: String,
+    /// The query to run
+    pub script: String,
+    /// Query type
+    #[serde(rename = "sqlType")]
+    pub sql_type: ScheduledSqlType,
+    /// SQL resource pool, `standard` or `enhanced`
+    #[serde(rename = "resourcePool")]
+    pub resource_pool: String,
+    /// Endpoint of the destination project
+    #[serde(rename = "destEndpoint")]
+    pub dest_endpoint: String,
+    /// Destination project
+    #[serde(rename = "destProject")]
+    pub dest_project: String,
+    /// Destination logstore or metricstore
+    #[serde(rename = "destLogstore")]
+    pub dest_logstore: String,
+    /// RAM role used to read the source
+    #[serde(rename = "roleArn")]
+    pub role_arn: String,
+    /// RAM role used to write the destination
+    #[serde(rename = "destRoleArn")]
+    pub dest_role_arn: String,
+    /// Start of the query window relative to the scheduled time
+    #[serde(rename = "fromTimeExpr")]
+    pub from_time_expr: String,
+    /// End of the query window relative to the scheduled time
+    #[serde(rename = "toTimeExpr")]
+    pub to_time_expr: String,
+    /// Unix timestamp in seconds from which the job is scheduled
+    #[serde(rename = "fromTime")]
+    pub from_time: i64,
+    /// Unix timestamp in seconds at which the job stops, 0 means never
+    #[serde(rename = "toTime")]
+    pub to_time: i64,
+    /// Maximum run time in seconds of each instance
+    #[serde(rename = "maxRunTimeInSeconds")]
+    pub max_run_time_in_seconds: i32,
+    /// Maximum number of retries of each instance
+    #[serde(rename = "maxRetries")]
+    pub max_retries: i32,
+    /// How the results are converted before written
+    #[serde(rename = "dataFormat")]
+    pub data_format: ScheduledSqlDataFormat,
+    /// Parameters of the data format
+    pub parameters: HashMap<String, String>,
+}
+
+impl Default for ScheduledSqlConfiguration {
+    fn default() -> Self {
+        Self {
+            source_logstore: String::new(),
+            script: String::new(),
+            sql_type: ScheduledSqlType::default(),
+            resource_pool: "enhanced".to_string(),
+            dest_endpoint: String::new(),
+            dest_project: String::new(),
+            dest_logstore: String::new(),
+            role_arn: String::new(),
+            dest_role_arn: String::new(),
+            from_time_expr: String::new(),
+            to_time_expr: String::new(),
+            from_time: 0,
+            to_time: 0,
+            max_run_time_in_seconds: 600,
+            max_retries: 20,
+            data_format: ScheduledSqlDataFormat::default(),
+            parameters: HashMap::new(),
+        }
+    }
+}
+
+impl ScheduledSqlConfiguration {
+    /// Create a new ScheduledSqlConfigurationBuilder for fluent configuration.
+    pub fn builder() -> ScheduledSqlConfigurationBuilder {
+        ScheduledSqlConfigurationBuilder::new()
+    }
+}
+
+/// Query type of a scheduled SQL job.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScheduledSqlType {
+    /// Search statement followed by an analytic statement, e.g. `* | select ...`
+    #[default]
+    #[serde(rename = "searchQuery")]
+    SearchQuery,
+    /// Standard SQL statement
+    #[serde(rename = "standardSQL")]
+    StandardSql,
+}
+
+/// How the results of a scheduled SQL job are converted before written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScheduledSqlDataFormat {
+    /// From a logstore to a logstore
+    #[default]
+    #[serde(rename = "log2log")]
+    LogToLog,
+    /// From a logstore to a metricstore
+    #[serde(rename = "log2metric")]
+    LogToMetric,
+    /// From a metricstore to a metricstore
+    #[serde(rename = "metric2metric")]
+    MetricToMetric,
+}
+
+/// A run of a scheduled SQL job.
+#[derive(Debug, Clone, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct ScheduledSqlJobInstance {
+    /// Instance ID
+    #[serde(rename = "instanceId")]
+    instance_id: String,
+
+    /// Name of the job
+    #[serde(rename = "jobName", default)]
+    job_name: String,
+
+    /// Instance state, `RUNNING`, `SUCCEEDED` or `FAILED`
+    #[serde(default)]
+    state: String,
+
+    /// The scheduled time of the instance (Unix timestamp)
+    #[serde(rename = "scheduleTime", default)]
+    schedule_time: i64,
+
+    /// Creation time (Unix timestamp in milliseconds)
+    #[serde(rename = "createTimeInMillis", default)]
+    create_time_in_millis: i64,
+
+    /// Last update time (Unix timestamp)
+    #[serde(rename = "updateTime", default)]
+    update_time: i64,
+
+    /// Error code if the instance failed
+    #[serde(rename = "errorCode", default)]
+    error_code: Option<String>,
+
+    /// Error message if the instance failed
+    #[serde(rename = "errorMessage", default)]
+    error_message: Option<String>,
+
+    /// Execution summary
+    #[serde(default)]
+    summary: Option<String>,
+}
+
+pub(crate) const JOB_TYPE_SCHEDULED_SQL: &str = "ScheduledSQL";
@@ -0,0 +1,179 @@
+use super::*;
+use crate::RequestErrorKind;
+
+impl crate::client::Client {
+    /// Create a new scheduled SQL job in a project.
+    ///
+    /// A scheduled SQL job runs a query over a source logstore on a schedule and writes
+    /// the results into a destination logstore or metricstore.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `job_name` - The name of the job, unique within the project
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::{Schedule, ScheduledSqlConfiguration};
+    ///
+    /// let configuration = ScheduledSqlConfiguration::builder()
+    ///     .source_logstore("access-log")
+    ///     .script("* | select status, count(*) as cnt group by status")
+    ///     .destination("cn-hangzhou-intranet.log.aliyuncs.com", "my-project", "access-log-5m")
+    ///     .role_arn("acs:ram::123456:role/aliyunlogetlrole")
+    ///     .time_window("@m-5m", "@m")
+    ///     .from_time(1700000000)
+    ///     .build();
+    ///
+    /// client.create_scheduled_sql("my-project", "access-log-rollup")
+    ///     .display_name("Access log rollup")
+    ///     .configuration(configuration)
+    ///     .schedule(Schedule::fixed_rate("5m"))
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_scheduled_sql(
+        &self,
+        project: impl AsRef<str>,
+        job_name: impl AsRef<str>,
+    ) -> CreateScheduledSqlRequestBuilder {
+        CreateScheduledSqlRequestBuilder {
+            project: project.as_ref().to_string(),
+            handle: self.handle.clone(),
+            job_name: job_name.as_ref().to_string(),
+            display_name: None,
+            description: None,
+            configuration: None,
+            schedule: None,
+        }
+    }
+}
+
+pub struct CreateScheduledSqlRequestBuilder {
+    project: String,
+    handle: HandleRef,
+    job_name: String,
+    display_name: Option<String>,
+    description: Option<String>,
+    configuration: Option<ScheduledSqlConfiguration>,
+    schedule: Option<Schedule>,
+}
+
+request_options!(CreateScheduledSqlRequestBuilder);
+
+impl CreateScheduledSqlRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Set the display name of the job (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `display_name` - Display name of the job
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// Set the description of the job (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `description` - Description of the job
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the job configuration (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - Source, query and destination of the job
+    pub fn configuration(mut self, configuration: ScheduledSqlConfiguration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+
+    /// Set how often the job runs (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule` - Schedule of the job, e.g. `Schedule::fixed_rate("5m")`
+    pub fn schedule(mut self, schedule: Schedule) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
+    fn build(self) -> BuildResult<CreateScheduledSqlRequest> {
+        check_required!(
+            ("display_name", self.display_name),
+            ("configuration", self.configuration),
+            ("schedule", self.schedule)
+        );
+        Ok((
+            self.handle,
+            CreateScheduledSqlRequest {
+                project: self.project,
+                name: self.job_name,
+                display_name: self.display_name.unwrap(),
+                description: self.description,
+                job_type: JOB_TYPE_SCHEDULED_SQL,
+                state: "Enabled",
+                configuration: self.configuration.unwrap(),
+                schedule: self.schedule.unwrap(),
+            },
+        ))
+    }
+}
+
+#[derive(Serialize)]
+struct CreateScheduledSqlRequest {
+    #[serde(skip_serializing)]
+    project: String,
+
+    name: String,
+
+    #[serde(rename = "displayName")]
+    display_name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    #[serde(rename = "type")]
+    job_type: &'static str,
+
+    state: &'static str,
+
+    configuration: ScheduledSqlConfiguration,
+
+    schedule: Schedule,
+}
+
+impl Request for CreateScheduledSqlRequest {
+    const HTTP_METHOD: http::Method = http::Method::POST;
+    const CONTENT_TYPE: Option<http::HeaderValue> = Some(LOG_JSON);
+    type ResponseBody = ();
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        "/jobs"
+    }
+
+    fn body(&self) -> crate::Result<Option<bytes::Bytes>, RequestError> {
+        let json = serde_json::to_string(&self).map_err(RequestErrorKind::JsonEncode)?;
+        Ok(Some(bytes::Bytes::from(json)))
+    }
+}
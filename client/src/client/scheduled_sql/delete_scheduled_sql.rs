@@ -0,0 +1,78 @@
+use super::*;
+
+impl crate::client::Client {
+    /// Delete a scheduled SQL job.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `job_name` - The name of the job to delete
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// client.delete_scheduled_sql("my-project", "access-log-rollup")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_scheduled_sql(
+        &self,
+        project: impl AsRef<str>,
+        job_name: impl AsRef<str>,
+    ) -> DeleteScheduledSqlRequestBuilder {
+        DeleteScheduledSqlRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/jobs/{}", job_name.as_ref()),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+pub struct DeleteScheduledSqlRequestBuilder {
+    handle: HandleRef,
+    project: String,
+    path: String,
+}
+
+request_options!(DeleteScheduledSqlRequestBuilder);
+
+impl DeleteScheduledSqlRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<DeleteScheduledSqlRequest> {
+        Ok((
+            self.handle,
+            DeleteScheduledSqlRequest {
+                project: self.project,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+struct DeleteScheduledSqlRequest {
+    project: String,
+    path: String,
+}
+
+impl Request for DeleteScheduledSqlRequest {
+    type ResponseBody = ();
+    const HTTP_METHOD: http::Method = http::Method::DELETE;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::ResponseResult;
+
+impl crate::client::Client {
+    /// Get a scheduled SQL job.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `job_name` - The name of the job to get
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let resp = client.get_scheduled_sql("my-project", "access-log-rollup")
+    ///     .send()
+    ///     .await?;
+    /// let job = resp.get_body();
+    /// println!(
+    ///     "{} writes into {}/{}",
+    ///     job.display_name(),
+    ///     job.configuration().dest_project,
+    ///     job.configuration().dest_logstore
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_scheduled_sql(
+        &self,
+        project: impl AsRef<str>,
+        job_name: impl AsRef<str>,
+    ) -> GetScheduledSqlRequestBuilder {
+        GetScheduledSqlRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/jobs/{}", job_name.as_ref()),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+pub struct GetScheduledSqlRequestBuilder {
+    handle: HandleRef,
+    project: String,
+    path: String,
+}
+
+request_options!(GetScheduledSqlRequestBuilder);
+
+impl GetScheduledSqlRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<ScheduledSql> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<GetScheduledSqlRequest> {
+        Ok((
+            self.handle,
+            GetScheduledSqlRequest {
+                project: self.project,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+struct GetScheduledSqlRequest {
+    project: String,
+    path: String,
+}
+
+impl Request for GetScheduledSqlRequest {
+    type ResponseBody = ScheduledSql;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl FromHttpResponse for ScheduledSql {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
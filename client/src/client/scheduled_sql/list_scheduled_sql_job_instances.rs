@@ -0,0 +1,165 @@
+use super::*;
+use crate::ResponseResult;
+
+impl crate::client::Client {
+    /// List the instances of a scheduled SQL job, i.e. the runs scheduled in a time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `job_name` - The name of the scheduled SQL job
+    /// * `offset` - The offset for pagination (starting from 0)
+    /// * `size` - The number of instances to return (page size)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let now = chrono::Utc::now().timestamp();
+    /// let resp = client.list_scheduled_sql_job_instances("my-project", "access-log-rollup", 0, 100)
+    ///     .from(now - 86400)
+    ///     .to(now)
+    ///     .state("FAILED")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for instance in resp.get_body().instances() {
+    ///     println!("{} failed: {:?}", instance.instance_id(), instance.error_message());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_scheduled_sql_job_instances(
+        &self,
+        project: impl AsRef<str>,
+        job_name: impl AsRef<str>,
+        offset: i32,
+        size: i32,
+    ) -> ListScheduledSqlJobInstancesRequestBuilder {
+        ListScheduledSqlJobInstancesRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/jobs/{}/jobinstances", job_name.as_ref()),
+            handle: self.handle.clone(),
+            offset,
+            size,
+            from: None,
+            to: None,
+            state: None,
+        }
+    }
+}
+
+pub struct ListScheduledSqlJobInstancesRequestBuilder {
+    project: String,
+    path: String,
+    handle: HandleRef,
+    offset: i32,
+    size: i32,
+    from: Option<i64>,
+    to: Option<i64>,
+    state: Option<String>,
+}
+
+request_options!(ListScheduledSqlJobInstancesRequestBuilder);
+
+impl ListScheduledSqlJobInstancesRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<ListScheduledSqlJobInstancesResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Required, the start of the scheduled time range, in unix timestamp, in seconds.
+    pub fn from(mut self, from: i64) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Required, the end of the scheduled time range, in unix timestamp, in seconds.
+    pub fn to(mut self, to: i64) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Optional, only list instances in the given state, `RUNNING`, `SUCCEEDED` or `FAILED`.
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    fn build(self) -> BuildResult<ListScheduledSqlJobInstancesRequest> {
+        check_required!(("from", self.from), ("to", self.to));
+        Ok((
+            self.handle,
+            ListScheduledSqlJobInstancesRequest {
+                project: self.project,
+                path: self.path,
+                offset: self.offset,
+                size: self.size,
+                from: self.from.unwrap(),
+                to: self.to.unwrap(),
+                state: self.state,
+            },
+        ))
+    }
+}
+
+struct ListScheduledSqlJobInstancesRequest {
+    project: String,
+    path: String,
+    offset: i32,
+    size: i32,
+    from: i64,
+    to: i64,
+    state: Option<String>,
+}
+
+impl Request for ListScheduledSqlJobInstancesRequest {
+    type ResponseBody = ListScheduledSqlJobInstancesResponse;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        let mut params = vec![
+            ("jobType".to_string(), JOB_TYPE_SCHEDULED_SQL.to_string()),
+            ("offset".to_string(), self.offset.to_string()),
+            ("size".to_string(), self.size.to_string()),
+            ("start".to_string(), self.from.to_string()),
+            ("end".to_string(), self.to.to_string()),
+        ];
+        if let Some(ref state) = self.state {
+            params.push(("state".to_string(), state.clone()));
+        }
+        Some(params)
+    }
+}
+
+/// Response containing a list of scheduled SQL job instances
+#[derive(Debug, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct ListScheduledSqlJobInstancesResponse {
+    /// Number of instances returned in this response
+    count: i32,
+
+    /// Total number of instances in the time range
+    total: i32,
+
+    /// List of job instances
+    #[serde(rename = "results", default)]
+    instances: Vec<ScheduledSqlJobInstance>,
+}
+
+impl FromHttpResponse for ListScheduledSqlJobInstancesResponse {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
@@ -0,0 +1,119 @@
+use super::*;
+use crate::ResponseResult;
+
+impl crate::client::Client {
+    /// List scheduled SQL jobs in a project with pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `offset` - The offset for pagination (starting from 0)
+    /// * `size` - The number of jobs to return (page size)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let resp = client.list_scheduled_sqls("my-project", 0, 100)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// println!("Total scheduled SQL jobs: {}", resp.get_body().total());
+    /// for job in resp.get_body().scheduled_sqls() {
+    ///     println!("Job: {}, source: {}", job.name(), job.configuration().source_logstore);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_scheduled_sqls(
+        &self,
+        project: impl AsRef<str>,
+        offset: i32,
+        size: i32,
+    ) -> ListScheduledSqlsRequestBuilder {
+        ListScheduledSqlsRequestBuilder {
+            project: project.as_ref().to_string(),
+            handle: self.handle.clone(),
+            offset,
+            size,
+        }
+    }
+}
+
+pub struct ListScheduledSqlsRequestBuilder {
+    project: String,
+    handle: HandleRef,
+    offset: i32,
+    size: i32,
+}
+
+request_options!(ListScheduledSqlsRequestBuilder);
+
+impl ListScheduledSqlsRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<ListScheduledSqlsResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<ListScheduledSqlsRequest> {
+        Ok((
+            self.handle,
+            ListScheduledSqlsRequest {
+                project: self.project,
+                offset: self.offset,
+                size: self.size,
+            },
+        ))
+    }
+}
+
+struct ListScheduledSqlsRequest {
+    project: String,
+    offset: i32,
+    size: i32,
+}
+
+impl Request for ListScheduledSqlsRequest {
+    type ResponseBody = ListScheduledSqlsResponse;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        "/jobs"
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        Some(vec![
+            ("jobType".to_string(), JOB_TYPE_SCHEDULED_SQL.to_string()),
+            ("offset".to_string(), self.offset.to_string()),
+            ("size".to_string(), self.size.to_string()),
+        ])
+    }
+}
+
+/// Response containing a list of scheduled SQL jobs
+#[derive(Debug, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct ListScheduledSqlsResponse {
+    /// Number of jobs returned in this response
+    count: i32,
+
+    /// Total number of jobs in the project
+    total: i32,
+
+    /// List of scheduled SQL jobs
+    #[serde(rename = "results", default)]
+    scheduled_sqls: Vec<ScheduledSql>,
+}
+
+impl FromHttpResponse for ListScheduledSqlsResponse {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
@@ -0,0 +1,98 @@
+use super::*;
+
+impl crate::client::Client {
+    /// Re-run an instance of a scheduled SQL job, e.g. one that failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `job_name` - The name of the scheduled SQL job
+    /// * `instance_id` - The ID of the instance to re-run
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let now = chrono::Utc::now().timestamp();
+    /// let resp = client.list_scheduled_sql_job_instances("my-project", "access-log-rollup", 0, 100)
+    ///     .from(now - 86400)
+    ///     .to(now)
+    ///     .state("FAILED")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for instance in resp.get_body().instances() {
+    ///     client.rerun_scheduled_sql_job_instance("my-project", "access-log-rollup", instance.instance_id())
+    ///         .send()
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rerun_scheduled_sql_job_instance(
+        &self,
+        project: impl AsRef<str>,
+        job_name: impl AsRef<str>,
+        instance_id: impl AsRef<str>,
+    ) -> RerunScheduledSqlJobInstanceRequestBuilder {
+        RerunScheduledSqlJobInstanceRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!(
+                "/jobs/{}/jobinstances/{}",
+                job_name.as_ref(),
+                instance_id.as_ref()
+            ),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+pub struct RerunScheduledSqlJobInstanceRequestBuilder {
+    handle: HandleRef,
+    project: String,
+    path: String,
+}
+
+request_options!(RerunScheduledSqlJobInstanceRequestBuilder);
+
+impl RerunScheduledSqlJobInstanceRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<RerunScheduledSqlJobInstanceRequest> {
+        Ok((
+            self.handle,
+            RerunScheduledSqlJobInstanceRequest {
+                project: self.project,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+struct RerunScheduledSqlJobInstanceRequest {
+    project: String,
+    path: String,
+}
+
+impl Request for RerunScheduledSqlJobInstanceRequest {
+    type ResponseBody = ();
+    const HTTP_METHOD: http::Method = http::Method::PUT;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        Some(vec![("state".to_string(), "RUNNING".to_string())])
+    }
+}
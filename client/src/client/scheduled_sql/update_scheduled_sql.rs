@@ -0,0 +1,176 @@
+use super::*;
+use crate::RequestErrorKind;
+
+impl crate::client::Client {
+    /// Update an existing scheduled SQL job.
+    ///
+    /// The whole job definition is replaced, so all required fields must be set again.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `job_name` - The name of the job to update
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let resp = client.get_scheduled_sql("my-project", "access-log-rollup").send().await?;
+    /// let job = resp.take_body();
+    ///
+    /// let mut configuration = job.configuration().clone();
+    /// configuration.script = "* | select status, count(*) as cnt, avg(latency) as latency group by status".to_string();
+    ///
+    /// client.update_scheduled_sql("my-project", "access-log-rollup")
+    ///     .display_name(job.display_name())
+    ///     .configuration(configuration)
+    ///     .schedule(job.schedule().clone())
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_scheduled_sql(
+        &self,
+        project: impl AsRef<str>,
+        job_name: impl AsRef<str>,
+    ) -> UpdateScheduledSqlRequestBuilder {
+        UpdateScheduledSqlRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/jobs/{}", job_name.as_ref()),
+            handle: self.handle.clone(),
+            job_name: job_name.as_ref().to_string(),
+            display_name: None,
+            description: None,
+            configuration: None,
+            schedule: None,
+        }
+    }
+}
+
+pub struct UpdateScheduledSqlRequestBuilder {
+    project: String,
+    path: String,
+    handle: HandleRef,
+    job_name: String,
+    display_name: Option<String>,
+    description: Option<String>,
+    configuration: Option<ScheduledSqlConfiguration>,
+    schedule: Option<Schedule>,
+}
+
+request_options!(UpdateScheduledSqlRequestBuilder);
+
+impl UpdateScheduledSqlRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Set the display name of the job (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `display_name` - Display name of the job
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// Set the description of the job (optional).
+    ///
+    /// # Arguments
+    ///
+    /// * `description` - Description of the job
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the job configuration (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - Source, query and destination of the job
+    pub fn configuration(mut self, configuration: ScheduledSqlConfiguration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+
+    /// Set how often the job runs (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule` - Schedule of the job, e.g. `Schedule::fixed_rate("5m")`
+    pub fn schedule(mut self, schedule: Schedule) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
+    fn build(self) -> BuildResult<UpdateScheduledSqlRequest> {
+        check_required!(
+            ("display_name", self.display_name),
+            ("configuration", self.configuration),
+            ("schedule", self.schedule)
+        );
+        Ok((
+            self.handle,
+            UpdateScheduledSqlRequest {
+                project: self.project,
+                path: self.path,
+                name: self.job_name,
+                display_name: self.display_name.unwrap(),
+                description: self.description,
+                job_type: JOB_TYPE_SCHEDULED_SQL,
+                configuration: self.configuration.unwrap(),
+                schedule: self.schedule.unwrap(),
+            },
+        ))
+    }
+}
+
+#[derive(Serialize)]
+struct UpdateScheduledSqlRequest {
+    #[serde(skip_serializing)]
+    project: String,
+
+    #[serde(skip_serializing)]
+    path: String,
+
+    name: String,
+
+    #[serde(rename = "displayName")]
+    display_name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    #[serde(rename = "type")]
+    job_type: &'static str,
+
+    configuration: ScheduledSqlConfiguration,
+
+    schedule: Schedule,
+}
+
+impl Request for UpdateScheduledSqlRequest {
+    const HTTP_METHOD: http::Method = http::Method::PUT;
+    const CONTENT_TYPE: Option<http::HeaderValue> = Some(LOG_JSON);
+    type ResponseBody = ();
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn body(&self) -> crate::Result<Option<bytes::Bytes>, RequestError> {
+        let json = serde_json::to_string(&self).map_err(RequestErrorKind::JsonEncode)?;
+        Ok(Some(bytes::Bytes::from(json)))
+    }
+}
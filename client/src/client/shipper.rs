@@ -0,0 +1,397 @@
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+mod create_shipper;
+pub use create_shipper::*;
+
+mod update_shipper;
+pub use update_shipper::*;
+
+mod delete_shipper;
+pub use delete_shipper::*;
+
+mod get_shipper;
+pub use get_shipper::*;
+
+mod list_shippers;
+pub use list_shippers::*;
+
+mod list_shipper_tasks;
+pub use list_shipper_tasks::*;
+
+mod retry_shipper_tasks;
+pub use retry_shipper_tasks::*;
+
+use super::*;
+
+/// Shipper information returned by the server.
+#[derive(Debug, Clone, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct Shipper {
+    /// Shipper name, unique within the logstore
+    #[serde(rename = "shipperName")]
+    shipper_name: String,
+
+    /// Where the data is shipped to
+    #[serde(flatten)]
+    target: ShipperTarget,
+
+    /// Creation time (Unix timestamp)
+    #[serde(rename = "createTime", default)]
+    create_time: Option<i64>,
+
+    /// Last modification time (Unix timestamp)
+    #[serde(rename = "lastModifyTime", default)]
+    last_modify_time: Option<i64>,
+}
+
+/// Where a shipper ships the logstore data to.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_log_rust_sdk::{MaxComputeShipperConfiguration, OssShipperConfiguration, ShipperTarget};
+///
+/// let oss: ShipperTarget = OssShipperConfiguration::new(
+///     "my-bucket",
+///     "archive/access-log",
+///     "acs:ram::123456:role/aliyunlogdefaultrole",
+/// )
+/// .into();
+///
+/// let max_compute: ShipperTarget = MaxComputeShipperConfiguration::new(
+///     "http://service.cn-hangzhou.maxcompute.aliyun-inc.com/api",
+///     "my_odps_project",
+///     "access_log",
+///     &["__time__", "status", "latency"],
+/// )
+/// .partition_columns(&["__partition_time__"], "%Y_%m_%d")
+/// .into();
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "targetType", content = "targetConfiguration")]
+pub enum ShipperTarget {
+    /// Ship to an OSS bucket
+    #[serde(rename = "oss")]
+    Oss(OssShipperConfiguration),
+    /// Ship to a MaxCompute table
+    #[serde(rename = "odps")]
+    MaxCompute(MaxComputeShipperConfiguration),
+}
+
+impl From<OssShipperConfiguration> for ShipperTarget {
+    fn from(configuration: OssShipperConfiguration) -> Self {
+        ShipperTarget::Oss(configuration)
+    }
+}
+
+impl From<MaxComputeShipperConfiguration> for ShipperTarget {
+    fn from(configuration: MaxComputeShipperConfiguration) -> Self {
+        ShipperTarget::MaxCompute(configuration)
+    }
+}
+
+/// Configuration of a shipper writing into an OSS bucket.
+///
+/// # Examples
+///
+/// Ship logs as snappy compressed Parquet files every 5 minutes:
+///
+/// ```
+/// use aliyun_log_rust_sdk::{OssShipperConfiguration, OssStorageFormat, ShipperCompressType};
+///
+/// let configuration = OssShipperConfiguration::new(
+///     "my-bucket",
+///     "archive/access-log",
+///     "acs:ram::123456:role/aliyunlogdefaultrole",
+/// )
+/// .storage(OssStorageFormat::parquet(&[("status", "int64"), ("request_uri", "string")]))
+/// .compress_type(ShipperCompressType::Snappy)
+/// .buffer_interval(300);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OssShipperConfiguration {
+    /// OSS bucket
+    #[serde(rename = "ossBucket")]
+    pub oss_bucket: String,
+    /// Prefix of the OSS objects
+    #[serde(rename = "ossPrefix", default)]
+    pub oss_prefix: String,
+    /// RAM role used to write the bucket
+    #[serde(rename = "roleArn", default)]
+    pub role_arn: String,
+    /// Maximum number of seconds data is buffered before written
+    #[serde(rename = "bufferInterval")]
+    pub buffer_interval: i32,
+    /// Maximum number of MB buffered before written
+    #[serde(rename = "bufferSize")]
+    pub buffer_size: i32,
+    /// Compression of the OSS objects
+    #[serde(rename = "compressType", default)]
+    pub compress_type: ShipperCompressType,
+    /// Time-based partition format of the object path, e.g. `%Y/%m/%d/%H/%M`
+    #[serde(rename = "pathFormat")]
+    pub path_format: String,
+    /// Format of the OSS objects
+    pub storage: OssStorageFormat,
+}
+
+impl OssShipperConfiguration {
+    /// Create a configuration shipping JSON objects into `oss_bucket`/`oss_prefix`.
+    ///
+    /// # Arguments
+    ///
+    /// * `oss_bucket` - OSS bucket
+    /// * `oss_prefix` - Prefix of the OSS objects
+    /// * `role_arn` - RAM role used to write the bucket
+    pub fn new(
+        oss_bucket: impl Into<String>,
+        oss_prefix: impl Into<String>,
+        role_arn: impl Into<String>,
+    ) -> Self {
+        Self {
+            oss_bucket: oss_bucket.into(),
+            oss_prefix: oss_prefix.into(),
+            role_arn: role_arn.into(),
+            buffer_interval: 300,
+            buffer_size: 256,
+            compress_type: ShipperCompressType::default(),
+            path_format: "%Y/%m/%d/%H/%M".to_string(),
+            storage: OssStorageFormat::json(),
+        }
+    }
+
+    /// Set the maximum number of seconds data is buffered before written, 300 to 900, default 300.
+    pub fn buffer_interval(mut self, buffer_interval: i32) -> Self {
+        self.buffer_interval = buffer_interval;
+        self
+    }
+
+    /// Set the maximum number of MB buffered before written, 5 to 256, default 256.
+    pub fn buffer_size(mut self, buffer_size: i32) -> Self {
+        self.buffer_size = buffer_size;
+        self
+    }
+
+    /// Set the compression of the OSS objects, default [`ShipperCompressType::None`].
+    pub fn compress_type(mut self, compress_type: ShipperCompressType) -> Self {
+        self.compress_type = compress_type;
+        self
+    }
+
+    /// Set the time-based partition format of the object path, default `%Y/%m/%d/%H/%M`.
+    pub fn path_format(mut self, path_format: impl Into<String>) -> Self {
+        self.path_format = path_format.into();
+        self
+    }
+
+    /// Set the format of the OSS objects, default JSON.
+    pub fn storage(mut self, storage: OssStorageFormat) -> Self {
+        self.storage = storage;
+        self
+    }
+}
+
+/// Compression of the objects written by an OSS shipper.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShipperCompressType {
+    /// No compression
+    #[default]
+    None,
+    /// Snappy compression
+    Snappy,
+    /// Gzip compression
+    Gzip,
+    /// Zstd compression
+    Zstd,
+}
+
+/// Format of the objects written by an OSS shipper.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "format", content = "detail", rename_all = "lowercase")]
+pub enum OssStorageFormat {
+    /// One JSON object per log
+    Json(OssJsonDetail),
+    /// CSV with the given columns
+    Csv(OssCsvDetail),
+    /// Parquet with the given typed columns
+    Parquet(OssParquetDetail),
+}
+
+impl OssStorageFormat {
+    /// JSON objects without log tags.
+    pub fn json() -> Self {
+        OssStorageFormat::Json(OssJsonDetail { enable_tag: false })
+    }
+
+    /// CSV with the given columns, comma separated, with a header line.
+    pub fn csv(columns: &[&str]) -> Self {
+        OssStorageFormat::Csv(OssCsvDetail {
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            delimiter: ",".to_string(),
+            quote: "\"".to_string(),
+            null_identifier: String::new(),
+            line_feed: "\n".to_string(),
+            header: true,
+        })
+    }
+
+    /// Parquet with the given `(name, type)` columns, e.g. `("status", "int64")`.
+    ///
+    /// Supported types are `string`, `boolean`, `int32`, `int64`, `float` and `double`.
+    pub fn parquet(columns: &[(&str, &str)]) -> Self {
+        OssStorageFormat::Parquet(OssParquetDetail {
+            columns: columns
+                .iter()
+                .map(|(name, column_type)| OssParquetColumn {
+                    name: name.to_string(),
+                    column_type: column_type.to_string(),
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Details of the JSON storage format.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OssJsonDetail {
+    /// Whether log tags are written
+    #[serde(rename = "enableTag", default)]
+    pub enable_tag: bool,
+}
+
+/// Details of the CSV storage format.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OssCsvDetail {
+    /// Log fields written as columns, in order
+    pub columns: Vec<String>,
+    /// Column delimiter
+    pub delimiter: String,
+    /// Quote character
+    pub quote: String,
+    /// Value written for missing fields
+    #[serde(rename = "nullIdentifier")]
+    pub null_identifier: String,
+    /// Line separator
+    #[serde(rename = "lineFeed")]
+    pub line_feed: String,
+    /// Whether a header line is written
+    pub header: bool,
+}
+
+/// Details of the Parquet storage format.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OssParquetDetail {
+    /// Log fields written as columns
+    #[serde(default)]
+    pub columns: Vec<OssParquetColumn>,
+}
+
+/// A typed column of the Parquet storage format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OssParquetColumn {
+    /// Log field name
+    pub name: String,
+    /// Column type, e.g. `string` or `int64`
+    #[serde(rename = "type")]
+    pub column_type: String,
+}
+
+/// Configuration of a shipper writing into a MaxCompute table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaxComputeShipperConfiguration {
+    /// MaxCompute endpoint
+    #[serde(rename = "odpsEndpoint")]
+    pub odps_endpoint: String,
+    /// MaxCompute project
+    #[serde(rename = "odpsProject")]
+    pub odps_project: String,
+    /// MaxCompute table
+    #[serde(rename = "odpsTable")]
+    pub odps_table: String,
+    /// Log fields written as table columns, in order
+    pub fields: Vec<String>,
+    /// Log fields written as partition columns, in order
+    #[serde(rename = "partitionColumn", default)]
+    pub partition_column: Vec<String>,
+    /// Time format of the `__partition_time__` partition column, e.g. `%Y_%m_%d`
+    #[serde(rename = "partitionTimeFormat", default)]
+    pub partition_time_format: String,
+    /// Number of seconds data is buffered before written
+    #[serde(rename = "bufferInterval")]
+    pub buffer_interval: i32,
+}
+
+impl MaxComputeShipperConfiguration {
+    /// Create a configuration writing `fields` into `odps_project`.`odps_table`.
+    ///
+    /// # Arguments
+    ///
+    /// * `odps_endpoint` - MaxCompute endpoint
+    /// * `odps_project` - MaxCompute project
+    /// * `odps_table` - MaxCompute table
+    /// * `fields` - Log fields written as table columns, in order
+    pub fn new(
+        odps_endpoint: impl Into<String>,
+        odps_project: impl Into<String>,
+        odps_table: impl Into<String>,
+        fields: &[&str],
+    ) -> Self {
+        Self {
+            odps_endpoint: odps_endpoint.into(),
+            odps_project: odps_project.into(),
+            odps_table: odps_table.into(),
+            fields: fields.iter().map(|f| f.to_string()).collect(),
+            partition_column: Vec::new(),
+            partition_time_format: String::new(),
+            buffer_interval: 1800,
+        }
+    }
+
+    /// Set the partition columns and the time format of `__partition_time__`.
+    pub fn partition_columns(
+        mut self,
+        partition_column: &[&str],
+        partition_time_format: impl Into<String>,
+    ) -> Self {
+        self.partition_column = partition_column.iter().map(|c| c.to_string()).collect();
+        self.partition_time_format = partition_time_format.into();
+        self
+    }
+
+    /// Set the number of seconds data is buffered before written, 1800 to 3600, default 1800.
+    pub fn buffer_interval(mut self, buffer_interval: i32) -> Self {
+        self.buffer_interval = buffer_interval;
+        self
+    }
+}
+
+/// A run of a shipper, writing one batch of data to the target.
+#[derive(Debug, Clone, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct ShipperTask {
+    /// Task ID
+    id: String,
+
+    /// Task status, `running`, `success` or `fail`
+    #[serde(rename = "taskStatus", default)]
+    task_status: String,
+
+    /// Error message if the task failed
+    #[serde(rename = "taskMessage", default)]
+    task_message: String,
+
+    /// Creation time (Unix timestamp)
+    #[serde(rename = "taskCreateTime", default)]
+    task_create_time: i64,
+
+    /// Receive time of the last log shipped by the task (Unix timestamp)
+    #[serde(rename = "taskLastDataReceiveTime", default)]
+    task_last_data_receive_time: i64,
+
+    /// Finish time (Unix timestamp)
+    #[serde(rename = "taskFinishTime", default)]
+    task_finish_time: i64,
+}
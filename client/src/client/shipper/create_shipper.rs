@@ -0,0 +1,115 @@
+use super::*;
+use crate::RequestErrorKind;
+
+impl crate::client::Client {
+    /// Create a shipper exporting the data of a logstore to OSS or MaxCompute.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `logstore` - The name of the logstore whose data is shipped
+    /// * `shipper_name` - The name of the shipper, unique within the logstore
+    /// * `target` - Where the data is shipped to
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::{OssShipperConfiguration, OssStorageFormat, ShipperCompressType};
+    ///
+    /// let target = OssShipperConfiguration::new(
+    ///     "my-bucket",
+    ///     "archive/access-log",
+    ///     "acs:ram::123456:role/aliyunlogdefaultrole",
+    /// )
+    /// .storage(OssStorageFormat::csv(&["__time__", "status", "request_uri"]))
+    /// .compress_type(ShipperCompressType::Gzip);
+    ///
+    /// client.create_shipper("my-project", "access-log", "archive-to-oss", target)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_shipper(
+        &self,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+        shipper_name: impl AsRef<str>,
+        target: impl Into<ShipperTarget>,
+    ) -> CreateShipperRequestBuilder {
+        CreateShipperRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/logstores/{}/shipper", logstore.as_ref()),
+            handle: self.handle.clone(),
+            shipper_name: shipper_name.as_ref().to_string(),
+            target: target.into(),
+        }
+    }
+}
+
+pub struct CreateShipperRequestBuilder {
+    project: String,
+    path: String,
+    handle: HandleRef,
+    shipper_name: String,
+    target: ShipperTarget,
+}
+
+request_options!(CreateShipperRequestBuilder);
+
+impl CreateShipperRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<CreateShipperRequest> {
+        Ok((
+            self.handle,
+            CreateShipperRequest {
+                project: self.project,
+                path: self.path,
+                shipper_name: self.shipper_name,
+                target: self.target,
+            },
+        ))
+    }
+}
+
+#[derive(Serialize)]
+struct CreateShipperRequest {
+    #[serde(skip_serializing)]
+    project: String,
+
+    #[serde(skip_serializing)]
+    path: String,
+
+    #[serde(rename = "shipperName")]
+    shipper_name: String,
+
+    #[serde(flatten)]
+    target: ShipperTarget,
+}
+
+impl Request for CreateShipperRequest {
+    const HTTP_METHOD: http::Method = http::Method::POST;
+    const CONTENT_TYPE: Option<http::HeaderValue> = Some(LOG_JSON);
+    type ResponseBody = ();
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn body(&self) -> crate::Result<Option<bytes::Bytes>, RequestError> {
+        let json = serde_json::to_string(&self).map_err(RequestErrorKind::JsonEncode)?;
+        Ok(Some(bytes::Bytes::from(json)))
+    }
+}
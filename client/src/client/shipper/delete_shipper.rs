@@ -0,0 +1,84 @@
+use super::*;
+
+impl crate::client::Client {
+    /// Delete a shipper.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `logstore` - The name of the logstore whose data is shipped
+    /// * `shipper_name` - The name of the shipper to delete
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// client.delete_shipper("my-project", "access-log", "archive-to-oss")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_shipper(
+        &self,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+        shipper_name: impl AsRef<str>,
+    ) -> DeleteShipperRequestBuilder {
+        DeleteShipperRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!(
+                "/logstores/{}/shipper/{}",
+                logstore.as_ref(),
+                shipper_name.as_ref()
+            ),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+pub struct DeleteShipperRequestBuilder {
+    handle: HandleRef,
+    project: String,
+    path: String,
+}
+
+request_options!(DeleteShipperRequestBuilder);
+
+impl DeleteShipperRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<DeleteShipperRequest> {
+        Ok((
+            self.handle,
+            DeleteShipperRequest {
+                project: self.project,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+struct DeleteShipperRequest {
+    project: String,
+    path: String,
+}
+
+impl Request for DeleteShipperRequest {
+    type ResponseBody = ();
+    const HTTP_METHOD: http::Method = http::Method::DELETE;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+}
@@ -0,0 +1,97 @@
+use super::*;
+use crate::ResponseResult;
+
+impl crate::client::Client {
+    /// Get a shipper.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `logstore` - The name of the logstore whose data is shipped
+    /// * `shipper_name` - The name of the shipper to get
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::ShipperTarget;
+    ///
+    /// let resp = client.get_shipper("my-project", "access-log", "archive-to-oss")
+    ///     .send()
+    ///     .await?;
+    /// match resp.get_body().target() {
+    ///     ShipperTarget::Oss(oss) => println!("Shipping to oss://{}/{}", oss.oss_bucket, oss.oss_prefix),
+    ///     ShipperTarget::MaxCompute(odps) => println!("Shipping to {}.{}", odps.odps_project, odps.odps_table),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_shipper(
+        &self,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+        shipper_name: impl AsRef<str>,
+    ) -> GetShipperRequestBuilder {
+        GetShipperRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!(
+                "/logstores/{}/shipper/{}",
+                logstore.as_ref(),
+                shipper_name.as_ref()
+            ),
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+pub struct GetShipperRequestBuilder {
+    handle: HandleRef,
+    project: String,
+    path: String,
+}
+
+request_options!(GetShipperRequestBuilder);
+
+impl GetShipperRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<Shipper> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<GetShipperRequest> {
+        Ok((
+            self.handle,
+            GetShipperRequest {
+                project: self.project,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+struct GetShipperRequest {
+    project: String,
+    path: String,
+}
+
+impl Request for GetShipperRequest {
+    type ResponseBody = Shipper;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl FromHttpResponse for Shipper {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
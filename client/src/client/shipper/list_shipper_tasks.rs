@@ -0,0 +1,190 @@
+use super::*;
+use crate::ResponseResult;
+
+impl crate::client::Client {
+    /// List the tasks of a shipper, i.e. the batches shipped in a time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `logstore` - The name of the logstore whose data is shipped
+    /// * `shipper_name` - The name of the shipper
+    /// * `offset` - The offset for pagination (starting from 0)
+    /// * `size` - The number of tasks to return (page size)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let now = chrono::Utc::now().timestamp();
+    /// let resp = client.list_shipper_tasks("my-project", "access-log", "archive-to-oss", 0, 100)
+    ///     .from(now - 86400)
+    ///     .to(now)
+    ///     .status("fail")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// println!("{} tasks failed", resp.get_body().statistics().fail());
+    /// for task in resp.get_body().tasks() {
+    ///     println!("{} failed: {}", task.id(), task.task_message());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_shipper_tasks(
+        &self,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+        shipper_name: impl AsRef<str>,
+        offset: i32,
+        size: i32,
+    ) -> ListShipperTasksRequestBuilder {
+        ListShipperTasksRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!(
+                "/logstores/{}/shipper/{}/tasks",
+                logstore.as_ref(),
+                shipper_name.as_ref()
+            ),
+            handle: self.handle.clone(),
+            offset,
+            size,
+            from: None,
+            to: None,
+            status: None,
+        }
+    }
+}
+
+pub struct ListShipperTasksRequestBuilder {
+    project: String,
+    path: String,
+    handle: HandleRef,
+    offset: i32,
+    size: i32,
+    from: Option<i64>,
+    to: Option<i64>,
+    status: Option<String>,
+}
+
+request_options!(ListShipperTasksRequestBuilder);
+
+impl ListShipperTasksRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<ListShipperTasksResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Required, the start of the task creation time range, in unix timestamp, in seconds.
+    pub fn from(mut self, from: i64) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Required, the end of the task creation time range, in unix timestamp, in seconds.
+    pub fn to(mut self, to: i64) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Optional, only list tasks in the given status, `running`, `success` or `fail`.
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    fn build(self) -> BuildResult<ListShipperTasksRequest> {
+        check_required!(("from", self.from), ("to", self.to));
+        Ok((
+            self.handle,
+            ListShipperTasksRequest {
+                project: self.project,
+                path: self.path,
+                offset: self.offset,
+                size: self.size,
+                from: self.from.unwrap(),
+                to: self.to.unwrap(),
+                status: self.status,
+            },
+        ))
+    }
+}
+
+struct ListShipperTasksRequest {
+    project: String,
+    path: String,
+    offset: i32,
+    size: i32,
+    from: i64,
+    to: i64,
+    status: Option<String>,
+}
+
+impl Request for ListShipperTasksRequest {
+    type ResponseBody = ListShipperTasksResponse;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        Some(vec![
+            ("offset".to_string(), self.offset.to_string()),
+            ("size".to_string(), self.size.to_string()),
+            ("from".to_string(), self.from.to_string()),
+            ("to".to_string(), self.to.to_string()),
+            (
+                "status".to_string(),
+                self.status.clone().unwrap_or_default(),
+            ),
+        ])
+    }
+}
+
+/// Response containing a list of shipper tasks
+#[derive(Debug, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct ListShipperTasksResponse {
+    /// Number of tasks returned in this response
+    count: i32,
+
+    /// Total number of tasks in the time range
+    total: i32,
+
+    /// Number of tasks in each status
+    #[serde(default)]
+    statistics: ShipperTaskStatistics,
+
+    /// List of tasks
+    #[serde(default)]
+    tasks: Vec<ShipperTask>,
+}
+
+/// Number of shipper tasks in each status.
+#[derive(Debug, Clone, Default, Getters, Deserialize)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct ShipperTaskStatistics {
+    /// Number of running tasks
+    running: i32,
+
+    /// Number of succeeded tasks
+    success: i32,
+
+    /// Number of failed tasks
+    fail: i32,
+}
+
+impl FromHttpResponse for ListShipperTasksResponse {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
@@ -0,0 +1,123 @@
+use super::*;
+use crate::ResponseResult;
+
+impl crate::client::Client {
+    /// List the names of the shippers of a logstore with pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `logstore` - The name of the logstore
+    /// * `offset` - The offset for pagination (starting from 0)
+    /// * `size` - The number of shippers to return (page size)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let resp = client.list_shippers("my-project", "access-log", 0, 100)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for shipper_name in resp.get_body().shippers() {
+    ///     println!("Shipper: {}", shipper_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_shippers(
+        &self,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+        offset: i32,
+        size: i32,
+    ) -> ListShippersRequestBuilder {
+        ListShippersRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!("/logstores/{}/shipper", logstore.as_ref()),
+            handle: self.handle.clone(),
+            offset,
+            size,
+        }
+    }
+}
+
+pub struct ListShippersRequestBuilder {
+    project: String,
+    path: String,
+    handle: HandleRef,
+    offset: i32,
+    size: i32,
+}
+
+request_options!(ListShippersRequestBuilder);
+
+impl ListShippersRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<ListShippersResponse> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<ListShippersRequest> {
+        Ok((
+            self.handle,
+            ListShippersRequest {
+                project: self.project,
+                path: self.path,
+                offset: self.offset,
+                size: self.size,
+            },
+        ))
+    }
+}
+
+struct ListShippersRequest {
+    project: String,
+    path: String,
+    offset: i32,
+    size: i32,
+}
+
+impl Request for ListShippersRequest {
+    type ResponseBody = ListShippersResponse;
+    const HTTP_METHOD: http::Method = http::Method::GET;
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn query_params(&self) -> Option<Vec<(String, String)>> {
+        Some(vec![
+            ("offset".to_string(), self.offset.to_string()),
+            ("size".to_string(), self.size.to_string()),
+        ])
+    }
+}
+
+/// Response containing a list of shipper names
+#[derive(Debug, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct ListShippersResponse {
+    /// Number of shippers returned in this response
+    count: i32,
+
+    /// Total number of shippers of the logstore
+    total: i32,
+
+    /// List of shipper names
+    #[serde(rename = "shipper", default)]
+    shippers: Vec<String>,
+}
+
+impl FromHttpResponse for ListShippersResponse {
+    fn try_from(body: bytes::Bytes, http_headers: &http::HeaderMap) -> ResponseResult<Self> {
+        parse_json_response(body.as_ref(), http_headers)
+    }
+}
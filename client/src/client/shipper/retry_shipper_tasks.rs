@@ -0,0 +1,120 @@
+use super::*;
+use crate::RequestErrorKind;
+
+impl crate::client::Client {
+    /// Retry failed tasks of a shipper.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `logstore` - The name of the logstore whose data is shipped
+    /// * `shipper_name` - The name of the shipper
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// let now = chrono::Utc::now().timestamp();
+    /// let resp = client.list_shipper_tasks("my-project", "access-log", "archive-to-oss", 0, 100)
+    ///     .from(now - 86400)
+    ///     .to(now)
+    ///     .status("fail")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// let task_ids: Vec<String> = resp.get_body().tasks().iter().map(|t| t.id().clone()).collect();
+    /// client.retry_shipper_tasks("my-project", "access-log", "archive-to-oss")
+    ///     .task_ids(task_ids)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn retry_shipper_tasks(
+        &self,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+        shipper_name: impl AsRef<str>,
+    ) -> RetryShipperTasksRequestBuilder {
+        RetryShipperTasksRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!(
+                "/logstores/{}/shipper/{}/tasks",
+                logstore.as_ref(),
+                shipper_name.as_ref()
+            ),
+            handle: self.handle.clone(),
+            task_ids: None,
+        }
+    }
+}
+
+pub struct RetryShipperTasksRequestBuilder {
+    project: String,
+    path: String,
+    handle: HandleRef,
+    task_ids: Option<Vec<String>>,
+}
+
+request_options!(RetryShipperTasksRequestBuilder);
+
+impl RetryShipperTasksRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    /// Set the IDs of the tasks to retry (required).
+    ///
+    /// # Arguments
+    ///
+    /// * `task_ids` - IDs of failed tasks, as returned by `list_shipper_tasks`
+    pub fn task_ids<I, S>(mut self, task_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.task_ids = Some(task_ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    fn build(self) -> BuildResult<RetryShipperTasksRequest> {
+        check_required!(("task_ids", self.task_ids));
+        Ok((
+            self.handle,
+            RetryShipperTasksRequest {
+                project: self.project,
+                path: self.path,
+                task_ids: self.task_ids.unwrap(),
+            },
+        ))
+    }
+}
+
+struct RetryShipperTasksRequest {
+    project: String,
+    path: String,
+    task_ids: Vec<String>,
+}
+
+impl Request for RetryShipperTasksRequest {
+    const HTTP_METHOD: http::Method = http::Method::PUT;
+    const CONTENT_TYPE: Option<http::HeaderValue> = Some(LOG_JSON);
+    type ResponseBody = ();
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn body(&self) -> crate::Result<Option<bytes::Bytes>, RequestError> {
+        let json = serde_json::to_string(&self.task_ids).map_err(RequestErrorKind::JsonEncode)?;
+        Ok(Some(bytes::Bytes::from(json)))
+    }
+}
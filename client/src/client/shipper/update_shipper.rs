@@ -0,0 +1,119 @@
+use super::*;
+use crate::RequestErrorKind;
+
+impl crate::client::Client {
+    /// Update the target of an existing shipper.
+    ///
+    /// The whole target configuration is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The name of the project
+    /// * `logstore` - The name of the logstore whose data is shipped
+    /// * `shipper_name` - The name of the shipper to update
+    /// * `target` - Where the data is shipped to from now on
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::ShipperTarget;
+    ///
+    /// let resp = client.get_shipper("my-project", "access-log", "archive-to-oss").send().await?;
+    /// let ShipperTarget::Oss(target) = resp.get_body().target().clone() else {
+    ///     return Ok(());
+    /// };
+    ///
+    /// let target = target.buffer_interval(900);
+    /// client.update_shipper("my-project", "access-log", "archive-to-oss", target)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_shipper(
+        &self,
+        project: impl AsRef<str>,
+        logstore: impl AsRef<str>,
+        shipper_name: impl AsRef<str>,
+        target: impl Into<ShipperTarget>,
+    ) -> UpdateShipperRequestBuilder {
+        UpdateShipperRequestBuilder {
+            project: project.as_ref().to_string(),
+            path: format!(
+                "/logstores/{}/shipper/{}",
+                logstore.as_ref(),
+                shipper_name.as_ref()
+            ),
+            handle: self.handle.clone(),
+            shipper_name: shipper_name.as_ref().to_string(),
+            target: target.into(),
+        }
+    }
+}
+
+pub struct UpdateShipperRequestBuilder {
+    project: String,
+    path: String,
+    handle: HandleRef,
+    shipper_name: String,
+    target: ShipperTarget,
+}
+
+request_options!(UpdateShipperRequestBuilder);
+
+impl UpdateShipperRequestBuilder {
+    #[must_use = "the result future must be awaited"]
+    pub fn send(self) -> ResponseResultBoxFuture<()> {
+        Box::pin(async move {
+            let (handle, request) = self.build()?;
+            handle.send(request).await
+        })
+    }
+
+    fn build(self) -> BuildResult<UpdateShipperRequest> {
+        Ok((
+            self.handle,
+            UpdateShipperRequest {
+                project: self.project,
+                path: self.path,
+                shipper_name: self.shipper_name,
+                target: self.target,
+            },
+        ))
+    }
+}
+
+#[derive(Serialize)]
+struct UpdateShipperRequest {
+    #[serde(skip_serializing)]
+    project: String,
+
+    #[serde(skip_serializing)]
+    path: String,
+
+    #[serde(rename = "shipperName")]
+    shipper_name: String,
+
+    #[serde(flatten)]
+    target: ShipperTarget,
+}
+
+impl Request for UpdateShipperRequest {
+    const HTTP_METHOD: http::Method = http::Method::PUT;
+    const CONTENT_TYPE: Option<http::HeaderValue> = Some(LOG_JSON);
+    type ResponseBody = ();
+
+    fn project(&self) -> Option<&str> {
+        Some(&self.project)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn body(&self) -> crate::Result<Option<bytes::Bytes>, RequestError> {
+        let json = serde_json::to_string(&self).map_err(RequestErrorKind::JsonEncode)?;
+        Ok(Some(bytes::Bytes::from(json)))
+    }
+}
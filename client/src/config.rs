@@ -1,7 +1,12 @@
+use crate::middleware::{http_layer, HttpLayer, HttpService};
+use crate::observer::RequestObserver;
+use crate::rate_limit::WriteRateLimit;
 use crate::utils::is_empty_or_none;
 use crate::ConfigError;
 use lazy_static::lazy_static;
 use regex::Regex;
+use std::sync::Arc;
+use tower::{BoxError, Layer, Service};
 
 /// Configuration for the Aliyun Log Service client.
 ///
@@ -30,6 +35,17 @@ pub struct Config {
     pub(crate) max_retry: u32,
     pub(crate) base_retry_backoff: std::time::Duration,
     pub(crate) max_retry_backoff: std::time::Duration,
+    pub(crate) layers: Vec<HttpLayer>,
+    pub(crate) http_client: Option<reqwest::Client>,
+    pub(crate) proxies: Vec<reqwest::Proxy>,
+    pub(crate) root_certificates: Vec<reqwest::Certificate>,
+    pub(crate) tls_backend: Option<TlsBackend>,
+    pub(crate) pool_max_idle_per_host: Option<usize>,
+    pub(crate) pool_idle_timeout: std::time::Duration,
+    pub(crate) tcp_keepalive: Option<std::time::Duration>,
+    pub(crate) http2_prior_knowledge: bool,
+    pub(crate) observers: Vec<Arc<dyn RequestObserver>>,
+    pub(crate) write_rate_limit: Option<WriteRateLimit>,
 }
 
 impl Config {
@@ -64,6 +80,17 @@ pub struct ConfigBuilder {
     security_token: Option<String>,
     connection_timeout: Option<std::time::Duration>,
     request_timeout: Option<std::time::Duration>,
+    layers: Vec<HttpLayer>,
+    http_client: Option<reqwest::Client>,
+    proxies: Vec<reqwest::Proxy>,
+    root_certificates: Vec<reqwest::Certificate>,
+    tls_backend: Option<TlsBackend>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<std::time::Duration>,
+    tcp_keepalive: Option<std::time::Duration>,
+    http2_prior_knowledge: bool,
+    observers: Vec<Arc<dyn RequestObserver>>,
+    write_rate_limit: Option<WriteRateLimit>,
 }
 
 impl ConfigBuilder {
@@ -136,6 +163,193 @@ impl ConfigBuilder {
         self
     }
 
+    /// Use this http client to send requests, instead of building one from the config.
+    ///
+    /// The timeouts and transport options of the config, such as proxies, root certificates
+    /// and pool settings, are not applied to this client and must be set on it instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - The http client to send requests with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # async fn wrapper() -> aliyun_log_rust_sdk::Result<()> {
+    /// use aliyun_log_rust_sdk::{Client, Config, FromConfig};
+    ///
+    /// let http_client = reqwest::Client::builder()
+    ///     .user_agent("my-app")
+    ///     .build()
+    ///     .unwrap();
+    /// let config = Config::builder()
+    ///     .endpoint("cn-hangzhou.log.aliyuncs.com")
+    ///     .access_key("access_key_id", "access_key_secret")
+    ///     .http_client(http_client)
+    ///     .build()?;
+    /// let client = Client::from_config(config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Send requests through a proxy, can be called multiple times to add more proxies.
+    ///
+    /// By default, the system proxies from environment variables such as `HTTPS_PROXY` are used.
+    ///
+    /// # Arguments
+    ///
+    /// * `proxy` - The proxy, e.g. `reqwest::Proxy::all("http://proxy.example.com:8080")`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # async fn wrapper() -> Result<(), Box<dyn std::error::Error>> {
+    /// use aliyun_log_rust_sdk::{Client, Config, FromConfig};
+    ///
+    /// let cert = std::fs::read("/etc/ssl/private-ca.pem")?;
+    /// let config = Config::builder()
+    ///     .endpoint("https://cn-hangzhou.log.aliyuncs.com")
+    ///     .access_key("access_key_id", "access_key_secret")
+    ///     .proxy(reqwest::Proxy::all("http://proxy.example.com:8080")?.basic_auth("user", "pass"))
+    ///     .root_certificate(reqwest::Certificate::from_pem(&cert)?)
+    ///     .build()?;
+    /// let client = Client::from_config(config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Trust an additional root certificate, e.g. a private CA, can be called multiple times.
+    ///
+    /// # Arguments
+    ///
+    /// * `certificate` - The root certificate to trust
+    pub fn root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Set the TLS implementation used for https endpoints, default is [`TlsBackend::NativeTls`].
+    ///
+    /// # Arguments
+    ///
+    /// * `tls_backend` - The TLS implementation
+    pub fn tls_backend(mut self, tls_backend: TlsBackend) -> Self {
+        self.tls_backend = Some(tls_backend);
+        self
+    }
+
+    /// Set the maximum number of idle connections kept in the pool for each host, unlimited by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_idle` - The maximum number of idle connections per host
+    pub fn pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max_idle);
+        self
+    }
+
+    /// Set how long an idle connection is kept in the pool, default is 55 seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The idle timeout of pooled connections
+    pub fn pool_idle_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Enable TCP keepalive on connections, with this interval. Disabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The keepalive interval
+    pub fn tcp_keepalive(mut self, interval: std::time::Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Only use HTTP/2, without negotiating it first.
+    ///
+    /// This requires the endpoint, or the proxy in front of it, to support HTTP/2 prior knowledge.
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http2_prior_knowledge = true;
+        self
+    }
+
+    /// Add a [`tower`] layer to the stack every HTTP request goes through.
+    ///
+    /// Layers wrap each attempt of a request, inside the retry layer of the client,
+    /// and the first layer added is the outermost one.
+    /// Requests are already signed when they reach the layers, see [`HttpService`].
+    ///
+    /// # Arguments
+    ///
+    /// * `layer` - A layer wrapping an [`HttpService`], e.g. a `tower::limit::ConcurrencyLimitLayer`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # async fn wrapper() -> aliyun_log_rust_sdk::Result<()> {
+    /// use aliyun_log_rust_sdk::{Client, Config, FromConfig};
+    /// use tower::limit::ConcurrencyLimitLayer;
+    /// use tower::timeout::TimeoutLayer;
+    ///
+    /// let config = Config::builder()
+    ///     .endpoint("cn-hangzhou.log.aliyuncs.com")
+    ///     .access_key("access_key_id", "access_key_secret")
+    ///     .layer(ConcurrencyLimitLayer::new(64))
+    ///     .layer(TimeoutLayer::new(std::time::Duration::from_secs(5)))
+    ///     .build()?;
+    /// let client = Client::from_config(config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn layer<L>(mut self, layer: L) -> Self
+    where
+        L: Layer<HttpService> + Send + Sync + 'static,
+        L::Service:
+            Service<reqwest::Request, Response = reqwest::Response> + Clone + Send + Sync + 'static,
+        <L::Service as Service<reqwest::Request>>::Error: Into<BoxError>,
+        <L::Service as Service<reqwest::Request>>::Future: Send + 'static,
+    {
+        self.layers.push(http_layer(layer));
+        self
+    }
+
+    /// Add an observer called once each request is completed, e.g. to record metrics,
+    /// can be called multiple times to add more observers.
+    ///
+    /// See [`RequestObserver`] for more details.
+    ///
+    /// # Arguments
+    ///
+    /// * `observer` - The observer, e.g. a `MetricsObserver` with the `metrics` feature
+    pub fn observer(mut self, observer: impl RequestObserver + 'static) -> Self {
+        self.observers.push(Arc::new(observer));
+        self
+    }
+
+    /// Limit the rate of `put_logs` and `put_logs_raw` on the client side,
+    /// and retry the writes rejected by the server with `WriteQuotaExceed` or `ShardWriteQuotaExceed`.
+    ///
+    /// See [`WriteRateLimit`] for more details.
+    ///
+    /// # Arguments
+    ///
+    /// * `write_rate_limit` - The bytes and requests per second allowed, and what they are counted for
+    pub fn write_rate_limit(mut self, write_rate_limit: WriteRateLimit) -> Self {
+        self.write_rate_limit = Some(write_rate_limit);
+        self
+    }
+
     /// Build the client with the configured settings.
     pub fn build(self) -> Result<Config, ConfigError> {
         let endpoint = self.validate_endpoint()?;
@@ -165,6 +379,17 @@ impl ConfigBuilder {
             max_retry: DEFAULT_MAX_RETRY,
             base_retry_backoff: DEFAULT_BASE_RETRY_BACKOFF,
             max_retry_backoff: DEFAULT_MAX_RETRY_BACKOFF,
+            layers: self.layers,
+            http_client: self.http_client,
+            proxies: self.proxies,
+            root_certificates: self.root_certificates,
+            tls_backend: self.tls_backend,
+            pool_max_idle_per_host: self.pool_max_idle_per_host,
+            pool_idle_timeout: self.pool_idle_timeout.unwrap_or(DEFAULT_POOL_IDLE_TIMEOUT),
+            tcp_keepalive: self.tcp_keepalive,
+            http2_prior_knowledge: self.http2_prior_knowledge,
+            observers: self.observers,
+            write_rate_limit: self.write_rate_limit,
         })
     }
 
@@ -207,6 +432,17 @@ impl ConfigBuilder {
     }
 }
 
+/// TLS implementation used to connect to https endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TlsBackend {
+    /// The TLS library of the platform: OpenSSL on Linux, Secure Transport on macOS and SChannel on Windows
+    NativeTls,
+    /// rustls, only available with the `rustls-tls` feature
+    #[cfg(feature = "rustls-tls")]
+    Rustls,
+}
+
 #[derive(Clone)]
 pub(crate) struct Endpoint {
     pub(crate) domain: String,
@@ -215,6 +451,7 @@ pub(crate) struct Endpoint {
 
 const DEFAULT_REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
 const DEFAULT_CONNECTION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+const DEFAULT_POOL_IDLE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(55);
 const DEFAULT_MAX_RETRY: u32 = 3;
 const DEFAULT_BASE_RETRY_BACKOFF: std::time::Duration = std::time::Duration::from_millis(1000);
 const DEFAULT_MAX_RETRY_BACKOFF: std::time::Duration = std::time::Duration::from_secs(10);
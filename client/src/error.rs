@@ -24,6 +24,8 @@ pub enum Error {
     Network(#[from] reqwest::Error),
 
     /// The server returns an error response with error code and message.
+    ///
+    /// The error code can be matched with [`Error::code`], [`Error::is_not_found`] and [`Error::is_throttled`].
     #[error("Server error: code={error_code}, message={error_message}, httpStatus={http_status}, requestId={request_id:?}")]
     Server {
         error_code: String,
@@ -32,6 +34,11 @@ pub enum Error {
         request_id: Option<String>,
     },
 
+    /// The request was not completed before the deadline set with `deadline` on its builder,
+    /// retries included.
+    #[error("Request deadline exceeded")]
+    DeadlineExceeded,
+
     #[error("Other error: {0}")]
     Other(anyhow::Error),
 }
@@ -68,6 +75,9 @@ pub(crate) enum RequestErrorKind {
     #[error("Missing required parameter: {0}")]
     MissingRequiredParameter(String),
 
+    #[error("Invalid parameter: {0}")]
+    InvalidParameter(String),
+
     #[error("Failed to compress data: {0}")]
     Compression(#[from] CompressionError),
 
@@ -139,6 +149,66 @@ pub(crate) enum ResponseErrorKind {
 pub(crate) type ResponseResult<T> = std::result::Result<T, ResponseError>;
 
 impl Error {
+    /// The error code returned by the server, `None` if the error is not a server error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: aliyun_log_rust_sdk::Client) -> Result<(), aliyun_log_rust_sdk::Error> {
+    /// use aliyun_log_rust_sdk::ErrorCode;
+    ///
+    /// match client.get_logstore("my-project", "my-logstore").send().await {
+    ///     Ok(resp) => println!("TTL: {} days", resp.get_body().ttl()),
+    ///     Err(err) if err.code() == Some(ErrorCode::LogStoreNotExist) => println!("not created yet"),
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn code(&self) -> Option<ErrorCode> {
+        match self {
+            Error::Server { error_code, .. } => Some(ErrorCode::from(error_code.as_str())),
+            _ => None,
+        }
+    }
+
+    /// Whether the server reported that the resource requested does not exist,
+    /// e.g. `ProjectNotExist` or `LogStoreNotExist`.
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Error::Server { http_status, .. } if *http_status == 404 => true,
+            _ => self.code().is_some_and(|code| code.is_not_found()),
+        }
+    }
+
+    /// Whether the server rejected the request because a quota was exceeded,
+    /// e.g. `WriteQuotaExceed` or `ReadQuotaExceed`, so that it may succeed if retried later.
+    pub fn is_throttled(&self) -> bool {
+        self.code().is_some_and(|code| code.is_throttled())
+    }
+
+    /// Whether the request timed out, either an attempt after `request_timeout`
+    /// or the whole request after its `deadline`.
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            Error::DeadlineExceeded => true,
+            Error::Network(err) => err.is_timeout(),
+            _ => false,
+        }
+    }
+
+    /// Convert an error returned by the http service stack, keeping network and sdk errors as is.
+    pub(crate) fn from_box_error(err: tower::BoxError) -> Self {
+        let err = match err.downcast::<reqwest::Error>() {
+            Ok(err) => return Error::Network(*err),
+            Err(err) => err,
+        };
+        match err.downcast::<Error>() {
+            Ok(err) => *err,
+            Err(err) => Error::Other(anyhow::anyhow!(err)),
+        }
+    }
+
     pub(crate) fn server_error(
         status: http::StatusCode,
         request_id: Option<String>,
@@ -162,11 +232,187 @@ impl Error {
     }
 }
 
+macro_rules! error_codes {
+    ($($(#[$meta:meta])* $code:ident,)*) => {
+        /// Error codes returned by the server in [`Error::Server`], see [`Error::code`].
+        ///
+        /// Codes which are not known by this version of the SDK are kept as [`ErrorCode::Unknown`].
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum ErrorCode {
+            $($(#[$meta])* $code,)*
+            /// An error code not known by this version of the SDK
+            Unknown(String),
+        }
+
+        impl ErrorCode {
+            /// The error code as returned by the server, e.g. `ProjectNotExist`.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(ErrorCode::$code => stringify!($code),)*
+                    ErrorCode::Unknown(code) => code,
+                }
+            }
+        }
+
+        impl From<&str> for ErrorCode {
+            fn from(code: &str) -> Self {
+                match code {
+                    $(stringify!($code) => ErrorCode::$code,)*
+                    _ => ErrorCode::Unknown(code.to_string()),
+                }
+            }
+        }
+    };
+}
+
+error_codes! {
+    /// The project does not exist
+    ProjectNotExist,
+    /// The logstore does not exist
+    LogStoreNotExist,
+    /// The shard does not exist
+    ShardNotExist,
+    /// The logstore has no index
+    IndexConfigNotExist,
+    /// The consumer group does not exist
+    ConsumerGroupNotExist,
+    /// The machine group does not exist
+    MachineGroupNotExist,
+    /// The job, e.g. an alert or a data transformation, does not exist
+    JobNotExist,
+    /// A project with the same name already exists
+    ProjectAlreadyExist,
+    /// A logstore with the same name already exists
+    LogStoreAlreadyExist,
+    /// The logstore already has an index
+    IndexAlreadyExist,
+    /// A consumer group with the same name already exists
+    ConsumerGroupAlreadyExist,
+    /// A job with the same name already exists
+    JobAlreadyExist,
+    /// The cursor is invalid or expired
+    InvalidCursor,
+    /// A parameter of the request is invalid
+    ParameterInvalid,
+    /// The body of the request is invalid
+    PostBodyInvalid,
+    /// The body of the request is too large
+    PostBodyTooLarge,
+    /// The caller is not authorized to perform the request
+    Unauthorized,
+    /// The access key id does not exist
+    InvalidAccessKeyId,
+    /// The signature of the request does not match
+    SignatureNotMatch,
+    /// The write quota of the logstore is exceeded
+    WriteQuotaExceed,
+    /// The write quota of the shard is exceeded
+    ShardWriteQuotaExceed,
+    /// The read quota of the logstore is exceeded
+    ReadQuotaExceed,
+    /// The read quota of the shard is exceeded
+    ShardReadQuotaExceed,
+    /// A quota of the project is exceeded
+    ExceedQuota,
+    /// The request timed out on the server
+    RequestTimeout,
+    /// The server is too busy to handle the request
+    ServerBusy,
+    /// The server failed to handle the request
+    InternalServerError,
+}
+
+impl ErrorCode {
+    /// Whether the code reports that the resource requested does not exist.
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::ProjectNotExist
+                | ErrorCode::LogStoreNotExist
+                | ErrorCode::ShardNotExist
+                | ErrorCode::IndexConfigNotExist
+                | ErrorCode::ConsumerGroupNotExist
+                | ErrorCode::MachineGroupNotExist
+                | ErrorCode::JobNotExist
+        )
+    }
+
+    /// Whether the code reports that a quota was exceeded.
+    pub fn is_throttled(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::WriteQuotaExceed
+                | ErrorCode::ShardWriteQuotaExceed
+                | ErrorCode::ReadQuotaExceed
+                | ErrorCode::ShardReadQuotaExceed
+                | ErrorCode::ExceedQuota
+        )
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for ErrorCode {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ErrorCode {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
 #[derive(Deserialize, Debug)]
 pub(crate) struct ServerError {
-    #[serde(rename = "errorCode")]
+    // the Prometheus-compatible API reports errors as `errorType` and `error`
+    #[serde(rename = "errorCode", alias = "errorType")]
     error_code: String,
 
-    #[serde(rename = "errorMessage")]
+    #[serde(rename = "errorMessage", alias = "error")]
     error_message: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server_error(error_code: &str, http_status: u32) -> Error {
+        Error::Server {
+            error_code: error_code.to_string(),
+            error_message: "".to_string(),
+            http_status,
+            request_id: None,
+        }
+    }
+
+    #[test]
+    fn test_error_code() {
+        assert_eq!(
+            ErrorCode::from("LogStoreNotExist"),
+            ErrorCode::LogStoreNotExist
+        );
+        assert_eq!(ErrorCode::LogStoreNotExist.as_str(), "LogStoreNotExist");
+        assert_eq!(
+            ErrorCode::from("SomethingNew"),
+            ErrorCode::Unknown("SomethingNew".to_string())
+        );
+        assert_eq!(ErrorCode::from("SomethingNew"), "SomethingNew");
+
+        let err = server_error("ProjectNotExist", 404);
+        assert_eq!(err.code(), Some(ErrorCode::ProjectNotExist));
+        assert!(err.is_not_found());
+        assert!(!err.is_throttled());
+        assert!(server_error("ShardWriteQuotaExceed", 403).is_throttled());
+        assert!(!server_error("Unauthorized", 401).is_not_found());
+        assert!(Error::DeadlineExceeded.is_timeout());
+        assert!(!err.is_timeout());
+        assert_eq!(Error::Other(anyhow::anyhow!("other")).code(), None);
+    }
+}
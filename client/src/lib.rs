@@ -3,11 +3,18 @@ mod common;
 mod compress;
 mod config;
 mod error;
+mod middleware;
+mod observer;
+pub mod query;
+mod rate_limit;
 mod utils;
 
 pub use self::error::*;
 pub use client::*;
-pub use config::{Config, ConfigBuilder};
+pub use config::{Config, ConfigBuilder, TlsBackend};
+pub use middleware::HttpService;
+pub use observer::*;
+pub use rate_limit::{RateLimitScope, WriteRateLimit};
 mod macros;
 mod request;
 mod response;
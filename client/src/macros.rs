@@ -12,6 +12,40 @@ macro_rules! check_required {
     };
 }
 
+/// Add the per-request overrides of the client settings to a request builder,
+/// which must store its `HandleRef` in a `handle` field, or at the given path,
+/// e.g. `inner.handle` for a builder wrapping another one.
+macro_rules! request_options {
+    ($builder:ty) => {
+        request_options!($builder, handle);
+    };
+    ($builder:ty, $($handle:ident).+) => {
+        impl $builder {
+            /// Override the request timeout of the client for this request, applied to each attempt.
+            pub fn request_timeout(mut self, timeout: std::time::Duration) -> Self {
+                self.$($handle).+.options.timeout = Some(timeout);
+                self
+            }
+
+            /// Override the maximum number of retries of the client for this request,
+            /// `0` disables retries.
+            pub fn max_retry(mut self, max_retry: u32) -> Self {
+                self.$($handle).+.options.max_retry = Some(max_retry);
+                self
+            }
+
+            /// Fail the request if it is not completed before this deadline, retries included.
+            ///
+            /// The request is cancelled when the deadline is reached, and fails with
+            /// [`Error::DeadlineExceeded`](crate::Error::DeadlineExceeded).
+            pub fn deadline(mut self, deadline: std::time::Instant) -> Self {
+                self.$($handle).+.options.deadline = Some(deadline);
+                self
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! token_list {
     [$($t:expr),* $(,)?] => {
@@ -26,3 +60,4 @@ macro_rules! token_list {
 }
 
 pub(crate) use check_required;
+pub(crate) use request_options;
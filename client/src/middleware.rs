@@ -0,0 +1,299 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tower::retry::{Policy, Retry};
+use tower::util::BoxCloneSyncService;
+use tower::{BoxError, Layer, Service, ServiceExt};
+use tracing::{debug, debug_span, Instrument};
+
+use crate::common::{LOG_BODY_RAW_SIZE, LOG_REQUEST_ID};
+use crate::config::Config;
+use crate::rate_limit::{is_write_quota_error, WriteLimit};
+use crate::utils::ValueGetter;
+use crate::Error;
+
+/// The service stack every HTTP request of the client goes through.
+///
+/// Requests are already signed when they enter the stack, so layers must not modify
+/// the signed headers, the path, the query parameters or the body.
+/// Errors returned by the layers are surfaced as [`Error::Other`](crate::Error::Other),
+/// except [`reqwest::Error`] and [`Error`](crate::Error) which are kept as is.
+pub type HttpService = BoxCloneSyncService<reqwest::Request, reqwest::Response, BoxError>;
+
+pub(crate) type HttpLayer = Arc<dyn Fn(HttpService) -> HttpService + Send + Sync>;
+
+/// Wrap a user layer so that it can be stored in the config and applied later.
+pub(crate) fn http_layer<L>(layer: L) -> HttpLayer
+where
+    L: Layer<HttpService> + Send + Sync + 'static,
+    L::Service:
+        Service<reqwest::Request, Response = reqwest::Response> + Clone + Send + Sync + 'static,
+    <L::Service as Service<reqwest::Request>>::Error: Into<BoxError>,
+    <L::Service as Service<reqwest::Request>>::Future: Send + 'static,
+{
+    Arc::new(move |inner| BoxCloneSyncService::new(layer.layer(inner).map_err(Into::into)))
+}
+
+/// Build the service stack: the user layers in the order they were added,
+/// then the http client which sends the requests.
+///
+/// The retry layer is added on top of it for each request by [`with_retry`],
+/// as the maximum number of retries can be overridden per request.
+pub(crate) fn build_http_service(config: &Config, http_client: reqwest::Client) -> HttpService {
+    let mut service = BoxCloneSyncService::new(http_client.map_err(BoxError::from));
+    for layer in config.layers.iter().rev() {
+        service = layer(service);
+    }
+    service
+}
+
+/// A response of the service stack, with its body read.
+pub(crate) struct HttpResponse {
+    pub(crate) status: http::StatusCode,
+    pub(crate) headers: http::HeaderMap,
+    pub(crate) body: bytes::Bytes,
+}
+
+type BufferedHttpService = BoxCloneSyncService<reqwest::Request, HttpResponse, BoxError>;
+
+/// Wrap the service stack with the retry layer, allowing up to `max_retry` retries.
+/// The number of retries done is counted in `retries`.
+///
+/// The body of the response is read within each attempt, so that an error while reading it
+/// is retried as any other network error.
+/// Each attempt is traced in a `sls.attempt` span, with the `x-log-requestid` of its response.
+///
+/// With a `write_limit`, each attempt waits for the rate limit to allow it,
+/// and write quota errors are retried too, after reducing the rate.
+pub(crate) fn with_retry(
+    service: HttpService,
+    config: &Config,
+    max_retry: u32,
+    retries: Arc<AtomicU32>,
+    write_limit: Option<WriteLimit>,
+) -> Retry<RetryPolicy, BufferedHttpService> {
+    let attempts = retries.clone();
+    let service = BoxCloneSyncService::new(service.map_future(move |future| {
+        let span = debug_span!(
+            "sls.attempt",
+            attempt = attempts.load(Ordering::Relaxed) + 1,
+            request_id = tracing::field::Empty,
+            http_status = tracing::field::Empty,
+        );
+        async move {
+            let resp: reqwest::Response = future.await?;
+            let span = tracing::Span::current();
+            span.record("http_status", resp.status().as_u16());
+            if let Some(request_id) = resp.headers().get(LOG_REQUEST_ID) {
+                span.record("request_id", request_id.to_str().unwrap_or_default());
+            }
+            let status = resp.status();
+            let headers = resp.headers().clone();
+            let body = resp.bytes().await?;
+            Ok::<_, BoxError>(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        }
+        .instrument(span)
+    }));
+    let service = match write_limit.clone() {
+        Some(limit) => BoxCloneSyncService::new(tower::service_fn(move |req: reqwest::Request| {
+            let limit = limit.clone();
+            let service = service.clone();
+            async move {
+                let raw_size = req.headers().get_i32_or_default(&LOG_BODY_RAW_SIZE, 0);
+                limit.acquire(raw_size as usize).await;
+                service.oneshot(req).await
+            }
+        })),
+        None => service,
+    };
+    Retry::new(
+        RetryPolicy::new(config, max_retry, retries, write_limit),
+        service,
+    )
+}
+
+/// Retry on network errors, including the ones while reading the response body,
+/// and on 500 to 503 responses, with an exponential backoff.
+///
+/// Writes subject to a rate limit are also retried on write quota errors,
+/// which reduce the rate of their [`WriteLimit`].
+#[derive(Clone)]
+pub(crate) struct RetryPolicy {
+    attempt: u32,
+    max_retry: u32,
+    base_retry_backoff: Duration,
+    max_retry_backoff: Duration,
+    retries: Arc<AtomicU32>,
+    write_limit: Option<WriteLimit>,
+}
+
+impl RetryPolicy {
+    fn new(
+        config: &Config,
+        max_retry: u32,
+        retries: Arc<AtomicU32>,
+        write_limit: Option<WriteLimit>,
+    ) -> Self {
+        Self {
+            attempt: 0,
+            max_retry,
+            base_retry_backoff: config.base_retry_backoff,
+            max_retry_backoff: config.max_retry_backoff,
+            retries,
+            write_limit,
+        }
+    }
+}
+
+/// Whether the server rejected a write because a write quota was exceeded.
+fn is_write_quota_exceeded(resp: &HttpResponse) -> bool {
+    !resp.status.is_success()
+        && Error::server_error(resp.status, None, &resp.body)
+            .code()
+            .is_some_and(|code| is_write_quota_error(&code))
+}
+
+impl Policy<reqwest::Request, HttpResponse, BoxError> for RetryPolicy {
+    type Future = tokio::time::Sleep;
+
+    fn retry(
+        &mut self,
+        _req: &mut reqwest::Request,
+        result: &mut Result<HttpResponse, BoxError>,
+    ) -> Option<Self::Future> {
+        let should_retry = match result {
+            Ok(resp) => match &self.write_limit {
+                Some(write_limit) if is_write_quota_exceeded(resp) => {
+                    write_limit.throttled();
+                    true
+                }
+                _ => (500..=503).contains(&resp.status.as_u16()),
+            },
+            Err(err) => err.is::<reqwest::Error>(),
+        };
+        if !should_retry || self.attempt >= self.max_retry {
+            return None;
+        }
+        let backoff = exponential_backoff(
+            self.base_retry_backoff,
+            self.attempt,
+            self.max_retry_backoff,
+        );
+        match result {
+            Ok(resp) => debug!(
+                attempt = self.attempt + 1,
+                backoff_ms = backoff.as_millis() as u64,
+                http_status = resp.status.as_u16(),
+                "retrying request after backoff"
+            ),
+            Err(err) => debug!(
+                attempt = self.attempt + 1,
+                backoff_ms = backoff.as_millis() as u64,
+                error = %err,
+                "retrying request after backoff"
+            ),
+        }
+        self.attempt += 1;
+        self.retries.fetch_add(1, Ordering::Relaxed);
+        Some(tokio::time::sleep(backoff))
+    }
+
+    fn clone_request(&mut self, req: &reqwest::Request) -> Option<reqwest::Request> {
+        // the body is always in memory, so this is O(1) and never fails
+        req.try_clone()
+    }
+}
+
+pub(crate) fn exponential_backoff(
+    base_delay: Duration,
+    retry_count: u32,
+    max_delay: Duration,
+) -> Duration {
+    let exp_delay = base_delay.saturating_mul(2u32.saturating_pow(retry_count));
+    std::cmp::min(exp_delay, max_delay)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rate_limit::RateLimiter;
+    use crate::{RateLimitScope, WriteRateLimit};
+
+    fn response(status: u16) -> Result<HttpResponse, BoxError> {
+        Ok(HttpResponse {
+            status: http::StatusCode::from_u16(status).unwrap(),
+            headers: http::HeaderMap::new(),
+            body: bytes::Bytes::new(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy() {
+        let mut policy = RetryPolicy {
+            attempt: 0,
+            max_retry: 2,
+            base_retry_backoff: Duration::from_millis(1),
+            max_retry_backoff: Duration::from_millis(2),
+            retries: Arc::new(AtomicU32::new(0)),
+            write_limit: None,
+        };
+        let mut req =
+            reqwest::Request::new(http::Method::GET, "http://localhost/".parse().unwrap());
+        assert!(policy.retry(&mut req, &mut response(200)).is_none());
+        assert!(policy.retry(&mut req, &mut response(404)).is_none());
+        assert!(policy
+            .retry(&mut req, &mut Err(BoxError::from("not a network error")))
+            .is_none());
+
+        assert!(policy.retry(&mut req, &mut response(503)).is_some());
+        assert!(policy.retry(&mut req, &mut response(500)).is_some());
+        assert!(policy.retry(&mut req, &mut response(500)).is_none());
+        assert_eq!(policy.retries.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_write_quota() {
+        let quota_exceeded = || {
+            Ok(HttpResponse {
+                status: http::StatusCode::FORBIDDEN,
+                headers: http::HeaderMap::new(),
+                body: r#"{"errorCode": "ShardWriteQuotaExceed", "errorMessage": "error"}"#.into(),
+            })
+        };
+        let mut policy = RetryPolicy {
+            attempt: 0,
+            max_retry: 1,
+            base_retry_backoff: Duration::from_millis(1),
+            max_retry_backoff: Duration::from_millis(2),
+            retries: Arc::new(AtomicU32::new(0)),
+            write_limit: None,
+        };
+        let mut req =
+            reqwest::Request::new(http::Method::POST, "http://localhost/".parse().unwrap());
+        // only writes subject to a rate limit are retried on quota errors
+        assert!(policy.retry(&mut req, &mut quota_exceeded()).is_none());
+
+        let limiter = Arc::new(RateLimiter::new(
+            WriteRateLimit::new(RateLimitScope::Logstore).requests_per_second(10),
+        ));
+        policy.write_limit = Some(WriteLimit::new(limiter, "my-project/my-logstore".into()));
+        assert!(policy.retry(&mut req, &mut quota_exceeded()).is_some());
+        // quota and server errors share the maximum number of retries
+        assert!(policy.retry(&mut req, &mut response(503)).is_none());
+    }
+
+    #[test]
+    fn test_exponential_backoff() {
+        let base = Duration::from_secs(1);
+        let max = Duration::from_secs(10);
+        assert_eq!(exponential_backoff(base, 0, max), Duration::from_secs(1));
+        assert_eq!(exponential_backoff(base, 2, max), Duration::from_secs(4));
+        assert_eq!(exponential_backoff(base, 5, max), max);
+        assert_eq!(exponential_backoff(base, 100, max), max);
+    }
+}
@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use getset::{CopyGetters, Getters};
+
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "metrics")]
+pub use self::metrics::MetricsObserver;
+
+/// An observer of the requests sent by the client, e.g. to record metrics.
+///
+/// Observers are registered with [`ConfigBuilder::observer`](crate::ConfigBuilder::observer),
+/// and are called once each request is completed, successfully or not.
+/// They are called on the task sending the request, and should not block.
+///
+/// # Examples
+///
+/// ```
+/// # async fn wrapper() -> aliyun_log_rust_sdk::Result<()> {
+/// use aliyun_log_rust_sdk::{Client, Config, FromConfig, RequestEvent, RequestObserver};
+///
+/// struct SlowRequestLogger;
+///
+/// impl RequestObserver for SlowRequestLogger {
+///     fn on_request_completed(&self, event: &RequestEvent) {
+///         if event.latency() > std::time::Duration::from_secs(1) {
+///             println!(
+///                 "slow {} on {:?}: {:?}, {} retries",
+///                 event.api(),
+///                 event.project(),
+///                 event.latency(),
+///                 event.retry_count()
+///             );
+///         }
+///     }
+/// }
+///
+/// let config = Config::builder()
+///     .endpoint("cn-hangzhou.log.aliyuncs.com")
+///     .access_key("access_key_id", "access_key_secret")
+///     .observer(SlowRequestLogger)
+///     .build()?;
+/// let client = Client::from_config(config)?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait RequestObserver: Send + Sync {
+    /// Called once a request is completed, successfully or not.
+    fn on_request_completed(&self, event: &RequestEvent);
+}
+
+impl<T: RequestObserver + ?Sized> RequestObserver for std::sync::Arc<T> {
+    fn on_request_completed(&self, event: &RequestEvent) {
+        (**self).on_request_completed(event)
+    }
+}
+
+/// A completed request, as seen by a [`RequestObserver`].
+#[derive(Debug, Clone, Getters, CopyGetters)]
+pub struct RequestEvent {
+    /// Name of the API, the same as the method of the client, e.g. `put_logs`
+    #[getset(get = "pub")]
+    pub(crate) api: String,
+
+    /// Project of the request, `None` for APIs which are not scoped to a project
+    #[getset(get = "pub")]
+    pub(crate) project: Option<String>,
+
+    /// HTTP status of the last response, `None` if no response was received
+    #[getset(get_copy = "pub")]
+    pub(crate) http_status: Option<u16>,
+
+    /// Error code returned by the server, if any, e.g. `WriteQuotaExceed`
+    #[getset(get = "pub")]
+    pub(crate) error_code: Option<String>,
+
+    /// Whether the request succeeded
+    #[getset(get_copy = "pub")]
+    pub(crate) success: bool,
+
+    /// Number of retries, `0` if the first attempt completed the request
+    #[getset(get_copy = "pub")]
+    pub(crate) retry_count: u32,
+
+    /// Size of the request body sent, after compression, in bytes
+    #[getset(get_copy = "pub")]
+    pub(crate) request_size: usize,
+
+    /// Size of the request body before compression, in bytes
+    #[getset(get_copy = "pub")]
+    pub(crate) request_raw_size: usize,
+
+    /// Size of the response body received, before decompression, in bytes
+    #[getset(get_copy = "pub")]
+    pub(crate) response_size: usize,
+
+    /// Size of the response body after decompression, in bytes
+    #[getset(get_copy = "pub")]
+    pub(crate) response_raw_size: usize,
+
+    /// Time taken by the request, retries and backoffs included
+    #[getset(get_copy = "pub")]
+    pub(crate) latency: Duration,
+}
+
+impl RequestEvent {
+    /// Compression ratio of the request body, raw size divided by compressed size,
+    /// `None` if the request has no body.
+    pub fn compression_ratio(&self) -> Option<f64> {
+        if self.request_size == 0 {
+            return None;
+        }
+        Some(self.request_raw_size as f64 / self.request_size as f64)
+    }
+}
+
+/// Name of the API of a request type, e.g. `put_logs` for `PutLogsRequest`.
+pub(crate) fn api_name<R>() -> String {
+    let type_name = std::any::type_name::<R>();
+    let name = type_name.rsplit("::").next().unwrap_or(type_name);
+    let name = name.strip_suffix("Request").unwrap_or(name);
+    let mut api = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                api.push('_');
+            }
+            api.push(c.to_ascii_lowercase());
+        } else {
+            api.push(c);
+        }
+    }
+    api
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct PullLogsRawRequest;
+    struct GetLogsRequest;
+
+    #[test]
+    fn test_api_name() {
+        assert_eq!(api_name::<PullLogsRawRequest>(), "pull_logs_raw");
+        assert_eq!(api_name::<GetLogsRequest>(), "get_logs");
+    }
+}
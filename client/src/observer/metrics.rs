@@ -0,0 +1,107 @@
+use super::{RequestEvent, RequestObserver};
+
+/// A [`RequestObserver`] recording the requests with the [`metrics`](::metrics) crate.
+///
+/// The following metrics are recorded, labeled with `api`, `project` and `status`,
+/// which is `ok`, the error code returned by the server, or `error` for other failures:
+///
+/// * `aliyun_log_requests_total` - Counter of completed requests
+/// * `aliyun_log_request_duration_seconds` - Histogram of the request latency, retries included
+/// * `aliyun_log_request_retries_total` - Counter of retries
+/// * `aliyun_log_request_bytes_total` / `aliyun_log_request_raw_bytes_total` - Counters of request
+///   body bytes sent, after and before compression
+/// * `aliyun_log_response_bytes_total` / `aliyun_log_response_raw_bytes_total` - Counters of
+///   response body bytes received, before and after decompression
+/// * `aliyun_log_request_compression_ratio` - Histogram of the compression ratio of request bodies
+///
+/// The prefix `aliyun_log` can be changed with [`prefix`](Self::prefix).
+/// This observer is only available with the `metrics` feature.
+///
+/// # Examples
+///
+/// ```
+/// # async fn wrapper() -> aliyun_log_rust_sdk::Result<()> {
+/// use aliyun_log_rust_sdk::{Client, Config, FromConfig, MetricsObserver};
+///
+/// let config = Config::builder()
+///     .endpoint("cn-hangzhou.log.aliyuncs.com")
+///     .access_key("access_key_id", "access_key_secret")
+///     .observer(MetricsObserver::new())
+///     .build()?;
+/// let client = Client::from_config(config)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MetricsObserver {
+    requests: String,
+    duration: String,
+    retries: String,
+    request_bytes: String,
+    request_raw_bytes: String,
+    response_bytes: String,
+    response_raw_bytes: String,
+    compression_ratio: String,
+}
+
+impl MetricsObserver {
+    /// Create an observer recording metrics prefixed with `aliyun_log`.
+    pub fn new() -> Self {
+        Self::with_prefix(DEFAULT_PREFIX)
+    }
+
+    /// Set the prefix of the metric names, default is `aliyun_log`.
+    pub fn prefix(self, prefix: impl AsRef<str>) -> Self {
+        Self::with_prefix(prefix.as_ref())
+    }
+
+    fn with_prefix(prefix: &str) -> Self {
+        Self {
+            requests: format!("{prefix}_requests_total"),
+            duration: format!("{prefix}_request_duration_seconds"),
+            retries: format!("{prefix}_request_retries_total"),
+            request_bytes: format!("{prefix}_request_bytes_total"),
+            request_raw_bytes: format!("{prefix}_request_raw_bytes_total"),
+            response_bytes: format!("{prefix}_response_bytes_total"),
+            response_raw_bytes: format!("{prefix}_response_raw_bytes_total"),
+            compression_ratio: format!("{prefix}_request_compression_ratio"),
+        }
+    }
+}
+
+impl Default for MetricsObserver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestObserver for MetricsObserver {
+    fn on_request_completed(&self, event: &RequestEvent) {
+        let status = match (event.success, &event.error_code) {
+            (true, _) => "ok".to_string(),
+            (false, Some(code)) => code.clone(),
+            (false, None) => "error".to_string(),
+        };
+        let labels = [
+            ("api", event.api.clone()),
+            ("project", event.project.clone().unwrap_or_default()),
+            ("status", status),
+        ];
+        ::metrics::counter!(self.requests.clone(), &labels).increment(1);
+        ::metrics::histogram!(self.duration.clone(), &labels).record(event.latency);
+        ::metrics::counter!(self.retries.clone(), &labels).increment(event.retry_count as u64);
+        ::metrics::counter!(self.request_bytes.clone(), &labels)
+            .increment(event.request_size as u64);
+        ::metrics::counter!(self.request_raw_bytes.clone(), &labels)
+            .increment(event.request_raw_size as u64);
+        ::metrics::counter!(self.response_bytes.clone(), &labels)
+            .increment(event.response_size as u64);
+        ::metrics::counter!(self.response_raw_bytes.clone(), &labels)
+            .increment(event.response_raw_size as u64);
+        if let Some(ratio) = event.compression_ratio() {
+            ::metrics::histogram!(self.compression_ratio.clone(), &labels).record(ratio);
+        }
+    }
+}
+
+const DEFAULT_PREFIX: &str = "aliyun_log";
@@ -0,0 +1,620 @@
+//! Typed builder for queries, escaping the values and field names they contain.
+//!
+//! A [`Condition`] renders to the search syntax of `get_logs`, and to SPL with [`Condition::to_spl`]
+//! for the `query` of `pull_logs`. A [`Query`] adds a [`Select`] statement to analyze the logs matched.
+//!
+//! # Examples
+//!
+//! ```
+//! use aliyun_log_rust_sdk::query::{field, phrase, Order, Select};
+//!
+//! let user_input = r#"timeout" or "*"#;
+//! let condition = field("level").eq("error").and(phrase(user_input).or(field("latency").gt(500)));
+//! assert_eq!(
+//!     condition.to_string(),
+//!     r#""level": "error" and (#"timeout\" or \"*" or "latency" > 500)"#
+//! );
+//!
+//! let query = condition.clone().select(
+//!     Select::new(["host", "count(*) as pv"])
+//!         .group_by(["host"])
+//!         .order_by("pv", Order::Desc)
+//!         .limit(10),
+//! );
+//! assert_eq!(
+//!     query.render()?,
+//!     format!("{condition} | SELECT host, count(*) as pv GROUP BY host ORDER BY pv DESC LIMIT 10")
+//! );
+//!
+//! let spl = field("level").eq("error").and(field("latency").between(100, 500)).to_spl()?;
+//! assert_eq!(
+//!     spl,
+//!     r#"* | where "level" = 'error' and cast("latency" as double) between 100 and 500"#
+//! );
+//! # Ok::<(), aliyun_log_rust_sdk::Error>(())
+//! ```
+
+use std::fmt::{self, Display, Formatter, Write};
+
+use crate::{RequestError, RequestErrorKind, Result};
+
+/// A search condition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition(Node);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    All,
+    Term(String),
+    Phrase(String),
+    Eq(String, String),
+    FieldPhrase(String, String),
+    Compare(String, &'static str, Number),
+    Between(String, Number, Number),
+    Exists(String),
+    And(Vec<Node>),
+    Or(Vec<Node>),
+    Not(Box<Node>),
+}
+
+/// A number compared with a field, converted from the integer and float types.
+///
+/// Floats which are not finite, `NaN` and the infinities, cannot be rendered:
+/// [`Condition::render`], [`Condition::to_spl`] and [`Query::render`] fail with them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Number {
+    value: String,
+    finite: bool,
+}
+
+macro_rules! number_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Number {
+                fn from(value: $t) -> Self {
+                    Number {
+                        value: value.to_string(),
+                        finite: true,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! number_from_float {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Number {
+                fn from(value: $t) -> Self {
+                    Number {
+                        value: value.to_string(),
+                        finite: value.is_finite(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+number_from!(i8, i16, i32, i64, u8, u16, u32, u64);
+number_from_float!(f32, f64);
+
+impl Number {
+    fn check(&self) -> std::result::Result<&str, &'static str> {
+        if self.finite {
+            Ok(&self.value)
+        } else {
+            Err("numbers compared with fields must be finite, not NaN or infinite")
+        }
+    }
+}
+
+/// Match all logs, `*`.
+pub fn all() -> Condition {
+    Condition(Node::All)
+}
+
+/// Match the logs containing a word in any field, by the full-text index.
+pub fn term(word: impl Into<String>) -> Condition {
+    Condition(Node::Term(word.into()))
+}
+
+/// Match the logs containing words in order in any field, by the full-text index.
+pub fn phrase(words: impl Into<String>) -> Condition {
+    Condition(Node::Phrase(words.into()))
+}
+
+/// A field to match logs by, using its field index.
+///
+/// Fields within a JSON field are named by their dotted path, e.g. `body.status`.
+pub fn field(name: impl Into<String>) -> Field {
+    Field(name.into())
+}
+
+/// A field to match logs by, created with [`field`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field(String);
+
+impl Field {
+    /// Match the logs whose field contains the word, or equals the value in SQL and SPL.
+    pub fn eq(self, value: impl Into<String>) -> Condition {
+        Condition(Node::Eq(self.0, value.into()))
+    }
+
+    /// Match the logs whose field contains the words in order.
+    pub fn phrase(self, words: impl Into<String>) -> Condition {
+        Condition(Node::FieldPhrase(self.0, words.into()))
+    }
+
+    /// Match the logs whose field is greater than the value.
+    pub fn gt(self, value: impl Into<Number>) -> Condition {
+        Condition(Node::Compare(self.0, ">", value.into()))
+    }
+
+    /// Match the logs whose field is greater than or equal to the value.
+    pub fn ge(self, value: impl Into<Number>) -> Condition {
+        Condition(Node::Compare(self.0, ">=", value.into()))
+    }
+
+    /// Match the logs whose field is less than the value.
+    pub fn lt(self, value: impl Into<Number>) -> Condition {
+        Condition(Node::Compare(self.0, "<", value.into()))
+    }
+
+    /// Match the logs whose field is less than or equal to the value.
+    pub fn le(self, value: impl Into<Number>) -> Condition {
+        Condition(Node::Compare(self.0, "<=", value.into()))
+    }
+
+    /// Match the logs whose field is between the values, both included.
+    pub fn between(self, low: impl Into<Number>, high: impl Into<Number>) -> Condition {
+        Condition(Node::Between(self.0, low.into(), high.into()))
+    }
+
+    /// Match the logs which have the field.
+    pub fn exists(self) -> Condition {
+        Condition(Node::Exists(self.0))
+    }
+}
+
+impl Condition {
+    /// Match the logs matching both conditions.
+    pub fn and(self, other: Condition) -> Condition {
+        Condition(match (self.0, other.0) {
+            (Node::And(mut nodes), Node::And(others)) => {
+                nodes.extend(others);
+                Node::And(nodes)
+            }
+            (Node::And(mut nodes), other) => {
+                nodes.push(other);
+                Node::And(nodes)
+            }
+            (node, Node::And(mut others)) => {
+                others.insert(0, node);
+                Node::And(others)
+            }
+            (node, other) => Node::And(vec![node, other]),
+        })
+    }
+
+    /// Match the logs matching either condition.
+    pub fn or(self, other: Condition) -> Condition {
+        Condition(match (self.0, other.0) {
+            (Node::Or(mut nodes), Node::Or(others)) => {
+                nodes.extend(others);
+                Node::Or(nodes)
+            }
+            (Node::Or(mut nodes), other) => {
+                nodes.push(other);
+                Node::Or(nodes)
+            }
+            (node, Node::Or(mut others)) => {
+                others.insert(0, node);
+                Node::Or(others)
+            }
+            (node, other) => Node::Or(vec![node, other]),
+        })
+    }
+
+    /// Match the logs not matching the condition.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Condition {
+        Condition(Node::Not(Box::new(self.0)))
+    }
+
+    /// Analyze the logs matched with a SQL statement.
+    pub fn select(self, select: Select) -> Query {
+        Query {
+            condition: self,
+            select,
+        }
+    }
+
+    /// Render the condition in the search syntax, for the `query` of `get_logs`.
+    ///
+    /// Same as `to_string`, but fails if the condition compares a field with a float which is not finite.
+    pub fn render(&self) -> Result<String> {
+        check_numbers(&self.0).map_err(invalid_parameter)?;
+        Ok(self.to_string())
+    }
+
+    /// Render the condition as SPL, for the `query` of `pull_logs`.
+    ///
+    /// Fails if the condition contains full-text matches, made with [`term`] or [`phrase`],
+    /// which SPL does not support, or compares a field with a float which is not finite.
+    pub fn to_spl(&self) -> Result<String> {
+        if self.0 == Node::All {
+            return Ok("*".to_string());
+        }
+        Ok(format!("* | where {}", self.to_sql()?))
+    }
+
+    /// Render the condition as a SQL boolean expression.
+    fn to_sql(&self) -> Result<String> {
+        let mut sql = String::new();
+        write_sql(&mut sql, &self.0).map_err(invalid_parameter)?;
+        Ok(sql)
+    }
+}
+
+fn invalid_parameter(message: &str) -> RequestError {
+    RequestError::from(RequestErrorKind::InvalidParameter(message.to_string()))
+}
+
+/// Render the condition in the search syntax, for the `query` of `get_logs`.
+///
+/// Floats which are not finite are rendered as is, e.g. `NaN`, which the server rejects;
+/// use [`Condition::render`] to check them.
+impl Display for Condition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_search(f, &self.0)
+    }
+}
+
+impl From<Condition> for String {
+    fn from(condition: Condition) -> Self {
+        condition.to_string()
+    }
+}
+
+/// Sort order of [`Select::order_by`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+/// A SQL statement analyzing the logs matched by a condition.
+///
+/// Column, grouping and sorting expressions are SQL written as is, so values from users
+/// must be quoted with [`quote_literal`] and [`quote_ident`], or filtered with [`Select::filter`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Select {
+    columns: Vec<String>,
+    filter: Option<Condition>,
+    group_by: Vec<String>,
+    order_by: Vec<(String, Order)>,
+    limit: Option<u64>,
+}
+
+impl Select {
+    /// Select the columns, SQL expressions such as `count(*) as pv`.
+    pub fn new(columns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Select {
+            columns: columns.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Filter the logs analyzed with a `WHERE` clause.
+    ///
+    /// The condition cannot contain full-text matches, made with [`term`] or [`phrase`].
+    pub fn filter(mut self, condition: Condition) -> Self {
+        self.filter = Some(condition);
+        self
+    }
+
+    /// Group the rows by SQL expressions.
+    pub fn group_by(mut self, columns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.group_by = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sort the rows by a SQL expression, after the ones added before.
+    pub fn order_by(mut self, column: impl Into<String>, order: Order) -> Self {
+        self.order_by.push((column.into(), order));
+        self
+    }
+
+    /// Return at most `limit` rows.
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    fn to_sql(&self) -> Result<String> {
+        let mut sql = format!("SELECT {}", self.columns.join(", "));
+        if let Some(filter) = &self.filter {
+            sql.push_str(" WHERE ");
+            sql.push_str(&filter.to_sql()?);
+        }
+        if !self.group_by.is_empty() {
+            sql.push_str(" GROUP BY ");
+            sql.push_str(&self.group_by.join(", "));
+        }
+        if !self.order_by.is_empty() {
+            let order_by: Vec<String> = self
+                .order_by
+                .iter()
+                .map(|(column, order)| match order {
+                    Order::Asc => format!("{column} ASC"),
+                    Order::Desc => format!("{column} DESC"),
+                })
+                .collect();
+            sql.push_str(" ORDER BY ");
+            sql.push_str(&order_by.join(", "));
+        }
+        if let Some(limit) = self.limit {
+            let _ = write!(sql, " LIMIT {limit}");
+        }
+        Ok(sql)
+    }
+}
+
+/// A search condition with a SQL statement analyzing the logs matched, for the `query` of `get_logs`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    condition: Condition,
+    select: Select,
+}
+
+impl Query {
+    /// Render the query, `<condition> | SELECT ...`.
+    ///
+    /// Fails if the filter of the statement contains full-text matches,
+    /// or if the query compares a field with a float which is not finite.
+    pub fn render(&self) -> Result<String> {
+        Ok(format!(
+            "{} | {}",
+            self.condition.render()?,
+            self.select.to_sql()?
+        ))
+    }
+}
+
+/// Quote a string as a SQL literal, e.g. `'it''s'`.
+pub fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Quote a name as a SQL identifier, e.g. `"user.name"`.
+pub fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Quote a word or field name in the search syntax, escaping backslashes and double quotes.
+fn quote_search(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn write_search(f: &mut Formatter<'_>, node: &Node) -> fmt::Result {
+    match node {
+        Node::All => f.write_char('*'),
+        Node::Term(word) => f.write_str(&quote_search(word)),
+        Node::Phrase(words) => write!(f, "#{}", quote_search(words)),
+        Node::Eq(field, value) => write!(f, "{}: {}", quote_search(field), quote_search(value)),
+        Node::FieldPhrase(field, words) => {
+            write!(f, "{}: #{}", quote_search(field), quote_search(words))
+        }
+        Node::Compare(field, op, value) => {
+            write!(f, "{} {op} {}", quote_search(field), value.value)
+        }
+        Node::Between(field, low, high) => {
+            write!(
+                f,
+                "{} in [{} {}]",
+                quote_search(field),
+                low.value,
+                high.value
+            )
+        }
+        Node::Exists(field) => write!(f, "{}: *", quote_search(field)),
+        Node::And(nodes) | Node::Or(nodes) => {
+            let separator = if matches!(node, Node::And(_)) {
+                " and "
+            } else {
+                " or "
+            };
+            for (i, node) in nodes.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(separator)?;
+                }
+                write_search_operand(f, node)?;
+            }
+            Ok(())
+        }
+        Node::Not(node) => {
+            f.write_str("not ")?;
+            write_search_operand(f, node)
+        }
+    }
+}
+
+fn write_sql(f: &mut String, node: &Node) -> std::result::Result<(), &'static str> {
+    match node {
+        Node::All => f.push_str("true"),
+        Node::Term(_) | Node::Phrase(_) => {
+            return Err("full-text matches can only be rendered in the search syntax")
+        }
+        Node::Eq(field, value) => {
+            let _ = write!(f, "{} = {}", quote_ident(field), quote_literal(value));
+        }
+        Node::FieldPhrase(field, words) => {
+            let _ = write!(
+                f,
+                "strpos({}, {}) > 0",
+                quote_ident(field),
+                quote_literal(words)
+            );
+        }
+        Node::Compare(field, op, value) => {
+            let _ = write!(
+                f,
+                "cast({} as double) {op} {}",
+                quote_ident(field),
+                value.check()?
+            );
+        }
+        Node::Between(field, low, high) => {
+            let _ = write!(
+                f,
+                "cast({} as double) between {} and {}",
+                quote_ident(field),
+                low.check()?,
+                high.check()?
+            );
+        }
+        Node::Exists(field) => {
+            let _ = write!(f, "{} is not null", quote_ident(field));
+        }
+        Node::And(nodes) | Node::Or(nodes) => {
+            let separator = if matches!(node, Node::And(_)) {
+                " and "
+            } else {
+                " or "
+            };
+            for (i, node) in nodes.iter().enumerate() {
+                if i > 0 {
+                    f.push_str(separator);
+                }
+                write_sql_operand(f, node)?;
+            }
+        }
+        Node::Not(node) => {
+            f.push_str("not (");
+            write_sql(f, node)?;
+            f.push(')');
+        }
+    }
+    Ok(())
+}
+
+/// Check that the numbers compared with fields can be rendered.
+fn check_numbers(node: &Node) -> std::result::Result<(), &'static str> {
+    match node {
+        Node::Compare(_, _, value) => value.check().map(|_| ()),
+        Node::Between(_, low, high) => low.check().and(high.check()).map(|_| ()),
+        Node::And(nodes) | Node::Or(nodes) => nodes.iter().try_for_each(check_numbers),
+        Node::Not(node) => check_numbers(node),
+        _ => Ok(()),
+    }
+}
+
+fn write_sql_operand(f: &mut String, node: &Node) -> std::result::Result<(), &'static str> {
+    if matches!(node, Node::And(_) | Node::Or(_)) {
+        f.push('(');
+        write_sql(f, node)?;
+        f.push(')');
+        Ok(())
+    } else {
+        write_sql(f, node)
+    }
+}
+
+fn write_search_operand(f: &mut Formatter<'_>, node: &Node) -> fmt::Result {
+    if is_compound(node) {
+        f.write_char('(')?;
+        write_search(f, node)?;
+        f.write_char(')')
+    } else {
+        write_search(f, node)
+    }
+}
+
+fn is_compound(node: &Node) -> bool {
+    matches!(node, Node::And(_) | Node::Or(_) | Node::Not(_))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search() {
+        assert_eq!(all().to_string(), "*");
+        assert_eq!(term(r#"a"b\c"#).to_string(), r#""a\"b\\c""#);
+        assert_eq!(
+            field("host")
+                .eq("a")
+                .or(field("host").eq("b"))
+                .and(field("status").ge(500))
+                .to_string(),
+            r#"("host": "a" or "host": "b") and "status" >= 500"#
+        );
+        assert_eq!(
+            field("a")
+                .exists()
+                .and(field("b").phrase("x y").not())
+                .to_string(),
+            r#""a": * and (not "b": #"x y")"#
+        );
+        assert_eq!(
+            field("a").eq("1").and(field("b").eq("2")).not().to_string(),
+            r#"not ("a": "1" and "b": "2")"#
+        );
+        assert_eq!(
+            field("latency").between(0.5, 10).to_string(),
+            r#""latency" in [0.5 10]"#
+        );
+    }
+
+    #[test]
+    fn test_sql() {
+        assert_eq!(all().to_spl().unwrap(), "*");
+        assert_eq!(
+            field("name")
+                .eq("it's")
+                .or(field("a\"b").exists().not())
+                .to_spl()
+                .unwrap(),
+            r#"* | where "name" = 'it''s' or not ("a""b" is not null)"#
+        );
+        assert!(term("error").to_spl().is_err());
+
+        let query = phrase("timeout").select(
+            Select::new(["count(*) as pv"])
+                .filter(field("status").lt(300).and(field("uri").phrase("/api"))),
+        );
+        assert_eq!(
+            query.render().unwrap(),
+            r#"#"timeout" | SELECT count(*) as pv WHERE cast("status" as double) < 300 and strpos("uri", '/api') > 0"#
+        );
+        let query = all().select(Select::new(["*"]).filter(term("x")));
+        assert!(query.render().is_err());
+    }
+
+    #[test]
+    fn test_non_finite_numbers() {
+        assert_eq!(
+            field("latency").gt(0.5).render().unwrap(),
+            r#""latency" > 0.5"#
+        );
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let condition = field("a").eq("x").and(field("latency").gt(value).not());
+            assert!(condition.render().is_err());
+            assert!(condition.to_spl().is_err());
+            assert!(condition
+                .clone()
+                .select(Select::new(["*"]))
+                .render()
+                .is_err());
+            assert!(all()
+                .select(Select::new(["*"]).filter(condition))
+                .render()
+                .is_err());
+            assert!(field("latency").between(0, value).to_spl().is_err());
+        }
+        assert!(field("latency").lt(f32::NAN).render().is_err());
+    }
+}
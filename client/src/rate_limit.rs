@@ -0,0 +1,540 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::response::Response;
+use crate::{ErrorCode, ListShardsResponse};
+
+/// Client-side rate limit of the writes of `put_logs` and `put_logs_raw`.
+///
+/// Writes are delayed to stay under the configured bytes and requests per second,
+/// counted separately for each project, logstore or shard depending on the [`RateLimitScope`].
+/// Bytes are counted before compression.
+///
+/// When the server rejects a write with `WriteQuotaExceed` or `ShardWriteQuotaExceed`,
+/// the rate of its scope is halved, down to a tenth of the configured rate, and the write is retried
+/// after a backoff, up to the maximum number of retries of the request.
+/// The rate then recovers by a tenth of the configured rate every second.
+///
+/// # Examples
+///
+/// ```
+/// # async fn wrapper() -> aliyun_log_rust_sdk::Result<()> {
+/// use aliyun_log_rust_sdk::{Client, Config, FromConfig, RateLimitScope, WriteRateLimit};
+///
+/// let config = Config::builder()
+///     .endpoint("cn-hangzhou.log.aliyuncs.com")
+///     .access_key("access_key_id", "access_key_secret")
+///     .write_rate_limit(
+///         WriteRateLimit::new(RateLimitScope::Logstore)
+///             .bytes_per_second(5 * 1024 * 1024)
+///             .requests_per_second(100),
+///     )
+///     .build()?;
+/// let client = Client::from_config(config)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WriteRateLimit {
+    scope: RateLimitScope,
+    bytes_per_second: Option<u64>,
+    requests_per_second: Option<u32>,
+}
+
+impl WriteRateLimit {
+    /// Create a rate limit counted separately for each `scope`, without any limit set.
+    ///
+    /// Without limits, writes are not delayed but still retried on write quota errors.
+    pub fn new(scope: RateLimitScope) -> Self {
+        Self {
+            scope,
+            bytes_per_second: None,
+            requests_per_second: None,
+        }
+    }
+
+    /// Limit the bytes written per second, before compression, 0 for no limit.
+    pub fn bytes_per_second(mut self, bytes_per_second: u64) -> Self {
+        self.bytes_per_second = Some(bytes_per_second).filter(|&rate| rate > 0);
+        self
+    }
+
+    /// Limit the write requests per second, 0 for no limit.
+    pub fn requests_per_second(mut self, requests_per_second: u32) -> Self {
+        self.requests_per_second = Some(requests_per_second).filter(|&rate| rate > 0);
+        self
+    }
+}
+
+/// What a [`WriteRateLimit`] is counted for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RateLimitScope {
+    /// All the writes to a project share the limit
+    Project,
+    /// All the writes to a logstore share the limit
+    Logstore,
+    /// All the writes to a shard share the limit, whatever their hash key.
+    /// Load balanced writes, without a hash key, share the limit of their logstore.
+    ///
+    /// Hash keys are resolved to shards with the key ranges returned by
+    /// [`list_shards`](crate::Client::list_shards), listed on the first write to a logstore,
+    /// then again every minute to follow shard splits and merges.
+    /// The writes whose shard is not known, e.g. because the shards could not be listed,
+    /// share the limit of their logstore.
+    Shard,
+}
+
+/// The rate limit of a write request, with the key of its bucket.
+#[derive(Clone)]
+pub(crate) struct WriteLimit {
+    rate_limiter: Arc<RateLimiter>,
+    key: String,
+}
+
+impl WriteLimit {
+    pub(crate) fn new(rate_limiter: Arc<RateLimiter>, key: String) -> Self {
+        Self { rate_limiter, key }
+    }
+
+    /// Wait until a write of `bytes` is allowed.
+    pub(crate) async fn acquire(&self, bytes: usize) {
+        self.rate_limiter.acquire(&self.key, bytes).await
+    }
+
+    /// Reduce the rate after the server rejected a write with a quota error.
+    pub(crate) fn throttled(&self) {
+        self.rate_limiter.throttled(&self.key)
+    }
+}
+
+/// Where a write request goes, used to find its rate limit.
+pub(crate) struct WriteTarget<'a> {
+    pub(crate) logstore: &'a str,
+    pub(crate) hash_key: Option<&'a str>,
+}
+
+pub(crate) struct RateLimiter {
+    limit: WriteRateLimit,
+    buckets: Mutex<Buckets>,
+    /// The shards of the logstores written to, by `{project}/{logstore}`.
+    shards: Mutex<HashMap<String, Arc<LogstoreShards>>>,
+}
+
+/// The shards of a logstore, `None` until listed.
+/// Locked while the shards are listed, so that concurrent writes to the logstore list them once,
+/// without waiting for the writes to other logstores.
+type LogstoreShards = futures_util::lock::Mutex<Option<ShardRanges>>;
+
+/// The hash key ranges of the writable shards of a logstore.
+struct ShardRanges {
+    /// `(inclusive begin key, exclusive end key, shard id)`
+    ranges: Vec<(String, String, i32)>,
+    listed: Instant,
+}
+
+impl ShardRanges {
+    fn new(shards: &ListShardsResponse, listed: Instant) -> Self {
+        let ranges = shards
+            .shards()
+            .iter()
+            .filter(|shard| shard.status() == "readwrite")
+            .map(|shard| {
+                (
+                    shard.inclusive_begin_key().to_ascii_lowercase(),
+                    shard.exclusive_end_key().to_ascii_lowercase(),
+                    *shard.shard_id(),
+                )
+            })
+            .collect();
+        Self { ranges, listed }
+    }
+
+    fn find(&self, hash_key: &str) -> Option<i32> {
+        let hash_key = hash_key.to_ascii_lowercase();
+        self.ranges
+            .iter()
+            .find(|(begin, end, _)| *begin <= hash_key && hash_key < *end)
+            .map(|(_, _, shard_id)| *shard_id)
+    }
+}
+
+/// The buckets of the keys written to recently.
+struct Buckets {
+    buckets: HashMap<String, Bucket>,
+    pruned: Instant,
+}
+
+impl Buckets {
+    fn get(&mut self, key: &str, limit: &WriteRateLimit, now: Instant) -> &mut Bucket {
+        if now.saturating_duration_since(self.pruned) >= IDLE_TIMEOUT {
+            // idle buckets are full again, so dropping them is the same as keeping them
+            self.buckets
+                .retain(|_, bucket| now.saturating_duration_since(bucket.updated) < IDLE_TIMEOUT);
+            self.pruned = now;
+        }
+        self.buckets
+            .entry(key.to_string())
+            .or_insert_with(|| Bucket::new(limit, now))
+    }
+}
+
+impl RateLimiter {
+    pub(crate) fn new(limit: WriteRateLimit) -> Self {
+        Self {
+            limit,
+            buckets: Mutex::new(Buckets {
+                buckets: HashMap::new(),
+                pruned: Instant::now(),
+            }),
+            shards: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Whether the shard of a write routed by hash key is part of the key of its bucket.
+    pub(crate) fn by_shard(&self) -> bool {
+        self.limit.scope == RateLimitScope::Shard
+    }
+
+    /// The key of the bucket of a write, given the shard it is routed to, if known.
+    pub(crate) fn key(&self, project: &str, target: &WriteTarget, shard: Option<i32>) -> String {
+        match (self.limit.scope, shard) {
+            (RateLimitScope::Project, _) => project.to_string(),
+            (RateLimitScope::Shard, Some(shard)) => {
+                format!("{project}/{}/{shard}", target.logstore)
+            }
+            _ => format!("{project}/{}", target.logstore),
+        }
+    }
+
+    /// The shard whose key range contains `hash_key`, awaiting `list_shards`
+    /// if the shards of the logstore are not known or outdated.
+    pub(crate) async fn shard_of<F>(
+        &self,
+        project: &str,
+        target: &WriteTarget<'_>,
+        hash_key: &str,
+        list_shards: F,
+    ) -> Option<i32>
+    where
+        F: Future<Output = crate::Result<Response<ListShardsResponse>>>,
+    {
+        let logstore = format!("{project}/{}", target.logstore);
+        let entry = self
+            .shards
+            .lock()
+            .unwrap()
+            .entry(logstore.clone())
+            .or_default()
+            .clone();
+        let mut shards = entry.lock().await;
+        let now = Instant::now();
+        let outdated = shards
+            .as_ref()
+            .is_none_or(|ranges| now.saturating_duration_since(ranges.listed) >= SHARDS_REFRESH);
+        if outdated {
+            let ranges = match list_shards.await {
+                Ok(resp) => ShardRanges::new(resp.get_body(), now),
+                Err(err) => {
+                    // kept until the next refresh, so that a failing logstore is not listed on every write
+                    tracing::debug!(logstore, error = %err, "failed to list shards for rate limit");
+                    ShardRanges {
+                        ranges: Vec::new(),
+                        listed: now,
+                    }
+                }
+            };
+            *shards = Some(ranges);
+        }
+        shards.as_ref().and_then(|ranges| ranges.find(hash_key))
+    }
+
+    /// Wait until a write of `bytes` to `key` is allowed.
+    pub(crate) async fn acquire(&self, key: &str, bytes: usize) {
+        let wait = self.reserve(key, bytes, Instant::now());
+        if !wait.is_zero() {
+            tracing::debug!(
+                key,
+                wait_ms = wait.as_millis() as u64,
+                "write delayed by rate limit"
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Reduce the rate of `key` after the server rejected a write with a quota error.
+    pub(crate) fn throttled(&self, key: &str) {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        buckets
+            .get(key, &self.limit, now)
+            .throttled(&self.limit, now);
+    }
+
+    /// Take the tokens of a write, and return how long to wait before sending it.
+    fn reserve(&self, key: &str, bytes: usize, now: Instant) -> Duration {
+        let mut buckets = self.buckets.lock().unwrap();
+        buckets
+            .get(key, &self.limit, now)
+            .reserve(&self.limit, bytes as f64, now)
+    }
+}
+
+/// A token bucket, which may go into debt to let writes larger than a second of tokens through.
+struct Bucket {
+    bytes: f64,
+    requests: f64,
+    /// Fraction of the configured rates currently allowed, reduced on quota errors
+    factor: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(limit: &WriteRateLimit, now: Instant) -> Self {
+        Self {
+            bytes: limit.bytes_per_second.unwrap_or_default() as f64,
+            requests: limit.requests_per_second.unwrap_or_default() as f64,
+            factor: 1.0,
+            updated: now,
+        }
+    }
+
+    fn refill(&mut self, limit: &WriteRateLimit, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.updated = now;
+        self.factor = (self.factor + RECOVERY_PER_SECOND * elapsed).min(1.0);
+        if let Some(rate) = limit.bytes_per_second {
+            let rate = rate as f64 * self.factor;
+            self.bytes = (self.bytes + rate * elapsed).min(rate);
+        }
+        if let Some(rate) = limit.requests_per_second {
+            let rate = rate as f64 * self.factor;
+            self.requests = (self.requests + rate * elapsed).min(rate);
+        }
+    }
+
+    fn reserve(&mut self, limit: &WriteRateLimit, bytes: f64, now: Instant) -> Duration {
+        self.refill(limit, now);
+        let mut wait: f64 = 0.0;
+        if let Some(rate) = limit.bytes_per_second {
+            self.bytes -= bytes;
+            wait = wait.max(-self.bytes / (rate as f64 * self.factor));
+        }
+        if let Some(rate) = limit.requests_per_second {
+            self.requests -= 1.0;
+            wait = wait.max(-self.requests / (rate as f64 * self.factor));
+        }
+        Duration::from_secs_f64(wait.max(0.0))
+    }
+
+    fn throttled(&mut self, limit: &WriteRateLimit, now: Instant) {
+        self.refill(limit, now);
+        self.factor = (self.factor * 0.5).max(MIN_FACTOR);
+        self.bytes = self.bytes.min(0.0);
+        self.requests = self.requests.min(0.0);
+    }
+}
+
+/// Whether an error code is a write quota error, which the rate limiter reacts to.
+pub(crate) fn is_write_quota_error(error_code: &ErrorCode) -> bool {
+    matches!(
+        error_code,
+        ErrorCode::WriteQuotaExceed | ErrorCode::ShardWriteQuotaExceed
+    )
+}
+
+const MIN_FACTOR: f64 = 0.1;
+const RECOVERY_PER_SECOND: f64 = 0.1;
+/// Time after which a bucket is full and its rate recovered, whatever its state.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+/// Time after which the shards of a logstore are listed again.
+const SHARDS_REFRESH: Duration = Duration::from_secs(60);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket() {
+        let limit = WriteRateLimit::new(RateLimitScope::Logstore)
+            .bytes_per_second(1000)
+            .requests_per_second(10);
+        let now = Instant::now();
+        let mut bucket = Bucket::new(&limit, now);
+
+        // a second of tokens is available at first
+        assert_eq!(bucket.reserve(&limit, 500.0, now), Duration::ZERO);
+        assert_eq!(bucket.reserve(&limit, 500.0, now), Duration::ZERO);
+        assert_eq!(
+            bucket.reserve(&limit, 500.0, now),
+            Duration::from_millis(500)
+        );
+
+        // the debt is paid back over time
+        let now = now + Duration::from_secs(2);
+        assert_eq!(bucket.reserve(&limit, 100.0, now), Duration::ZERO);
+
+        // the rate is halved on quota errors, then recovers
+        bucket.throttled(&limit, now);
+        assert_eq!(bucket.factor, 0.5);
+        assert_eq!(
+            bucket.reserve(&limit, 250.0, now),
+            Duration::from_millis(500)
+        );
+        let now = now + Duration::from_secs(10);
+        bucket.refill(&limit, now);
+        assert_eq!(bucket.factor, 1.0);
+    }
+
+    #[test]
+    fn test_unlimited() {
+        let limit = WriteRateLimit::new(RateLimitScope::Logstore)
+            .bytes_per_second(0)
+            .requests_per_second(0);
+        let now = Instant::now();
+        let mut bucket = Bucket::new(&limit, now);
+        assert_eq!(bucket.reserve(&limit, 1e9, now), Duration::ZERO);
+        bucket.throttled(&limit, now);
+        assert_eq!(bucket.reserve(&limit, 1e9, now), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_prune_buckets() {
+        let limiter = RateLimiter::new(WriteRateLimit::new(RateLimitScope::Shard));
+        let now = Instant::now();
+        limiter.reserve("a", 1, now);
+        limiter.reserve("b", 1, now + Duration::from_secs(30));
+        limiter.reserve("c", 1, now + IDLE_TIMEOUT + Duration::from_secs(1));
+        let buckets = limiter.buckets.lock().unwrap();
+        let mut keys: Vec<&String> = buckets.buckets.keys().collect();
+        keys.sort();
+        assert_eq!(keys, ["b", "c"]);
+    }
+
+    #[test]
+    fn test_rate_limiter_key() {
+        let target = WriteTarget {
+            logstore: "my-logstore",
+            hash_key: Some("00000000000000000000000000000000"),
+        };
+        let limiter = |scope| RateLimiter::new(WriteRateLimit::new(scope));
+        assert_eq!(
+            limiter(RateLimitScope::Project).key("my-project", &target, Some(1)),
+            "my-project"
+        );
+        assert_eq!(
+            limiter(RateLimitScope::Logstore).key("my-project", &target, Some(1)),
+            "my-project/my-logstore"
+        );
+        assert_eq!(
+            limiter(RateLimitScope::Shard).key("my-project", &target, Some(1)),
+            "my-project/my-logstore/1"
+        );
+        assert_eq!(
+            limiter(RateLimitScope::Shard).key("my-project", &target, None),
+            "my-project/my-logstore"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_shard_of() {
+        let limiter = RateLimiter::new(WriteRateLimit::new(RateLimitScope::Shard));
+        let target = WriteTarget {
+            logstore: "my-logstore",
+            hash_key: None,
+        };
+        let list_shards = || async {
+            let body = r#"[
+                {"shardID": 0, "status": "readonly", "inclusiveBeginKey": "00000000000000000000000000000000", "exclusiveEndKey": "ffffffffffffffffffffffffffffffff", "createTime": 0},
+                {"shardID": 1, "status": "readwrite", "inclusiveBeginKey": "00000000000000000000000000000000", "exclusiveEndKey": "80000000000000000000000000000000", "createTime": 0},
+                {"shardID": 2, "status": "readwrite", "inclusiveBeginKey": "80000000000000000000000000000000", "exclusiveEndKey": "ffffffffffffffffffffffffffffffff", "createTime": 0}
+            ]"#;
+            let headers = http::HeaderMap::new();
+            let body = <ListShardsResponse as crate::response::FromHttpResponse>::try_from(
+                body.into(),
+                &headers,
+            )?;
+            Ok(Response {
+                body,
+                headers,
+                status: http::StatusCode::OK,
+            })
+        };
+        let shard_of = |hash_key| limiter.shard_of("my-project", &target, hash_key, list_shards());
+        assert_eq!(shard_of("00000000000000000000000000000000").await, Some(1));
+        assert_eq!(shard_of("7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF").await, Some(1));
+        assert_eq!(shard_of("80000000000000000000000000000000").await, Some(2));
+
+        // the shards are listed once, then kept until the next refresh
+        let failing = async { Err(crate::Error::DeadlineExceeded) };
+        assert_eq!(
+            limiter
+                .shard_of(
+                    "my-project",
+                    &target,
+                    "80000000000000000000000000000000",
+                    failing
+                )
+                .await,
+            Some(2)
+        );
+
+        // writes to a logstore whose shards could not be listed share its limit
+        let other = WriteTarget {
+            logstore: "other-logstore",
+            hash_key: None,
+        };
+        let failing = async { Err(crate::Error::DeadlineExceeded) };
+        assert_eq!(
+            limiter
+                .shard_of(
+                    "my-project",
+                    &other,
+                    "80000000000000000000000000000000",
+                    failing
+                )
+                .await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_shard_of_concurrent_logstores() {
+        let limiter = RateLimiter::new(WriteRateLimit::new(RateLimitScope::Shard));
+        let target = |logstore| WriteTarget {
+            logstore,
+            hash_key: None,
+        };
+        let hash_key = "00000000000000000000000000000000";
+        let slow = target("slow-logstore");
+        let mut slow_write = std::pin::pin!(limiter.shard_of(
+            "my-project",
+            &slow,
+            hash_key,
+            std::future::pending::<crate::Result<Response<ListShardsResponse>>>(),
+        ));
+        assert!(futures_util::poll!(&mut slow_write).is_pending());
+
+        // the shards of another logstore are listed while the ones of the first are pending
+        let other = target("other-logstore");
+        let list_shards = async {
+            let body = r#"[{"shardID": 3, "status": "readwrite", "inclusiveBeginKey": "00000000000000000000000000000000", "exclusiveEndKey": "ffffffffffffffffffffffffffffffff", "createTime": 0}]"#;
+            let headers = http::HeaderMap::new();
+            let body = <ListShardsResponse as crate::response::FromHttpResponse>::try_from(
+                body.into(),
+                &headers,
+            )?;
+            Ok(Response {
+                body,
+                headers,
+                status: http::StatusCode::OK,
+            })
+        };
+        let other_write = limiter.shard_of("my-project", &other, hash_key, list_shards);
+        let shard = tokio::time::timeout(Duration::from_secs(1), other_write).await;
+        assert_eq!(shard.unwrap(), Some(3));
+        assert!(futures_util::poll!(&mut slow_write).is_pending());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::compress::CompressType;
+use crate::rate_limit::WriteTarget;
 use crate::response::FromHttpResponse;
 use crate::RequestError;
 
@@ -20,4 +21,9 @@ pub(crate) trait Request: Sized + Send + Sync {
     fn headers(&self) -> http::HeaderMap {
         http::HeaderMap::new()
     }
+
+    /// The logstore written to, for requests which are subject to the write rate limit.
+    fn write_target(&self) -> Option<WriteTarget<'_>> {
+        None
+    }
 }
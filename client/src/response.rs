@@ -41,6 +41,8 @@ pub(crate) struct DecompressedResponse {
     pub(crate) headers: http::HeaderMap,
     pub(crate) status: http::status::StatusCode,
     pub(crate) decompressed: Vec<u8>,
+    /// size of the response body before decompression
+    pub(crate) body_size: usize,
 }
 
 pub trait FromHttpResponse: Sized {
@@ -0,0 +1,166 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::*;
+    use aliyun_log_rust_sdk::Client;
+    use aliyun_log_rust_sdk::FromConfig;
+    use aliyun_log_rust_sdk::*;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref TEST_CLIENT: Client = {
+            Client::from_config(
+                Config::builder()
+                    .access_key(&TEST_ENV.access_key_id, &TEST_ENV.access_key_secret)
+                    .endpoint(&TEST_ENV.endpoint)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap()
+        };
+    }
+
+    fn init() {
+        let _ = env_logger::builder()
+            .is_test(true)
+            .filter_level(log::LevelFilter::Debug)
+            .try_init();
+    }
+
+    fn get_test_alert_name() -> String {
+        "rust-sdk-test-alert".to_string()
+    }
+
+    fn test_configuration(threshold: i32) -> AlertConfiguration {
+        AlertConfiguration::builder()
+            .query(AlertQuery::new(
+                &TEST_ENV.project,
+                &TEST_ENV.logstore,
+                "* | select count(*) as cnt",
+            ))
+            .severity(AlertSeverityConfiguration::new(
+                AlertSeverity::High,
+                AlertEvalCondition::new("cnt > 100000000"),
+            ))
+            .annotation("title", "rust sdk test alert")
+            .threshold(threshold)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_alert_lifecycle() {
+        init();
+
+        let client = &TEST_CLIENT;
+        let project = &TEST_ENV.project;
+        let alert_name = get_test_alert_name();
+
+        match client.delete_alert(project, &alert_name).send().await {
+            Ok(_) => {}
+            Err(e) => {
+                if !matches!(&e, aliyun_log_rust_sdk::Error::Server { error_code, .. } if error_code == "JobNotExist")
+                {
+                    eprintln!("Warning: Failed to cleanup alert: {}", e);
+                }
+            }
+        }
+
+        client
+            .create_alert(project, &alert_name)
+            .display_name("rust sdk test alert")
+            .description("created by rust sdk integration test")
+            .configuration(test_configuration(1))
+            .schedule(Schedule::fixed_rate("5m"))
+            .send()
+            .await
+            .expect("Failed to create alert");
+
+        let resp = client
+            .get_alert(project, &alert_name)
+            .send()
+            .await
+            .expect("Failed to get alert");
+        let alert = resp.get_body();
+        assert_eq!(alert.name(), &alert_name);
+        assert!(alert.is_enabled());
+        assert_eq!(alert.configuration().query_list.len(), 1);
+        assert_eq!(
+            alert.configuration().severity_configurations[0].severity,
+            AlertSeverity::High
+        );
+
+        client
+            .update_alert(project, &alert_name)
+            .display_name("rust sdk test alert updated")
+            .configuration(test_configuration(3))
+            .schedule(Schedule::fixed_rate("10m"))
+            .send()
+            .await
+            .expect("Failed to update alert");
+
+        let resp = client
+            .get_alert(project, &alert_name)
+            .send()
+            .await
+            .expect("Failed to get updated alert");
+        assert_eq!(
+            resp.get_body().display_name(),
+            "rust sdk test alert updated"
+        );
+        assert_eq!(resp.get_body().configuration().threshold, 3);
+
+        client
+            .disable_alert(project, &alert_name)
+            .send()
+            .await
+            .expect("Failed to disable alert");
+        let resp = client.get_alert(project, &alert_name).send().await.unwrap();
+        assert!(!resp.get_body().is_enabled());
+
+        client
+            .enable_alert(project, &alert_name)
+            .send()
+            .await
+            .expect("Failed to enable alert");
+        let resp = client.get_alert(project, &alert_name).send().await.unwrap();
+        assert!(resp.get_body().is_enabled());
+
+        let resp = client
+            .list_alerts(project, 0, 100)
+            .send()
+            .await
+            .expect("Failed to list alerts");
+        assert!(resp
+            .get_body()
+            .alerts()
+            .iter()
+            .any(|alert| alert.name() == &alert_name));
+
+        client
+            .delete_alert(project, &alert_name)
+            .send()
+            .await
+            .expect("Failed to delete alert");
+
+        let result = client.get_alert(project, &alert_name).send().await;
+        assert!(result.is_err(), "Getting deleted alert should return error");
+    }
+
+    #[tokio::test]
+    async fn test_create_alert_missing_parameters() {
+        init();
+
+        let result = TEST_CLIENT
+            .create_alert(&TEST_ENV.project, get_test_alert_name())
+            .display_name("missing configuration")
+            .schedule(Schedule::fixed_rate("1m"))
+            .send()
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(aliyun_log_rust_sdk::Error::RequestPreparation(_))
+        ));
+    }
+}
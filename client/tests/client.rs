@@ -0,0 +1,194 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::*;
+    use aliyun_log_rust_sdk::Client;
+    use aliyun_log_rust_sdk::FromConfig;
+    use aliyun_log_rust_sdk::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    fn init() {
+        let _ = env_logger::builder()
+            .is_test(true)
+            .filter_level(log::LevelFilter::Debug)
+            .try_init();
+    }
+
+    #[tokio::test]
+    async fn layer_test() {
+        init();
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = count.clone();
+        let client = Client::from_config(
+            Config::builder()
+                .access_key(&TEST_ENV.access_key_id, &TEST_ENV.access_key_secret)
+                .endpoint(&TEST_ENV.endpoint)
+                .layer(tower::layer::layer_fn(move |service: HttpService| {
+                    let counter = counter.clone();
+                    service.map_request(move |req: reqwest::Request| {
+                        counter.fetch_add(1, Ordering::SeqCst);
+                        req
+                    })
+                }))
+                .layer(tower::limit::ConcurrencyLimitLayer::new(1))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+        client.get_project(&TEST_ENV.project).send().await.unwrap();
+        client
+            .list_logstores(&TEST_ENV.project, 0, 10)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn transport_test() {
+        init();
+        let client = Client::from_config(
+            Config::builder()
+                .access_key(&TEST_ENV.access_key_id, &TEST_ENV.access_key_secret)
+                .endpoint(&TEST_ENV.endpoint)
+                .pool_max_idle_per_host(4)
+                .pool_idle_timeout(std::time::Duration::from_secs(30))
+                .tcp_keepalive(std::time::Duration::from_secs(60))
+                .tls_backend(TlsBackend::NativeTls)
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+        client.get_project(&TEST_ENV.project).send().await.unwrap();
+
+        let client = Client::from_config(
+            Config::builder()
+                .access_key(&TEST_ENV.access_key_id, &TEST_ENV.access_key_secret)
+                .endpoint(&TEST_ENV.endpoint)
+                .http_client(reqwest::Client::new())
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+        client.get_project(&TEST_ENV.project).send().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn deadline_test() {
+        init();
+        // nothing listens on this port, so requests are retried until the deadline is reached
+        let client = Client::from_config(
+            Config::builder()
+                .access_key("access_key_id", "access_key_secret")
+                .endpoint("127.0.0.1:1")
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+        let start = std::time::Instant::now();
+        let err = client
+            .list_projects(0, 10)
+            .deadline(start + std::time::Duration::from_millis(200))
+            .send()
+            .await
+            .err()
+            .expect("request should fail");
+        assert!(matches!(err, Error::DeadlineExceeded), "{err:?}");
+        assert!(err.is_timeout());
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+
+        let err = client
+            .list_projects(0, 10)
+            .max_retry(0)
+            .send()
+            .await
+            .err()
+            .expect("request should fail");
+        assert!(matches!(err, Error::Network(_)), "{err:?}");
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn write_quota_retry_test() {
+        init();
+        // the layer answers in place of the server, alternating an internal error and a write quota error
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = count.clone();
+        let client = Client::from_config(
+            Config::builder()
+                .access_key("access_key_id", "access_key_secret")
+                .endpoint("cn-hangzhou.log.aliyuncs.com")
+                .write_rate_limit(WriteRateLimit::new(RateLimitScope::Logstore))
+                .layer(tower::layer::layer_fn(move |_: HttpService| {
+                    let counter = counter.clone();
+                    tower::service_fn(move |_: reqwest::Request| {
+                        let attempt = counter.fetch_add(1, Ordering::SeqCst);
+                        let (status, error_code) = if attempt.is_multiple_of(2) {
+                            (503, "InternalServerError")
+                        } else {
+                            (403, "WriteQuotaExceed")
+                        };
+                        let body =
+                            format!(r#"{{"errorCode": "{error_code}", "errorMessage": "error"}}"#);
+                        let resp = http::Response::builder().status(status).body(body);
+                        async move { Ok::<_, tower::BoxError>(reqwest::Response::from(resp?)) }
+                    })
+                }))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+        let err = client
+            .put_logs("my-project", "my-logstore")
+            .log_group(aliyun_log_sdk_protobuf::LogGroup::new())
+            .max_retry(2)
+            .send()
+            .await
+            .err()
+            .expect("request should fail");
+        assert!(matches!(err, Error::Server { .. }), "{err:?}");
+        // both kinds of retries count towards the maximum
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+    }
+
+    #[derive(Default)]
+    struct RecordingObserver {
+        events: std::sync::Mutex<Vec<RequestEvent>>,
+    }
+
+    impl RequestObserver for RecordingObserver {
+        fn on_request_completed(&self, event: &RequestEvent) {
+            self.events.lock().unwrap().push(event.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn observer_test() {
+        init();
+        let observer = Arc::new(RecordingObserver::default());
+        let client = Client::from_config(
+            Config::builder()
+                .access_key("access_key_id", "access_key_secret")
+                .endpoint("127.0.0.1:1")
+                .observer(observer.clone())
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+        let result = client.list_projects(0, 10).max_retry(1).send().await;
+        assert!(result.is_err());
+
+        let events = observer.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].api(), "list_projects");
+        assert_eq!(*events[0].project(), None);
+        assert!(!events[0].success());
+        assert_eq!(events[0].http_status(), None);
+        assert_eq!(events[0].retry_count(), 1);
+    }
+}
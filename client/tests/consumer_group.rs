@@ -217,6 +217,70 @@ mod tests {
         println!("✓ Verified consumer group deletion");
     }
 
+    #[tokio::test]
+    async fn test_ensure_consumer_group() {
+        init();
+        let project = &crate::tests::TEST_ENV.project;
+        let logstore = &crate::tests::TEST_ENV.logstore;
+        let consumer_group_name = "test-consumer-group-ensure";
+        cleanup_consumer_group!(&TEST_CLIENT, project, logstore, consumer_group_name);
+
+        let ensure = || TEST_CLIENT.ensure_consumer_group(project, logstore, consumer_group_name);
+        let outcome = ensure().timeout(30).order(true).send().await.unwrap();
+        assert_eq!(outcome, EnsureOutcome::Created);
+
+        let outcome = ensure().timeout(30).send().await.unwrap();
+        assert_eq!(outcome, EnsureOutcome::Unchanged);
+
+        let outcome = ensure().timeout(60).order(true).send().await.unwrap();
+        assert_eq!(outcome.changed_fields(), ["timeout"]);
+        let consumer_group =
+            find_consumer_group(&TEST_CLIENT, project, logstore, consumer_group_name)
+                .await
+                .unwrap();
+        assert_eq!(*consumer_group.timeout(), 60);
+        assert!(*consumer_group.order());
+
+        cleanup_consumer_group!(&TEST_CLIENT, project, logstore, consumer_group_name);
+    }
+
+    #[tokio::test]
+    async fn test_get_consumer_group_status() {
+        init();
+        let project = &crate::tests::TEST_ENV.project;
+        let logstore = &crate::tests::TEST_ENV.logstore;
+        let consumer_group_name = "test-consumer-group-status";
+        cleanup_consumer_group!(&TEST_CLIENT, project, logstore, consumer_group_name);
+        create_consumer_group!(
+            &TEST_CLIENT,
+            project,
+            logstore,
+            consumer_group_name,
+            30,
+            true
+        );
+
+        let status = TEST_CLIENT
+            .get_consumer_group_status(project, logstore, consumer_group_name)
+            .send()
+            .await
+            .unwrap();
+        let shards = TEST_CLIENT
+            .list_shards(project, logstore)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(status.shards().len(), shards.get_body().shards().len());
+        for shard in status.shards() {
+            // no checkpoint has been saved yet
+            assert_eq!(*shard.cursor_lag(), None);
+            assert_eq!(*shard.time_lag(), None);
+        }
+        println!("{status:?}");
+
+        cleanup_consumer_group!(&TEST_CLIENT, project, logstore, consumer_group_name);
+    }
+
     #[tokio::test]
     async fn test_consumer_group_error_handling() {
         init();
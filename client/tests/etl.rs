@@ -0,0 +1,82 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::*;
+    use aliyun_log_rust_sdk::Client;
+    use aliyun_log_rust_sdk::FromConfig;
+    use aliyun_log_rust_sdk::*;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref TEST_CLIENT: Client = {
+            Client::from_config(
+                Config::builder()
+                    .access_key(&TEST_ENV.access_key_id, &TEST_ENV.access_key_secret)
+                    .endpoint(&TEST_ENV.endpoint)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap()
+        };
+    }
+
+    fn init() {
+        let _ = env_logger::builder()
+            .is_test(true)
+            .filter_level(log::LevelFilter::Debug)
+            .try_init();
+    }
+    #[tokio::test]
+    async fn test_list_etls() {
+        init();
+
+        let resp = TEST_CLIENT
+            .list_etls(&TEST_ENV.project, 0, 10)
+            .send()
+            .await
+            .expect("Failed to list ETL jobs");
+        let body = resp.get_body();
+        assert!(*body.count() as usize == body.etls().len());
+        assert!(body.total() >= body.count());
+
+        for etl in body.etls() {
+            let resp = TEST_CLIENT
+                .get_etl(&TEST_ENV.project, etl.name())
+                .send()
+                .await
+                .expect("Failed to get ETL job");
+            assert_eq!(resp.get_body().name(), etl.name());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_etl_missing_parameters() {
+        init();
+
+        let configuration = EtlConfiguration::builder()
+            .logstore(&TEST_ENV.logstore)
+            .script("* | where team = 'payment'")
+            .build();
+
+        let result = TEST_CLIENT
+            .create_etl(&TEST_ENV.project, "rust-sdk-test-etl")
+            .configuration(configuration.clone())
+            .send()
+            .await;
+        assert!(matches!(
+            result,
+            Err(aliyun_log_rust_sdk::Error::RequestPreparation(_))
+        ));
+
+        let result = TEST_CLIENT
+            .preview_etl(&TEST_ENV.project)
+            .configuration(configuration)
+            .send()
+            .await;
+        assert!(matches!(
+            result,
+            Err(aliyun_log_rust_sdk::Error::RequestPreparation(_))
+        ));
+    }
+}
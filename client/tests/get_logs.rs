@@ -38,4 +38,74 @@ mod tests {
         assert!(resp.get_body().is_complete());
         println!("{:?}", resp.get_body());
     }
+
+    #[tokio::test]
+    async fn test_get_logs_stream() {
+        use futures_util::TryStreamExt;
+        let now: i64 = chrono::Utc::now().timestamp();
+
+        let project = &TEST_ENV.project;
+        let logstore = &TEST_ENV.logstore;
+        let logs: Vec<_> = TEST_CLIENT
+            .get_logs(project, logstore)
+            .from(now - 3000)
+            .to(now)
+            .lines(10)
+            .query("*")
+            .into_stream()
+            .try_collect()
+            .await
+            .unwrap();
+        let resp = TEST_CLIENT
+            .get_histograms(project, logstore)
+            .from(now - 3000)
+            .to(now)
+            .query("*")
+            .send()
+            .await
+            .unwrap();
+        assert!(logs.len() as i64 <= *resp.get_body().count());
+        println!("{}", logs.len());
+    }
+
+    #[tokio::test]
+    async fn test_get_histograms() {
+        let now: i64 = chrono::Utc::now().timestamp();
+
+        let project = &TEST_ENV.project;
+        let logstore = &TEST_ENV.logstore;
+        let resp = TEST_CLIENT
+            .get_histograms(project, logstore)
+            .from(now - 3000)
+            .to(now)
+            .query("*")
+            .send()
+            .await
+            .unwrap();
+        let total: i64 = resp
+            .get_body()
+            .histograms()
+            .iter()
+            .map(|histogram| histogram.count())
+            .sum();
+        assert_eq!(total, *resp.get_body().count());
+        println!("{:?}", resp.get_body());
+    }
+
+    #[tokio::test]
+    async fn test_get_project_logs() {
+        let project = &TEST_ENV.project;
+        let logstore = &TEST_ENV.logstore;
+        let resp = TEST_CLIENT
+            .get_project_logs(project)
+            .query(format!(
+                "SELECT count(*) AS pv FROM \"{logstore}\" WHERE __time__ > to_unixtime(now()) - 3000"
+            ))
+            .send()
+            .await
+            .unwrap();
+        assert!(resp.get_body().is_complete());
+        assert_eq!(resp.get_body().logs_count(), 1);
+        println!("{:?}", resp.get_body());
+    }
 }
@@ -86,6 +86,7 @@ mod tests {
             case_sensitive: false,
             chn: true,
             token: token_list![",", " ", ";", "\n", "\t"],
+            ..Default::default()
         };
 
         let mut field_indexes = HashMap::new();
@@ -99,6 +100,7 @@ mod tests {
                 chn: false,
                 token: token_list![],
                 doc_value: true,
+                ..Default::default()
             }),
         );
 
@@ -108,6 +110,7 @@ mod tests {
             FieldIndex::Long(IndexKeyLong {
                 alias: None,
                 doc_value: true,
+                ..Default::default()
             }),
         );
 
@@ -117,6 +120,7 @@ mod tests {
             FieldIndex::Double(IndexKeyDouble {
                 alias: None,
                 doc_value: true,
+                ..Default::default()
             }),
         );
 
@@ -194,6 +198,7 @@ mod tests {
                 chn: false,
                 token: token_list![],
                 doc_value: true,
+                ..Default::default()
             }),
         );
 
@@ -202,6 +207,7 @@ mod tests {
             FieldIndex::Long(IndexKeyLong {
                 alias: None,
                 doc_value: true,
+                ..Default::default()
             }),
         );
 
@@ -210,6 +216,7 @@ mod tests {
             FieldIndex::Double(IndexKeyDouble {
                 alias: None,
                 doc_value: true,
+                ..Default::default()
             }),
         );
 
@@ -222,6 +229,7 @@ mod tests {
                 chn: false,
                 token: token_list![],
                 doc_value: true,
+                ..Default::default()
             }),
         );
 
@@ -229,6 +237,7 @@ mod tests {
             case_sensitive: false,
             chn: true,
             token: token_list![",", " ", ";", "\n", "\t"],
+            ..Default::default()
         };
 
         let updated_index = Index::builder()
@@ -167,4 +167,40 @@ mod tests {
         );
         println!("✓ Verified logstore deletion");
     }
+
+    #[tokio::test]
+    async fn test_ensure_logstore() {
+        init();
+        let project = &TEST_ENV.project;
+        let logstore_name = format!("{}-ensure", get_test_logstore_name());
+        cleanup_logstore!(&TEST_CLIENT, project, &logstore_name);
+
+        let ensure = || {
+            TEST_CLIENT
+                .ensure_logstore(project, &logstore_name)
+                .shard_count(2)
+                .ttl(30)
+        };
+        let outcome = ensure().send().await.unwrap();
+        assert_eq!(outcome, EnsureOutcome::Created);
+        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+        let outcome = ensure().send().await.unwrap();
+        assert_eq!(outcome, EnsureOutcome::Unchanged);
+
+        let outcome = ensure().ttl(60).send().await.unwrap();
+        assert_eq!(outcome.changed_fields(), ["ttl"]);
+        let get_resp = TEST_CLIENT
+            .get_logstore(project, &logstore_name)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(*get_resp.get_body().ttl(), 60);
+
+        TEST_CLIENT
+            .delete_logstore(project, &logstore_name)
+            .send()
+            .await
+            .unwrap();
+    }
 }
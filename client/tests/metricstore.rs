@@ -0,0 +1,140 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::*;
+    use aliyun_log_rust_sdk::Client;
+    use aliyun_log_rust_sdk::FromConfig;
+    use aliyun_log_rust_sdk::*;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref TEST_CLIENT: Client = {
+            Client::from_config(
+                Config::builder()
+                    .access_key(&TEST_ENV.access_key_id, &TEST_ENV.access_key_secret)
+                    .endpoint(&TEST_ENV.endpoint)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap()
+        };
+    }
+
+    fn init() {
+        let _ = env_logger::builder()
+            .is_test(true)
+            .filter_level(log::LevelFilter::Debug)
+            .try_init();
+    }
+    fn get_test_metricstore_name() -> String {
+        format!("{}-metrics-for-test", TEST_ENV.logstore)
+    }
+
+    #[tokio::test]
+    async fn test_metricstore_lifecycle() {
+        init();
+
+        let client = &TEST_CLIENT;
+        let project = &TEST_ENV.project;
+        let metricstore_name = get_test_metricstore_name();
+
+        match client
+            .delete_metricstore(project, &metricstore_name)
+            .send()
+            .await
+        {
+            Ok(_) => tokio::time::sleep(tokio::time::Duration::from_millis(500)).await,
+            Err(e) => {
+                if !matches!(&e, aliyun_log_rust_sdk::Error::Server { error_code, .. } if error_code == "LogStoreNotExist" || error_code == "MetricStoreNotExist")
+                {
+                    eprintln!("Warning: Failed to cleanup metricstore: {}", e);
+                }
+            }
+        }
+
+        client
+            .create_metricstore(project, &metricstore_name)
+            .shard_count(2)
+            .ttl(30)
+            .send()
+            .await
+            .expect("Failed to create metricstore");
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+
+        let resp = client
+            .get_metricstore(project, &metricstore_name)
+            .send()
+            .await
+            .expect("Failed to get metricstore");
+        assert_eq!(resp.get_body().name(), &metricstore_name);
+        assert_eq!(*resp.get_body().ttl(), 30);
+
+        let resp = client
+            .list_metricstores(project, 0, 100)
+            .metricstore_name(&metricstore_name)
+            .send()
+            .await
+            .expect("Failed to list metricstores");
+        assert!(resp.get_body().metricstores().contains(&metricstore_name));
+
+        client
+            .put_metrics(project, &metricstore_name)
+            .sample(
+                MetricSample::new("rust_sdk_test_requests_total", 1.0)
+                    .label("method", "get")
+                    .label("code", "200"),
+            )
+            .sample(MetricSample::new("rust_sdk_test_cpu_usage", 0.5).label("host", "test"))
+            .send()
+            .await
+            .expect("Failed to put metrics");
+
+        let resp = client
+            .query_metrics(project, &metricstore_name, "rust_sdk_test_cpu_usage")
+            .send()
+            .await
+            .expect("Failed to run instant query");
+        assert_eq!(resp.get_body().status(), "success");
+        assert!(matches!(
+            resp.get_body().data(),
+            MetricQueryResult::Vector(_)
+        ));
+
+        let now = chrono::Utc::now().timestamp();
+        let resp = client
+            .query_metrics(
+                project,
+                &metricstore_name,
+                "sum(rust_sdk_test_requests_total) by (code)",
+            )
+            .range(now - 3600, now, 60)
+            .send()
+            .await
+            .expect("Failed to run range query");
+        assert!(matches!(
+            resp.get_body().data(),
+            MetricQueryResult::Matrix(_)
+        ));
+
+        client
+            .delete_metricstore(project, &metricstore_name)
+            .send()
+            .await
+            .expect("Failed to delete metricstore");
+    }
+
+    #[tokio::test]
+    async fn test_put_metrics_missing_parameters() {
+        init();
+
+        let result = TEST_CLIENT
+            .put_metrics(&TEST_ENV.project, get_test_metricstore_name())
+            .send()
+            .await;
+        assert!(matches!(
+            result,
+            Err(aliyun_log_rust_sdk::Error::RequestPreparation(_))
+        ));
+    }
+}
@@ -286,4 +286,25 @@ mod tests {
         assert_missing_param!(result, "description");
         println!("✓ Correctly detected missing description parameter");
     }
+
+    #[tokio::test]
+    async fn test_list_projects_collect_all() {
+        init();
+        let first_page = TEST_CLIENT.list_projects(0, 500).send().await.unwrap();
+        let total = *first_page.get_body().total() as usize;
+
+        // small pages, fetched concurrently, must yield every project once
+        let projects = TEST_CLIENT
+            .list_projects(0, 2)
+            .concurrency(4)
+            .collect_all()
+            .await
+            .unwrap();
+        assert_eq!(projects.len(), total);
+        let mut names: Vec<&String> = projects.iter().map(|p| p.project_name()).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), total);
+        println!("✓ Collected {} projects", total);
+    }
 }
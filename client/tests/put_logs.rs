@@ -38,4 +38,39 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn rate_limit_test() {
+        let client = Client::from_config(
+            Config::builder()
+                .access_key(&TEST_ENV.access_key_id, &TEST_ENV.access_key_secret)
+                .endpoint(&TEST_ENV.endpoint)
+                .write_rate_limit(
+                    WriteRateLimit::new(RateLimitScope::Shard)
+                        .bytes_per_second(1024 * 1024)
+                        .requests_per_second(2),
+                )
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+        let project = &TEST_ENV.project;
+        let logstore = &TEST_ENV.logstore;
+        let start = std::time::Instant::now();
+        for i in 0..4 {
+            let mut log_group = LogGroup::new();
+            let mut log = Log::from_unixtime(chrono::Utc::now().timestamp() as u32);
+            log.add_content_kv("index", i.to_string());
+            log_group.add_log(log);
+            client
+                .put_logs(project, logstore)
+                .hash_key("00000000000000000000000000000000")
+                .log_group(log_group)
+                .send()
+                .await
+                .unwrap();
+        }
+        // the first 2 writes are a burst, the next ones wait for the bucket to refill
+        assert!(start.elapsed() >= std::time::Duration::from_millis(900));
+    }
 }
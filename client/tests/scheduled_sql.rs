@@ -0,0 +1,88 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::*;
+    use aliyun_log_rust_sdk::Client;
+    use aliyun_log_rust_sdk::FromConfig;
+    use aliyun_log_rust_sdk::*;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref TEST_CLIENT: Client = {
+            Client::from_config(
+                Config::builder()
+                    .access_key(&TEST_ENV.access_key_id, &TEST_ENV.access_key_secret)
+                    .endpoint(&TEST_ENV.endpoint)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap()
+        };
+    }
+
+    fn init() {
+        let _ = env_logger::builder()
+            .is_test(true)
+            .filter_level(log::LevelFilter::Debug)
+            .try_init();
+    }
+
+    #[tokio::test]
+    async fn test_list_scheduled_sqls() {
+        init();
+
+        let resp = TEST_CLIENT
+            .list_scheduled_sqls(&TEST_ENV.project, 0, 10)
+            .send()
+            .await
+            .expect("Failed to list scheduled SQL jobs");
+        let body = resp.get_body();
+        assert!(*body.count() as usize == body.scheduled_sqls().len());
+        assert!(body.total() >= body.count());
+
+        let now = chrono::Utc::now().timestamp();
+        for job in body.scheduled_sqls() {
+            let resp = TEST_CLIENT
+                .list_scheduled_sql_job_instances(&TEST_ENV.project, job.name(), 0, 10)
+                .from(now - 3600)
+                .to(now)
+                .send()
+                .await
+                .expect("Failed to list scheduled SQL job instances");
+            for instance in resp.get_body().instances() {
+                println!("{}: {}", instance.instance_id(), instance.state());
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_sql_missing_parameters() {
+        init();
+
+        let result = TEST_CLIENT
+            .create_scheduled_sql(&TEST_ENV.project, "rust-sdk-test-scheduled-sql")
+            .display_name("missing configuration")
+            .schedule(Schedule::fixed_rate("5m"))
+            .send()
+            .await;
+        assert!(matches!(
+            result,
+            Err(aliyun_log_rust_sdk::Error::RequestPreparation(_))
+        ));
+
+        let result = TEST_CLIENT
+            .list_scheduled_sql_job_instances(
+                &TEST_ENV.project,
+                "rust-sdk-test-scheduled-sql",
+                0,
+                10,
+            )
+            .send()
+            .await;
+        assert!(matches!(
+            result,
+            Err(aliyun_log_rust_sdk::Error::RequestPreparation(_))
+        ));
+    }
+}
@@ -0,0 +1,97 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::*;
+    use aliyun_log_rust_sdk::Client;
+    use aliyun_log_rust_sdk::FromConfig;
+    use aliyun_log_rust_sdk::*;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref TEST_CLIENT: Client = {
+            Client::from_config(
+                Config::builder()
+                    .access_key(&TEST_ENV.access_key_id, &TEST_ENV.access_key_secret)
+                    .endpoint(&TEST_ENV.endpoint)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap()
+        };
+    }
+
+    fn init() {
+        let _ = env_logger::builder()
+            .is_test(true)
+            .filter_level(log::LevelFilter::Debug)
+            .try_init();
+    }
+    #[tokio::test]
+    async fn test_list_shippers() {
+        init();
+
+        let resp = TEST_CLIENT
+            .list_shippers(&TEST_ENV.project, &TEST_ENV.logstore, 0, 10)
+            .send()
+            .await
+            .expect("Failed to list shippers");
+        let body = resp.get_body();
+        assert!(*body.count() as usize == body.shippers().len());
+        assert!(body.total() >= body.count());
+
+        let now = chrono::Utc::now().timestamp();
+        for shipper_name in body.shippers() {
+            let resp = TEST_CLIENT
+                .get_shipper(&TEST_ENV.project, &TEST_ENV.logstore, shipper_name)
+                .send()
+                .await
+                .expect("Failed to get shipper");
+            assert_eq!(resp.get_body().shipper_name(), shipper_name);
+
+            let resp = TEST_CLIENT
+                .list_shipper_tasks(&TEST_ENV.project, &TEST_ENV.logstore, shipper_name, 0, 10)
+                .from(now - 3600)
+                .to(now)
+                .send()
+                .await
+                .expect("Failed to list shipper tasks");
+            for task in resp.get_body().tasks() {
+                println!("{}: {}", task.id(), task.task_status());
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shipper_missing_parameters() {
+        init();
+
+        let result = TEST_CLIENT
+            .list_shipper_tasks(
+                &TEST_ENV.project,
+                &TEST_ENV.logstore,
+                "rust-sdk-test-shipper",
+                0,
+                10,
+            )
+            .send()
+            .await;
+        assert!(matches!(
+            result,
+            Err(aliyun_log_rust_sdk::Error::RequestPreparation(_))
+        ));
+
+        let result = TEST_CLIENT
+            .retry_shipper_tasks(
+                &TEST_ENV.project,
+                &TEST_ENV.logstore,
+                "rust-sdk-test-shipper",
+            )
+            .send()
+            .await;
+        assert!(matches!(
+            result,
+            Err(aliyun_log_rust_sdk::Error::RequestPreparation(_))
+        ));
+    }
+}